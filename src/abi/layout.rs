@@ -0,0 +1,125 @@
+//! # ABI Layout
+//!
+//! Asserções de tamanho/alinhamento em tempo de compilação e tabela de
+//! layout acessível em runtime para bindings FFI.
+
+use core::mem::{align_of, size_of};
+
+use crate::anim::CubicBezier;
+use crate::buffer::{BufferCapabilities, BufferDescriptor, BufferHandle, BufferRegion};
+use crate::color::{Color, ColorF};
+use crate::damage::DamageRegion;
+use crate::display::{DisplayInfo, DisplayMode, DisplayRecord, OutputInfo};
+use crate::geometry::{
+    Circle, DecomposedTransform, Ellipse, Insets, Line, LineF, Point, PointF, Rect, RectF,
+    RoundedRect, Size, SizeF, StaticPolygon, Transform2D, MAX_STATIC_POINTS,
+};
+use crate::input::{CursorHotspot, CursorImage, TouchId, TouchPoint};
+use crate::render::{BlitParams, ClipRect, FillParams, PipelineState};
+use crate::text::{ClusterInfo, GlyphId, GlyphMetrics, GlyphPosition, TextDecoration};
+use crate::window::{
+    BlurParams, OpacityParams, ShadowParams, SurfaceCommit, SurfaceConfig, SurfaceId,
+    WindowEffects, WindowFlags,
+};
+
+/// Entrada da tabela de layout: (nome, tamanho, alinhamento).
+pub type LayoutEntry = (&'static str, usize, usize);
+
+/// Declara o layout esperado de cada tipo.
+///
+/// Gera uma asserção em tempo de compilação por tipo (uma mudança acidental
+/// de campos quebra o build) e a tabela `LAYOUT_TABLE`.
+macro_rules! abi_layout {
+    ($($ty:ident => ($size:expr, $align:expr)),* $(,)?) => {
+        $(
+            const _: () = assert!(size_of::<$ty>() == $size);
+            const _: () = assert!(align_of::<$ty>() == $align);
+        )*
+
+        const LAYOUT_TABLE: &[LayoutEntry] = &[
+            $((stringify!($ty), size_of::<$ty>(), align_of::<$ty>()),)*
+        ];
+    };
+}
+
+abi_layout! {
+    // Geometry
+    Point => (8, 4),
+    PointF => (8, 4),
+    Size => (8, 4),
+    SizeF => (8, 4),
+    Rect => (16, 4),
+    RectF => (16, 4),
+    RoundedRect => (20, 4),
+    Insets => (16, 4),
+    Line => (16, 4),
+    LineF => (16, 4),
+    Circle => (12, 4),
+    Ellipse => (16, 4),
+    Transform2D => (24, 4),
+    DecomposedTransform => (24, 4),
+    StaticPolygon => (
+        MAX_STATIC_POINTS * size_of::<PointF>() + 2 * size_of::<usize>(),
+        align_of::<usize>()
+    ),
+
+    // Color
+    Color => (4, 4),
+    ColorF => (16, 4),
+
+    // Buffer
+    BufferDescriptor => (16, 4),
+    BufferHandle => (8, 8),
+    BufferRegion => (16, 4),
+    BufferCapabilities => (4, 4),
+
+    // Damage
+    DamageRegion => (16, 4),
+
+    // Display
+    DisplayInfo => (24, 4),
+    DisplayMode => (16, 4),
+    OutputInfo => (16, 4),
+    DisplayRecord => (76, 4),
+
+    // Window
+    WindowFlags => (4, 4),
+    SurfaceId => (4, 4),
+    SurfaceConfig => (16, 4),
+    SurfaceCommit => (24, 8),
+    ShadowParams => (20, 4),
+    BlurParams => (8, 4),
+    OpacityParams => (4, 4),
+    WindowEffects => (36, 4),
+
+    // Input
+    CursorHotspot => (8, 4),
+    CursorImage => (32, 8),
+    TouchId => (4, 4),
+    TouchPoint => (24, 4),
+
+    // Render
+    ClipRect => (20, 4),
+    FillParams => (24, 4),
+    BlitParams => (40, 8),
+    PipelineState => (5, 1),
+
+    // Text
+    GlyphId => (4, 4),
+    GlyphMetrics => (24, 4),
+    GlyphPosition => (20, 4),
+    ClusterInfo => (16, 4),
+    TextDecoration => (1, 1),
+
+    // Anim
+    CubicBezier => (16, 4),
+}
+
+/// Tabela de layout de todos os tipos `#[repr(C)]`/`#[repr(transparent)]`.
+///
+/// Bindings FFI comparam esta tabela com seus próprios layouts para
+/// detectar incompatibilidades.
+#[inline]
+pub fn layout_info() -> &'static [LayoutEntry] {
+    LAYOUT_TABLE
+}
@@ -0,0 +1,8 @@
+//! # ABI Module
+//!
+//! Garantias de layout binário dos tipos compartilhados entre kernel e
+//! userspace.
+
+mod layout;
+
+pub use layout::{layout_info, LayoutEntry};
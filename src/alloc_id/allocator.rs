@@ -0,0 +1,141 @@
+//! # ID Allocator
+//!
+//! Contador monotônico com reciclagem opcional de IDs.
+
+use crate::buffer::BufferHandle;
+use crate::window::SurfaceId;
+
+/// Capacidade da lista de IDs livres.
+pub const MAX_FREE_IDS: usize = 64;
+
+/// Alocador de IDs (single-threaded, sem alocação).
+///
+/// Nunca retorna o ID reservado 0 (`INVALID`). IDs liberados são reusados
+/// antes de novos valores do contador; se a lista de livres estiver cheia,
+/// o ID liberado é descartado (e nunca mais emitido). O contador não dá a
+/// volta: esgotado, só IDs reciclados são emitidos, então um ID vivo nunca
+/// é repetido.
+///
+/// Cada entrada da lista de livres guarda a geração do último uso; ao
+/// reciclar um ID para um `BufferHandle`, a geração é incrementada para que
+/// handles antigos não sejam iguais ao novo.
+#[derive(Clone, Copy, Debug)]
+pub struct IdAllocator {
+    /// Primeiro ID emitido pelo contador.
+    first: u32,
+    /// Próximo ID do contador (`u64` para representar o esgotamento).
+    next: u64,
+    /// IDs liberados para reuso, com a geração do último uso.
+    free: [(u32, u32); MAX_FREE_IDS],
+    /// Número de IDs livres.
+    free_count: usize,
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdAllocator {
+    /// Cria alocador começando em 1.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Cria alocador começando em um valor específico (0 vira 1).
+    #[inline]
+    pub const fn starting_at(first: u32) -> Self {
+        let first = if first == 0 { 1 } else { first };
+        Self {
+            first,
+            next: first as u64,
+            free: [(0, 0); MAX_FREE_IDS],
+            free_count: 0,
+        }
+    }
+
+    /// Número de IDs aguardando reuso.
+    #[inline]
+    pub const fn free_count(&self) -> usize {
+        self.free_count
+    }
+
+    /// Verifica se o contador se esgotou (só restam IDs reciclados).
+    #[inline]
+    pub const fn is_exhausted(&self) -> bool {
+        self.next > u32::MAX as u64
+    }
+
+    /// Aloca um ID (nunca 0). `None` se o contador esgotou e não há livres.
+    #[inline]
+    pub fn alloc(&mut self) -> Option<u32> {
+        self.alloc_slot().map(|(id, _)| id)
+    }
+
+    /// Libera um ID para reuso.
+    ///
+    /// Retorna `false` se o ID é 0, nunca foi emitido, já está livre ou a
+    /// lista está cheia. Handles de buffer devem usar `free_buffer`, que
+    /// preserva a geração.
+    #[inline]
+    pub fn free(&mut self, id: u32) -> bool {
+        self.release(id, 0)
+    }
+
+    /// Aloca um SurfaceId.
+    #[inline]
+    pub fn alloc_surface(&mut self) -> Option<SurfaceId> {
+        self.alloc().map(SurfaceId::new)
+    }
+
+    /// Libera um SurfaceId.
+    #[inline]
+    pub fn free_surface(&mut self, id: SurfaceId) -> bool {
+        self.free(id.0)
+    }
+
+    /// Aloca um BufferHandle (geração 0 para IDs novos, incrementada a cada
+    /// reuso de um ID).
+    #[inline]
+    pub fn alloc_buffer(&mut self) -> Option<BufferHandle> {
+        self.alloc_slot()
+            .map(|(id, generation)| BufferHandle::from_id_gen(id, generation))
+    }
+
+    /// Libera um BufferHandle, guardando sua geração para o próximo reuso.
+    #[inline]
+    pub fn free_buffer(&mut self, handle: BufferHandle) -> bool {
+        self.release(handle.id(), handle.generation())
+    }
+
+    /// Próximo `(id, geração)`: reciclado (geração + 1) ou do contador.
+    fn alloc_slot(&mut self) -> Option<(u32, u32)> {
+        if self.free_count > 0 {
+            self.free_count -= 1;
+            let (id, generation) = self.free[self.free_count];
+            return Some((id, generation.wrapping_add(1)));
+        }
+        if self.is_exhausted() {
+            return None;
+        }
+        let id = self.next as u32;
+        self.next += 1;
+        Some((id, 0))
+    }
+
+    /// Coloca `id` na lista de livres após validá-lo.
+    fn release(&mut self, id: u32, generation: u32) -> bool {
+        let issued = id >= self.first && (id as u64) < self.next;
+        if !issued || self.free_count >= MAX_FREE_IDS {
+            return false;
+        }
+        if self.free[..self.free_count].iter().any(|&(f, _)| f == id) {
+            return false;
+        }
+        self.free[self.free_count] = (id, generation);
+        self.free_count += 1;
+        true
+    }
+}
@@ -0,0 +1,7 @@
+//! # Alloc ID Module
+//!
+//! Alocação de identificadores (SurfaceId, BufferHandle).
+
+mod allocator;
+
+pub use allocator::{IdAllocator, MAX_FREE_IDS};
@@ -0,0 +1,118 @@
+//! # Cubic Bezier
+//!
+//! Função de timing `cubic-bezier()` no estilo CSS.
+
+/// Iterações máximas de Newton-Raphson.
+const NEWTON_ITERATIONS: usize = 8;
+/// Iterações máximas da bisseção de fallback.
+const BISECTION_ITERATIONS: usize = 32;
+/// Tolerância de convergência em x.
+const EPSILON: f32 = 1e-6;
+
+/// Curva de timing cúbica com extremos fixos em (0, 0) e (1, 1).
+///
+/// Equivalente ao `cubic-bezier(x1, y1, x2, y2)` do CSS. `x1` e `x2` são
+/// limitados a 0.0-1.0 para que a curva seja uma função de x.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    /// X do primeiro ponto de controle.
+    pub x1: f32,
+    /// Y do primeiro ponto de controle.
+    pub y1: f32,
+    /// X do segundo ponto de controle.
+    pub x2: f32,
+    /// Y do segundo ponto de controle.
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// Curva linear.
+    pub const LINEAR: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    /// CSS `ease`.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Cria curva (x1 e x2 limitados a 0.0-1.0).
+    #[inline]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Resolve a curva: retorna o y correspondente ao progresso `t` em x
+    /// (limitado a 0.0-1.0).
+    pub fn solve(&self, t: f32) -> f32 {
+        let x = t.clamp(0.0, 1.0);
+        if x <= 0.0 || x >= 1.0 {
+            return x;
+        }
+        let s = self.solve_curve_x(x);
+        sample(self.y1, self.y2, s)
+    }
+
+    /// Inverte x(s) = x, com Newton-Raphson e bisseção como fallback.
+    fn solve_curve_x(&self, x: f32) -> f32 {
+        let mut s = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let err = sample(self.x1, self.x2, s) - x;
+            if err.abs() < EPSILON {
+                return s;
+            }
+            let d = derivative(self.x1, self.x2, s);
+            if d.abs() < EPSILON {
+                break;
+            }
+            s -= err / d;
+        }
+
+        let (mut lo, mut hi) = (0.0f32, 1.0f32);
+        s = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let value = sample(self.x1, self.x2, s);
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+            if value < x {
+                lo = s;
+            } else {
+                hi = s;
+            }
+            s = (lo + hi) * 0.5;
+        }
+        s
+    }
+}
+
+impl Default for CubicBezier {
+    fn default() -> Self {
+        Self::EASE
+    }
+}
+
+/// Avalia uma coordenada da curva (extremos 0 e 1) no parâmetro `s`.
+#[inline]
+fn sample(p1: f32, p2: f32, s: f32) -> f32 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    ((a * s + b) * s + c) * s
+}
+
+/// Derivada de [`sample`] em relação a `s`.
+#[inline]
+fn derivative(p1: f32, p2: f32, s: f32) -> f32 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    (3.0 * a * s + 2.0 * b) * s + c
+}
@@ -0,0 +1,180 @@
+//! # Easing
+//!
+//! Funções de easing clássicas.
+
+use core::f32::consts::PI;
+
+/// Overshoot padrão das curvas Back.
+const BACK_C1: f32 = 1.70158;
+/// Overshoot das curvas Back in/out.
+const BACK_C2: f32 = BACK_C1 * 1.525;
+
+/// Função de easing.
+///
+/// `In` acelera a partir do início, `Out` desacelera no fim e `InOut`
+/// combina as duas metades. Toda curva leva 0 em 0 e 1 em 1.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum Easing {
+    /// Linear (sem easing).
+    #[default]
+    Linear = 0,
+    /// Quadrática (entrada).
+    QuadIn = 1,
+    /// Quadrática (saída).
+    QuadOut = 2,
+    /// Quadrática (entrada e saída).
+    QuadInOut = 3,
+    /// Cúbica (entrada).
+    CubicIn = 4,
+    /// Cúbica (saída).
+    CubicOut = 5,
+    /// Cúbica (entrada e saída).
+    CubicInOut = 6,
+    /// Quártica (entrada).
+    QuartIn = 7,
+    /// Quártica (saída).
+    QuartOut = 8,
+    /// Quártica (entrada e saída).
+    QuartInOut = 9,
+    /// Senoidal (entrada).
+    SineIn = 10,
+    /// Senoidal (saída).
+    SineOut = 11,
+    /// Senoidal (entrada e saída).
+    SineInOut = 12,
+    /// Exponencial (entrada).
+    ExpoIn = 13,
+    /// Exponencial (saída).
+    ExpoOut = 14,
+    /// Exponencial (entrada e saída).
+    ExpoInOut = 15,
+    /// Back (recua abaixo de 0 antes de avançar).
+    BackIn = 16,
+    /// Back (ultrapassa 1 antes de assentar).
+    BackOut = 17,
+    /// Back (entrada e saída).
+    BackInOut = 18,
+    /// Bounce (entrada).
+    BounceIn = 19,
+    /// Bounce (saída, quica no final).
+    BounceOut = 20,
+    /// Bounce (entrada e saída).
+    BounceInOut = 21,
+}
+
+impl Easing {
+    /// Todas as curvas, na ordem dos discriminantes.
+    pub const ALL: [Easing; 22] = [
+        Self::Linear,
+        Self::QuadIn,
+        Self::QuadOut,
+        Self::QuadInOut,
+        Self::CubicIn,
+        Self::CubicOut,
+        Self::CubicInOut,
+        Self::QuartIn,
+        Self::QuartOut,
+        Self::QuartInOut,
+        Self::SineIn,
+        Self::SineOut,
+        Self::SineInOut,
+        Self::ExpoIn,
+        Self::ExpoOut,
+        Self::ExpoInOut,
+        Self::BackIn,
+        Self::BackOut,
+        Self::BackInOut,
+        Self::BounceIn,
+        Self::BounceOut,
+        Self::BounceInOut,
+    ];
+
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        Self::ALL.get(value as usize).copied()
+    }
+
+    /// Aplica a curva a `t` (limitado a 0.0-1.0).
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => out(t, |t| t * t),
+            Self::QuadInOut => in_out(t, |t| t * t),
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => out(t, |t| t * t * t),
+            Self::CubicInOut => in_out(t, |t| t * t * t),
+            Self::QuartIn => t * t * t * t,
+            Self::QuartOut => out(t, |t| t * t * t * t),
+            Self::QuartInOut => in_out(t, |t| t * t * t * t),
+            Self::SineIn => sine_in(t),
+            Self::SineOut => out(t, sine_in),
+            Self::SineInOut => in_out(t, sine_in),
+            Self::ExpoIn => expo_in(t),
+            Self::ExpoOut => out(t, expo_in),
+            Self::ExpoInOut => in_out(t, expo_in),
+            Self::BackIn => back_in(t, BACK_C1),
+            Self::BackOut => out(t, |t| back_in(t, BACK_C1)),
+            Self::BackInOut => in_out(t, |t| back_in(t, BACK_C2)),
+            Self::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        }
+    }
+}
+
+/// Reflexão de uma curva de entrada: `1 - f(1 - t)`.
+#[inline]
+fn out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    1.0 - f(1.0 - t)
+}
+
+/// Curva de entrada na primeira metade e sua reflexão na segunda.
+#[inline]
+fn in_out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        f(t * 2.0) * 0.5
+    } else {
+        1.0 - f((1.0 - t) * 2.0) * 0.5
+    }
+}
+
+#[inline]
+fn sine_in(t: f32) -> f32 {
+    1.0 - rdsmath::cosf(t * PI * 0.5)
+}
+
+#[inline]
+fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        rdsmath::powf(2.0, 10.0 * t - 10.0)
+    }
+}
+
+#[inline]
+fn back_in(t: f32, c1: f32) -> f32 {
+    let c3 = c1 + 1.0;
+    c3 * t * t * t - c1 * t * t
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
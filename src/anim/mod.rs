@@ -0,0 +1,9 @@
+//! # Anim Module
+//!
+//! Curvas de easing e funções de timing para animações.
+
+mod bezier;
+mod easing;
+
+pub use bezier::CubicBezier;
+pub use easing::Easing;
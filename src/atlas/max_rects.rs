@@ -0,0 +1,163 @@
+//! # MaxRects Packer
+//!
+//! Empacotamento MaxRects com heurística best-area-fit: mantém a lista de
+//! retângulos livres maximais e escolhe o que desperdiça menos área.
+
+use crate::geometry::{Rect, Size};
+
+/// Número máximo de retângulos livres rastreados.
+pub const MAX_FREE_RECTS: usize = 128;
+
+/// Empacotador MaxRects com lista de espaços livres limitada.
+///
+/// Empacota itens de tamanhos variados melhor que o [`ShelfPacker`]. Se a
+/// lista de livres encher, os menores espaços são descartados: o atlas
+/// continua correto (sem sobreposição), apenas perde algum aproveitamento.
+///
+/// [`ShelfPacker`]: super::ShelfPacker
+#[derive(Clone, Copy, Debug)]
+pub struct MaxRectsPacker {
+    /// Tamanho do atlas.
+    size: Size,
+    /// Retângulos livres maximais.
+    free: [Rect; MAX_FREE_RECTS],
+    /// Número de retângulos livres.
+    free_count: usize,
+    /// Área ocupada por itens.
+    used_area: u64,
+}
+
+impl MaxRectsPacker {
+    /// Cria empacotador vazio para um atlas de `size`.
+    #[inline]
+    pub const fn new(size: Size) -> Self {
+        let mut free = [Rect::ZERO; MAX_FREE_RECTS];
+        free[0] = Rect::from_size(size);
+        Self {
+            size,
+            free,
+            free_count: if size.is_empty() { 0 } else { 1 },
+            used_area: 0,
+        }
+    }
+
+    /// Tamanho do atlas.
+    #[inline]
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Número de retângulos livres rastreados.
+    #[inline]
+    pub const fn free_count(&self) -> usize {
+        self.free_count
+    }
+
+    /// Reserva espaço para um item. Retorna `None` se não couber.
+    ///
+    /// Escolhe o livre com menor sobra de área; empates vão para o de menor
+    /// sobra no lado curto.
+    pub fn insert(&mut self, size: Size) -> Option<Rect> {
+        if size.is_empty() {
+            return None;
+        }
+
+        let (_, best) = self.free[..self.free_count]
+            .iter()
+            .filter(|f| f.width >= size.width && f.height >= size.height)
+            .map(|f| {
+                let area_fit = f.area() - size.area();
+                let short_fit = (f.width - size.width).min(f.height - size.height);
+                ((area_fit, short_fit), *f)
+            })
+            .min_by_key(|(score, _)| *score)?;
+
+        let placed = Rect::new(best.x, best.y, size.width, size.height);
+        self.split_free(&placed);
+        self.prune_free();
+        self.used_area += size.area();
+        Some(placed)
+    }
+
+    /// Fração da área do atlas ocupada por itens (0.0-1.0).
+    #[inline]
+    pub fn occupancy(&self) -> f32 {
+        let total = self.size.area();
+        if total == 0 {
+            return 0.0;
+        }
+        self.used_area as f32 / total as f32
+    }
+
+    /// Esvazia o atlas.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new(self.size);
+    }
+
+    /// Substitui cada livre que intersecta `placed` pelas sobras maximais.
+    fn split_free(&mut self, placed: &Rect) {
+        let mut i = 0;
+        while i < self.free_count {
+            let free = self.free[i];
+            if !free.intersects(placed) {
+                i += 1;
+                continue;
+            }
+
+            self.free_count -= 1;
+            self.free[i] = self.free[self.free_count];
+
+            let (fl, ft, fr, fb) = free.to_ltrb();
+            let (pl, pt, pr, pb) = placed.to_ltrb();
+            let pieces = [
+                Rect::from_ltrb(fl, ft, pl, fb),
+                Rect::from_ltrb(pr, ft, fr, fb),
+                Rect::from_ltrb(fl, ft, fr, pt),
+                Rect::from_ltrb(fl, pb, fr, fb),
+            ];
+            // As sobras não intersectam `placed`, então vão para o fim da
+            // lista e são ignoradas pelo restante da varredura.
+            for piece in pieces.into_iter().filter(|p| !p.is_empty()) {
+                self.push_free(piece);
+            }
+        }
+    }
+
+    /// Adiciona um livre; se a lista estiver cheia, descarta o menor.
+    fn push_free(&mut self, rect: Rect) {
+        if self.free_count < MAX_FREE_RECTS {
+            self.free[self.free_count] = rect;
+            self.free_count += 1;
+            return;
+        }
+        let (smallest, area) = self
+            .free
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (i, f.area()))
+            .min_by_key(|(_, area)| *area)
+            .unwrap_or((0, 0));
+        if rect.area() > area {
+            self.free[smallest] = rect;
+        }
+    }
+
+    /// Remove livres contidos em outros livres.
+    fn prune_free(&mut self) {
+        let mut i = 0;
+        while i < self.free_count {
+            let contained = (0..self.free_count).any(|j| {
+                j != i
+                    && self.free[j].contains_rect(&self.free[i])
+                    && (self.free[i] != self.free[j] || j < i)
+            });
+            if contained {
+                self.free_count -= 1;
+                self.free[i] = self.free[self.free_count];
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
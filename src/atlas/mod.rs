@@ -0,0 +1,9 @@
+//! # Atlas Module
+//!
+//! Empacotamento de retângulos em texturas atlas (glyphs, ícones).
+
+mod max_rects;
+mod shelf;
+
+pub use max_rects::{MaxRectsPacker, MAX_FREE_RECTS};
+pub use shelf::{ShelfPacker, MAX_SHELVES};
@@ -0,0 +1,115 @@
+//! # Shelf Packer
+//!
+//! Empacotamento em prateleiras: simples e rápido, ideal para itens de
+//! altura parecida (glyphs de um mesmo tamanho de fonte).
+
+use crate::geometry::{Rect, Size};
+
+/// Número máximo de prateleiras.
+pub const MAX_SHELVES: usize = 64;
+
+/// Prateleira horizontal.
+#[derive(Clone, Copy, Debug, Default)]
+struct Shelf {
+    /// Topo da prateleira.
+    y: u32,
+    /// Altura da prateleira.
+    height: u32,
+    /// Próximo X livre.
+    cursor: u32,
+}
+
+/// Empacotador em prateleiras com capacidade fixa.
+///
+/// Cada item vai para a prateleira de menor altura que o comporte; se
+/// nenhuma servir, abre uma nova abaixo da última.
+#[derive(Clone, Copy, Debug)]
+pub struct ShelfPacker {
+    /// Tamanho do atlas.
+    size: Size,
+    /// Prateleiras abertas.
+    shelves: [Shelf; MAX_SHELVES],
+    /// Número de prateleiras.
+    count: usize,
+    /// Área ocupada por itens.
+    used_area: u64,
+}
+
+impl ShelfPacker {
+    /// Cria empacotador vazio para um atlas de `size`.
+    #[inline]
+    pub const fn new(size: Size) -> Self {
+        Self {
+            size,
+            shelves: [Shelf {
+                y: 0,
+                height: 0,
+                cursor: 0,
+            }; MAX_SHELVES],
+            count: 0,
+            used_area: 0,
+        }
+    }
+
+    /// Tamanho do atlas.
+    #[inline]
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Reserva espaço para um item. Retorna `None` se não couber.
+    pub fn insert(&mut self, size: Size) -> Option<Rect> {
+        if size.is_empty() || size.width > self.size.width {
+            return None;
+        }
+
+        let best = self.shelves[..self.count]
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.height >= size.height && self.size.width - s.cursor >= size.width)
+            .min_by_key(|(_, s)| s.height)
+            .map(|(i, _)| i);
+
+        let index = match best {
+            Some(i) => i,
+            None => {
+                let top = self.shelves[..self.count]
+                    .last()
+                    .map_or(0, |s| s.y + s.height);
+                if self.count >= MAX_SHELVES || self.size.height - top < size.height {
+                    return None;
+                }
+                self.shelves[self.count] = Shelf {
+                    y: top,
+                    height: size.height,
+                    cursor: 0,
+                };
+                self.count += 1;
+                self.count - 1
+            }
+        };
+
+        let shelf = &mut self.shelves[index];
+        let rect = Rect::new(shelf.cursor as i32, shelf.y as i32, size.width, size.height);
+        shelf.cursor += size.width;
+        self.used_area += size.area();
+        Some(rect)
+    }
+
+    /// Fração da área do atlas ocupada por itens (0.0-1.0).
+    #[inline]
+    pub fn occupancy(&self) -> f32 {
+        let total = self.size.area();
+        if total == 0 {
+            return 0.0;
+        }
+        self.used_area as f32 / total as f32
+    }
+
+    /// Esvazia o atlas.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.used_area = 0;
+    }
+}
@@ -0,0 +1,87 @@
+//! # Buffer Age
+//!
+//! Idade dos buffers de uma superfície para repaint parcial (padrão
+//! `EGL_EXT_buffer_age`).
+
+use super::BufferHandle;
+
+/// Número de commits lembrados por BufferAge.
+pub const MAX_BUFFER_AGE: usize = 8;
+
+/// Histórico dos últimos buffers apresentados por uma superfície.
+///
+/// A idade 0 é o buffer do commit mais recente; idade N significa que o
+/// buffer foi apresentado N frames atrás e seu conteúdo está defasado pelo
+/// damage dos N frames seguintes. Equivale à idade EGL menos um.
+#[derive(Clone, Copy, Debug)]
+pub struct BufferAge {
+    /// Handles do mais recente para o mais antigo.
+    history: [BufferHandle; MAX_BUFFER_AGE],
+    /// Número de commits registrados (até `MAX_BUFFER_AGE`).
+    count: usize,
+}
+
+impl Default for BufferAge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BufferAge {
+    /// Cria histórico vazio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            history: [BufferHandle::INVALID; MAX_BUFFER_AGE],
+            count: 0,
+        }
+    }
+
+    /// Número de commits no histórico.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazio.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Registra um commit de `handle`. Handles inválidos são ignorados.
+    pub fn commit(&mut self, handle: BufferHandle) {
+        if !handle.is_valid() {
+            return;
+        }
+        let end = if self.count < MAX_BUFFER_AGE {
+            self.count += 1;
+            self.count - 1
+        } else {
+            MAX_BUFFER_AGE - 1
+        };
+        self.history.copy_within(0..end, 1);
+        self.history[0] = handle;
+    }
+
+    /// Há quantos frames `handle` foi apresentado pela última vez.
+    ///
+    /// `None` se o buffer é novo ou saiu do histórico: o conteúdo deve ser
+    /// considerado indefinido e a superfície repintada por inteiro.
+    #[inline]
+    pub fn age_of(&self, handle: BufferHandle) -> Option<u32> {
+        if !handle.is_valid() {
+            return None;
+        }
+        self.history[..self.count]
+            .iter()
+            .position(|&h| h == handle)
+            .map(|age| age as u32)
+    }
+
+    /// Esquece todo o histórico (ex: após redimensionar).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
@@ -0,0 +1,92 @@
+//! # Buffer Descriptor Builder
+//!
+//! Construção validada de descritores de buffer.
+
+use super::{BufferDescriptor, BufferError};
+use crate::color::PixelFormat;
+
+/// Builder para `BufferDescriptor`.
+///
+/// Por padrão usa `PixelFormat::ARGB8888` e o stride mínimo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferDescriptorBuilder {
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    alignment: Option<u32>,
+    stride: Option<u32>,
+}
+
+impl BufferDescriptorBuilder {
+    /// Cria builder para as dimensões dadas.
+    #[inline]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            format: PixelFormat::ARGB8888,
+            alignment: None,
+            stride: None,
+        }
+    }
+
+    /// Com formato de pixel.
+    #[inline]
+    pub const fn format(mut self, format: PixelFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Com alinhamento de linha (em bytes, potência de dois).
+    #[inline]
+    pub const fn alignment(mut self, alignment: u32) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Com stride explícito.
+    #[inline]
+    pub const fn stride(mut self, stride: u32) -> Self {
+        self.stride = Some(stride);
+        self
+    }
+
+    /// Valida e produz o descritor.
+    pub fn build(self) -> Result<BufferDescriptor, BufferError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(BufferError::EmptyDimensions);
+        }
+        if let Some(alignment) = self.alignment {
+            if !alignment.is_power_of_two() {
+                return Err(BufferError::InvalidAlignment);
+            }
+        }
+
+        let min_stride = self
+            .format
+            .checked_min_stride(self.width)
+            .ok_or(BufferError::Overflow)?;
+        let stride = match (self.stride, self.alignment) {
+            (Some(stride), _) if stride < min_stride => return Err(BufferError::StrideTooSmall),
+            (Some(stride), Some(alignment)) if stride % alignment != 0 => {
+                return Err(BufferError::StrideMisaligned)
+            }
+            (Some(stride), _) => stride,
+            (None, Some(alignment)) => self
+                .format
+                .checked_aligned_stride(self.width, alignment)
+                .ok_or(BufferError::Overflow)?,
+            (None, None) => min_stride,
+        };
+        (stride as usize)
+            .checked_mul(self.height as usize)
+            .ok_or(BufferError::Overflow)?;
+
+        Ok(BufferDescriptor::with_stride(
+            self.width,
+            self.height,
+            stride,
+            self.format,
+        ))
+    }
+}
@@ -0,0 +1,134 @@
+//! # Channel Operations
+//!
+//! Extração e recombinação de canais de cor.
+
+use super::{BufferView, BufferViewMut, ConvertError};
+use crate::color::{Channel, PixelFormat};
+
+impl BufferView<'_> {
+    /// Extrai um canal para um buffer Gray8 de mesmas dimensões.
+    pub fn extract_channel(
+        &self,
+        channel: Channel,
+        out: &mut BufferViewMut,
+    ) -> Result<(), ConvertError> {
+        if out.format() != PixelFormat::Gray8 {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        if out.width() != self.width() || out.height() != self.height() {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        for y in 0..self.height() {
+            let dst = match out.row_mut(y) {
+                Some(row) => row,
+                None => break,
+            };
+            for (x, value) in dst.iter_mut().enumerate() {
+                if let Some(color) = self.get_pixel(x as u32, y) {
+                    *value = channel.get(color);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Separa um buffer de 32 bits em planos Gray8 (R, G, B, A nessa ordem).
+    ///
+    /// Aceita de 1 a 4 planos, cada um com `width * height` bytes (sem
+    /// padding); planos a menos simplesmente omitem os últimos canais.
+    pub fn deinterleave(&self, planes: &mut [&mut [u8]]) -> Result<(), ConvertError> {
+        if !self.format().is_32bit() {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        check_planes(planes.iter().map(|p| p.len()), self.width(), self.height())?;
+
+        let width = self.width() as usize;
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let color = match self.get_pixel(x, y) {
+                    Some(c) => c,
+                    None => continue,
+                };
+                let index = y as usize * width + x as usize;
+                for (plane, channel) in planes.iter_mut().zip(Channel::ALL) {
+                    plane[index] = channel.get(color);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BufferViewMut<'_> {
+    /// Escreve um buffer Gray8 de mesmas dimensões em um canal.
+    ///
+    /// Falha se o formato de destino não armazena o canal (ex: alpha em
+    /// `XRGB8888` ou qualquer canal em formatos grayscale).
+    pub fn set_channel(&mut self, channel: Channel, src: &BufferView) -> Result<(), ConvertError> {
+        let format = self.format();
+        if src.format() != PixelFormat::Gray8
+            || format.is_grayscale()
+            || (channel == Channel::Alpha && !format.has_alpha())
+        {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        if src.width() != self.width() || src.height() != self.height() {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        for y in 0..self.height() {
+            let row = match src.row(y) {
+                Some(row) => row,
+                None => break,
+            };
+            for (x, &value) in row.iter().enumerate() {
+                let x = x as u32;
+                if let Some(color) = self.get_pixel(x, y) {
+                    self.set_pixel(x, y, channel.set(color, value));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Recombina planos Gray8 (R, G, B, A) neste buffer de 32 bits.
+    ///
+    /// Inverso de [`BufferView::deinterleave`]; canais sem plano mantêm o
+    /// valor atual.
+    pub fn interleave_from(&mut self, planes: &[&[u8]]) -> Result<(), ConvertError> {
+        if !self.format().is_32bit() {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        check_planes(planes.iter().map(|p| p.len()), self.width(), self.height())?;
+
+        let width = self.width() as usize;
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let mut color = match self.get_pixel(x, y) {
+                    Some(c) => c,
+                    None => continue,
+                };
+                let index = y as usize * width + x as usize;
+                for (plane, channel) in planes.iter().zip(Channel::ALL) {
+                    color = channel.set(color, plane[index]);
+                }
+                self.set_pixel(x, y, color);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Valida a quantidade (1-4) e o tamanho dos planos.
+fn check_planes(
+    mut lens: impl ExactSizeIterator<Item = usize>,
+    width: u32,
+    height: u32,
+) -> Result<(), ConvertError> {
+    let expected = width as usize * height as usize;
+    if !(1..=4).contains(&lens.len()) || lens.any(|len| len != expected) {
+        return Err(ConvertError::SizeMismatch);
+    }
+    Ok(())
+}
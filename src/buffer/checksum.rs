@@ -0,0 +1,50 @@
+//! # Row Checksums
+//!
+//! Checksums por linha para upload incremental.
+
+use super::BufferView;
+
+/// FNV-1a de 32 bits.
+#[inline]
+fn fnv1a(bytes: &[u8]) -> u32 {
+    const OFFSET: u32 = 0x811C_9DC5;
+    const PRIME: u32 = 0x0100_0193;
+    bytes
+        .iter()
+        .fold(OFFSET, |hash, &b| (hash ^ b as u32).wrapping_mul(PRIME))
+}
+
+impl BufferView<'_> {
+    /// Escreve um checksum por linha em `out` (bytes de padding são ignorados).
+    ///
+    /// Retorna o número de linhas escritas (limitado por `out.len()`).
+    pub fn row_checksums(&self, out: &mut [u32]) -> usize {
+        let mut count = 0;
+        for (y, slot) in (0..self.height()).zip(out.iter_mut()) {
+            if let Some(row) = self.row(y) {
+                *slot = fnv1a(row);
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+/// Escreve em `out` os índices das linhas cujo checksum mudou.
+///
+/// Compara apenas o prefixo comum de `prev` e `cur`; linhas a mais em `cur`
+/// contam como alteradas. Retorna o número de índices escritos.
+pub fn changed_rows(prev: &[u32], cur: &[u32], out: &mut [u32]) -> usize {
+    let changed = cur
+        .iter()
+        .enumerate()
+        .filter(|&(i, c)| prev.get(i) != Some(c))
+        .map(|(i, _)| i as u32);
+
+    let mut count = 0;
+    for (slot, index) in out.iter_mut().zip(changed) {
+        *slot = index;
+        count += 1;
+    }
+    count
+}
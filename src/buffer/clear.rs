@@ -0,0 +1,108 @@
+//! # Clear
+//!
+//! Preenchimento do buffer com uma cor (primitivas de `RenderOp::Clear` e
+//! `RenderOp::FillRect`).
+
+use super::words::fill_words;
+use super::BufferViewMut;
+use crate::color::{BlendMode, Color};
+use crate::damage::DamageBuffer;
+use crate::geometry::Rect;
+use crate::render::FillParams;
+
+/// Replica o pixel codificado em `span`.
+///
+/// Com 4 bytes por pixel e `span` alinhado a 4, escreve palavras `u32`;
+/// caso contrário copia o pixel byte a byte.
+#[inline]
+fn fill_span(span: &mut [u8], encoded: &[u8; 4], bpp: usize) {
+    if bpp == 4 && fill_words(span, u32::from_ne_bytes(*encoded)) {
+        return;
+    }
+    for px in span.chunks_exact_mut(bpp) {
+        px.copy_from_slice(&encoded[..bpp]);
+    }
+}
+
+impl BufferViewMut<'_> {
+    /// Preenche todos os pixels com a cor codificada no formato do buffer.
+    ///
+    /// Formatos de 32 bits em linhas alinhadas replicam uma palavra
+    /// pré-codificada; os demais copiam o pixel codificado byte a byte. Bytes
+    /// de padding do stride não são alterados.
+    pub fn clear_color(&mut self, c: Color) {
+        let format = self.format();
+        let bpp = format.bytes_per_pixel() as usize;
+        let mut encoded = [0u8; 4];
+        format.write_color(c, &mut encoded);
+
+        for y in 0..self.height() {
+            let row = match self.row_mut(y) {
+                Some(row) => row,
+                None => break,
+            };
+            fill_span(row, &encoded, bpp);
+        }
+    }
+
+    /// Preenche `rect` (recortado ao buffer) com a cor.
+    ///
+    /// Cada linha do retângulo usa o mesmo preenchimento por palavras de
+    /// `clear_color` quando o trecho está alinhado.
+    pub fn fill_rect(&mut self, rect: Rect, c: Color) {
+        let bounds = Rect::new(0, 0, self.width(), self.height());
+        let rect = match rect.intersection(&bounds) {
+            Some(r) => r,
+            None => return,
+        };
+
+        let format = self.format();
+        let bpp = format.bytes_per_pixel() as usize;
+        let mut encoded = [0u8; 4];
+        format.write_color(c, &mut encoded);
+
+        let start = rect.x as usize * bpp;
+        let end = start + rect.width as usize * bpp;
+        for y in rect.y as u32..rect.bottom() as u32 {
+            if let Some(row) = self.row_mut(y) {
+                fill_span(&mut row[start..end], &encoded, bpp);
+            }
+        }
+    }
+
+    /// Compõe `params.color` sobre `params.rect` usando `params.blend`.
+    ///
+    /// Implementa `RenderOp::FillRect`: cada pixel do destino é combinado
+    /// com a cor via [`BlendMode::blend_u8`](crate::color::BlendMode::blend_u8)
+    /// em vez de sobrescrito. `BlendMode::Normal` cai no caminho rápido de
+    /// `fill_rect`.
+    pub fn fill_rect_blended(&mut self, params: &FillParams) {
+        if params.blend == BlendMode::Normal {
+            self.fill_rect(params.rect, params.color);
+            return;
+        }
+        let rect = match params.rect.intersection(&self.descriptor().rect()) {
+            Some(r) => r,
+            None => return,
+        };
+
+        for y in rect.y as u32..rect.bottom() as u32 {
+            for x in rect.x as u32..rect.right() as u32 {
+                if let Some(dst) = self.get_pixel(x, y) {
+                    self.set_pixel(x, y, params.blend.blend_u8(params.color, dst));
+                }
+            }
+        }
+    }
+}
+
+/// Limpa apenas as regiões danificadas de `out` com `color`.
+///
+/// Cada região é recortada ao buffer e preenchida via `fill_rect`. Regiões
+/// sobrepostas apenas reescrevem a mesma cor, então o resultado é o mesmo
+/// de um clear completo restrito à união do damage.
+pub fn clear_regions(damage: &DamageBuffer, out: &mut BufferViewMut, color: Color) {
+    for region in damage.iter() {
+        out.fill_rect(region.rect, color);
+    }
+}
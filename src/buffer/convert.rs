@@ -0,0 +1,43 @@
+//! # Format Conversion
+//!
+//! Conversão de pixels entre formatos.
+
+use super::{BufferView, BufferViewMut, ConvertError};
+use crate::color::PixelFormat;
+
+impl BufferViewMut<'_> {
+    /// Converte `src` para o formato deste buffer.
+    ///
+    /// Pares rejeitados por `PixelFormat::can_convert` retornam
+    /// `ConvertError::Unsupported(origem, destino)`; as dimensões devem ser
+    /// iguais. Mesmo formato é uma cópia de linhas.
+    pub fn convert_from(&mut self, src: &BufferView) -> Result<(), ConvertError> {
+        let (from, to) = (src.format(), self.format());
+        if !PixelFormat::can_convert(from, to) {
+            return Err(ConvertError::Unsupported(from, to));
+        }
+        if src.width() != self.width() || src.height() != self.height() {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        let src_bpp = from.bytes_per_pixel() as usize;
+        let dst_bpp = to.bytes_per_pixel() as usize;
+        for y in 0..self.height() {
+            let (src_row, dst_row) = match (src.row(y), self.row_mut(y)) {
+                (Some(s), Some(d)) => (s, d),
+                _ => break,
+            };
+            if from == to {
+                dst_row.copy_from_slice(src_row);
+                continue;
+            }
+            for (s, d) in src_row
+                .chunks_exact(src_bpp)
+                .zip(dst_row.chunks_exact_mut(dst_bpp))
+            {
+                to.write_color(from.read_color(s), d);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+//! # Buffer Copy
+//!
+//! Cópias entre buffers.
+
+use super::{BufferView, BufferViewMut, ConvertError};
+
+impl BufferViewMut<'_> {
+    /// Copia `src` removendo o padding entre linhas.
+    ///
+    /// O destino deve ter as mesmas dimensões e formato de `src`, com
+    /// stride mínimo (ver `BufferDescriptor::normalize_stride`).
+    pub fn repack_tight(&mut self, src: &BufferView) -> Result<(), ConvertError> {
+        let dst = *self.descriptor();
+        if dst.format != src.format() {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        if dst.width != src.width() || dst.height != src.height() || !dst.is_tightly_packed() {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        let row_bytes = dst.bytes_per_row() as usize;
+        for y in 0..dst.height {
+            if let Some(row) = src.row(y) {
+                let start = y as usize * row_bytes;
+                self.data_mut()[start..start + row_bytes].copy_from_slice(row);
+            }
+        }
+        Ok(())
+    }
+}
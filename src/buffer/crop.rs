@@ -0,0 +1,30 @@
+//! # Crop
+//!
+//! Cópia de uma sub-região para um buffer próprio (feature `alloc`).
+
+use alloc::vec::Vec;
+
+use super::{BufferDescriptor, BufferView};
+use crate::geometry::Rect;
+
+impl BufferView<'_> {
+    /// Copia `rect` para um buffer novo, compacto (stride = largura × bpp).
+    ///
+    /// Retorna os dados e o descritor correspondente, ou `None` se `rect`
+    /// for vazio ou não estiver inteiramente dentro do buffer (mesma regra
+    /// de `sub_view`). Útil para extrair o conteúdo de uma janela em
+    /// miniaturas.
+    pub fn crop_to_vec(&self, rect: Rect) -> Option<(Vec<u8>, BufferDescriptor)> {
+        if rect.is_empty() {
+            return None;
+        }
+        let sub = self.sub_view(rect)?;
+        let desc = BufferDescriptor::new(rect.width, rect.height, self.format());
+
+        let mut data = Vec::with_capacity(desc.size_bytes());
+        for y in 0..sub.height() {
+            data.extend_from_slice(sub.row(y)?);
+        }
+        Some((data, desc))
+    }
+}
@@ -0,0 +1,32 @@
+//! # Desaturate
+//!
+//! Conversão de todo o buffer para tons de cinza.
+
+use super::BufferViewMut;
+
+impl BufferViewMut<'_> {
+    /// Converte todos os pixels para cinza, mantendo o alpha.
+    ///
+    /// Com `linear`, usa [`Color::to_grayscale_linear`]; caso contrário,
+    /// [`Color::to_grayscale`]. Formatos grayscale e `Alpha8` não mudam.
+    ///
+    /// [`Color::to_grayscale_linear`]: crate::color::Color::to_grayscale_linear
+    /// [`Color::to_grayscale`]: crate::color::Color::to_grayscale
+    pub fn desaturate(&mut self, linear: bool) {
+        if self.format().is_grayscale() {
+            return;
+        }
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                if let Some(color) = self.get_pixel(x, y) {
+                    let gray = if linear {
+                        color.to_grayscale_linear()
+                    } else {
+                        color.to_grayscale()
+                    };
+                    self.set_pixel(x, y, gray);
+                }
+            }
+        }
+    }
+}
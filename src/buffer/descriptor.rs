@@ -2,6 +2,7 @@
 //!
 //! Descritor de buffers de pixels.
 
+use super::BufferDescriptorBuilder;
 use crate::color::PixelFormat;
 use crate::geometry::{Rect, Size};
 
@@ -45,6 +46,12 @@ impl BufferDescriptor {
         }
     }
 
+    /// Inicia um builder com validação.
+    #[inline]
+    pub const fn builder(width: u32, height: u32) -> BufferDescriptorBuilder {
+        BufferDescriptorBuilder::new(width, height)
+    }
+
     /// Cria a partir de Size.
     #[inline]
     pub const fn from_size(size: Size, format: PixelFormat) -> Self {
@@ -69,6 +76,16 @@ impl BufferDescriptor {
         (self.stride as usize) * (self.height as usize)
     }
 
+    /// Menor slice que contém todos os pixels: `(height - 1) * stride`
+    /// mais os bytes efetivos da última linha (o padding final é opcional).
+    #[inline]
+    pub const fn min_data_len(&self) -> usize {
+        if self.width == 0 || self.height == 0 {
+            return 0;
+        }
+        self.row_offset(self.height - 1) + self.bytes_per_row() as usize
+    }
+
     /// Número total de pixels.
     #[inline]
     pub const fn pixel_count(&self) -> usize {
@@ -111,6 +128,18 @@ impl BufferDescriptor {
         self.stride - self.bytes_per_row()
     }
 
+    /// Verifica se as linhas são contíguas (stride == bytes por linha).
+    #[inline]
+    pub const fn is_tightly_packed(&self) -> bool {
+        self.stride == self.bytes_per_row()
+    }
+
+    /// Cópia do descritor com stride mínimo (sem padding).
+    #[inline]
+    pub const fn normalize_stride(&self) -> Self {
+        Self::new(self.width, self.height, self.format)
+    }
+
     /// Cria descritor para uma sub-região.
     #[inline]
     pub fn sub_region(&self, rect: Rect) -> Option<(Self, usize)> {
@@ -0,0 +1,61 @@
+//! # Buffer Diff
+//!
+//! Comparação de buffers para gerar damage.
+
+use super::BufferView;
+use crate::damage::DamageBuffer;
+use crate::geometry::{Rect, Size};
+
+impl BufferView<'_> {
+    /// Compara com o frame anterior tile a tile, registrando os tiles
+    /// alterados como damage.
+    ///
+    /// Tiles sujos adjacentes na mesma linha são unidos em faixas, e faixas
+    /// idênticas em linhas consecutivas são estendidas verticalmente.
+    /// Retorna sem registrar nada se os descritores forem diferentes.
+    pub fn diff(&self, prev: &BufferView, out: &mut DamageBuffer, tile: Size) {
+        if self.descriptor() != prev.descriptor() || tile.width == 0 || tile.height == 0 {
+            return;
+        }
+
+        let bpp = self.format().bytes_per_pixel() as usize;
+        let mut y = 0;
+        while y < self.height() {
+            let h = tile.height.min(self.height() - y);
+            let mut run: Option<Rect> = None;
+            let mut x = 0;
+            while x < self.width() {
+                let w = tile.width.min(self.width() - x);
+                let start = x as usize * bpp;
+                let end = (x + w) as usize * bpp;
+                let dirty = (y..y + h).any(|row| match (self.row(row), prev.row(row)) {
+                    (Some(a), Some(b)) => a[start..end] != b[start..end],
+                    _ => false,
+                });
+
+                if dirty {
+                    let rect = Rect::new(x as i32, y as i32, w, h);
+                    run = Some(run.map_or(rect, |r| r.union(&rect)));
+                } else if let Some(r) = run.take() {
+                    push_coalesced(out, r);
+                }
+                x += w;
+            }
+            if let Some(r) = run {
+                push_coalesced(out, r);
+            }
+            y += h;
+        }
+    }
+}
+
+/// Adiciona a faixa, estendendo uma região logo acima com mesma largura.
+fn push_coalesced(out: &mut DamageBuffer, rect: Rect) {
+    let above = out.as_mut_slice().iter_mut().find(|region| {
+        region.rect.x == rect.x && region.rect.width == rect.width && region.rect.bottom() == rect.y
+    });
+    match above {
+        Some(region) => region.rect.height += rect.height,
+        None => out.push(rect),
+    }
+}
@@ -0,0 +1,57 @@
+//! # Buffer Errors
+//!
+//! Erros de operações sobre buffers.
+
+use crate::color::PixelFormat;
+
+/// Erro em conversões e cópias entre buffers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConvertError {
+    /// Dimensões da fonte e do destino diferem.
+    SizeMismatch,
+    /// Formato de pixel não suportado pela operação.
+    UnsupportedFormat,
+    /// Conversão entre o par de formatos (origem, destino) não suportada.
+    Unsupported(PixelFormat, PixelFormat),
+}
+
+impl ConvertError {
+    /// Descrição do erro.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::SizeMismatch => "buffer size mismatch",
+            Self::UnsupportedFormat => "unsupported pixel format",
+            Self::Unsupported(..) => "unsupported format conversion",
+        }
+    }
+}
+
+/// Erro na criação de descritores de buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BufferError {
+    /// Largura ou altura zero.
+    EmptyDimensions,
+    /// Stride menor que os bytes efetivos por linha.
+    StrideTooSmall,
+    /// Alinhamento zero ou não potência de dois.
+    InvalidAlignment,
+    /// Stride explícito não respeita o alinhamento pedido.
+    StrideMisaligned,
+    /// Stride ou tamanho total não cabem no tipo inteiro.
+    Overflow,
+}
+
+impl BufferError {
+    /// Descrição do erro.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::EmptyDimensions => "buffer has zero width or height",
+            Self::StrideTooSmall => "stride smaller than row size",
+            Self::InvalidAlignment => "alignment must be a power of two",
+            Self::StrideMisaligned => "stride does not match alignment",
+            Self::Overflow => "buffer size overflows",
+        }
+    }
+}
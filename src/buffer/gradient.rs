@@ -0,0 +1,27 @@
+//! # Gradient Fill
+//!
+//! Preenchimento de retângulos com gradientes.
+
+use super::BufferViewMut;
+use crate::color::Gradient;
+use crate::geometry::{PointF, Rect};
+
+impl BufferViewMut<'_> {
+    /// Preenche `rect` (recortado ao buffer) com o gradiente.
+    ///
+    /// O gradiente é avaliado no centro de cada pixel, em coordenadas do
+    /// buffer, e o resultado substitui o conteúdo (sem composição).
+    pub fn fill_gradient_rect(&mut self, rect: Rect, gradient: &Gradient) {
+        let rect = match rect.intersection(&self.descriptor().rect()) {
+            Some(r) => r,
+            None => return,
+        };
+
+        for y in rect.y..rect.bottom() {
+            for x in rect.x..rect.right() {
+                let center = PointF::new(x as f32 + 0.5, y as f32 + 0.5);
+                self.set_pixel(x as u32, y as u32, gradient.sample(center));
+            }
+        }
+    }
+}
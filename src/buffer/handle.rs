@@ -1,7 +1,11 @@
 //! # Buffer Handle
 //!
 //! Handle opaco para buffers gerenciados pelo kernel.
-//
+
+use core::fmt;
+
+use crate::color::Color;
+
 /// Este handle é usado para referenciar buffers de display sem expor
 /// detalhes de implementação.
 #[repr(transparent)]
@@ -30,6 +34,12 @@ impl BufferHandle {
         self.0 == 0
     }
 
+    /// Verifica se é o valor sentinela reservado (`INVALID`).
+    #[inline]
+    pub const fn is_reserved(&self) -> bool {
+        self.0 == Self::INVALID.0
+    }
+
     /// Retorna o valor bruto.
     #[inline]
     pub const fn as_u64(&self) -> u64 {
@@ -71,6 +81,22 @@ impl BufferHandle {
     pub const fn from_id_gen(id: u32, generation: u32) -> Self {
         Self(((generation as u64) << 32) | (id as u64))
     }
+
+    /// Cor estável para tingir o buffer em modo de depuração.
+    #[inline]
+    pub fn debug_color(&self) -> Color {
+        Color::from_debug_id(self.0)
+    }
+}
+
+impl fmt::Display for BufferHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_reserved() {
+            f.write_str("Handle(INVALID)")
+        } else {
+            write!(f, "Handle(0x{:016x})", self.0)
+        }
+    }
 }
 
 impl From<u64> for BufferHandle {
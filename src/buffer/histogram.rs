@@ -0,0 +1,99 @@
+//! # Histogram
+//!
+//! Histograma de valores de pixel.
+
+use super::BufferView;
+use crate::color::PixelFormat;
+use crate::geometry::Rect;
+
+/// Histograma por canal (256 bins cada).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Histogram {
+    /// Canal vermelho.
+    pub r: [u32; 256],
+    /// Canal verde.
+    pub g: [u32; 256],
+    /// Canal azul.
+    pub b: [u32; 256],
+    /// Luminância.
+    pub luma: [u32; 256],
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Histogram {
+    /// Cria histograma zerado.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            r: [0; 256],
+            g: [0; 256],
+            b: [0; 256],
+            luma: [0; 256],
+        }
+    }
+
+    /// Número de amostras contadas.
+    #[inline]
+    pub fn total(&self) -> u32 {
+        self.luma.iter().sum()
+    }
+
+    /// Menor valor de luminância abaixo do qual está a fração `fraction`
+    /// (0.0-1.0) das amostras.
+    pub fn luma_percentile(&self, fraction: f32) -> u8 {
+        let total = self.total();
+        if total == 0 {
+            return 0;
+        }
+        let target = ((total as f32 * fraction.clamp(0.0, 1.0)) as u32).min(total - 1);
+        let mut acc = 0;
+        for (value, &count) in self.luma.iter().enumerate() {
+            acc += count;
+            if acc > target {
+                return value as u8;
+            }
+        }
+        u8::MAX
+    }
+}
+
+impl BufferView<'_> {
+    /// Calcula o histograma de uma região (recortada aos limites do buffer).
+    ///
+    /// Formatos grayscale preenchem apenas `luma`.
+    pub fn histogram(&self, region: Rect) -> Histogram {
+        let mut hist = Histogram::new();
+        let area = match region.intersection(&self.descriptor().rect()) {
+            Some(area) => area,
+            None => return hist,
+        };
+
+        let format = self.format();
+        for y in area.y..area.bottom() {
+            for x in area.x..area.right() {
+                let color = match self.get_pixel(x as u32, y as u32) {
+                    Some(color) => color,
+                    None => continue,
+                };
+                match format {
+                    PixelFormat::Alpha8 => hist.luma[color.alpha() as usize] += 1,
+                    PixelFormat::Gray8 | PixelFormat::Gray16 => {
+                        hist.luma[color.red() as usize] += 1
+                    }
+                    _ => {
+                        hist.r[color.red() as usize] += 1;
+                        hist.g[color.green() as usize] += 1;
+                        hist.b[color.blue() as usize] += 1;
+                        hist.luma[color.luminance() as usize] += 1;
+                    }
+                }
+            }
+        }
+        hist
+    }
+}
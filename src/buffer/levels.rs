@@ -0,0 +1,94 @@
+//! # Levels
+//!
+//! Ajuste de níveis (black/white point e gamma) e aplicação de LUTs.
+
+use super::BufferViewMut;
+use crate::color::{Color, ColorLut, PixelFormat};
+
+/// Percentil usado como black point em `auto_levels`.
+const AUTO_BLACK_PERCENTILE: f32 = 0.01;
+/// Percentil usado como white point em `auto_levels`.
+const AUTO_WHITE_PERCENTILE: f32 = 0.99;
+
+/// Monta a tabela de níveis para os parâmetros dados.
+fn levels_lut(in_black: u8, in_white: u8, gamma: f32) -> [u8; 256] {
+    let mut lut = [0u8; 256];
+    let range = (in_white - in_black) as f32;
+    let inv_gamma = if gamma > 0.0 { 1.0 / gamma } else { 1.0 };
+    for (value, out) in lut.iter_mut().enumerate() {
+        let clamped = (value as u8).clamp(in_black, in_white);
+        let mut norm = (clamped - in_black) as f32 / range;
+        if inv_gamma != 1.0 {
+            norm = rdsmath::powf(norm, inv_gamma);
+        }
+        *out = rdsmath::roundf(norm * 255.0) as u8;
+    }
+    lut
+}
+
+impl BufferViewMut<'_> {
+    /// Remapeia cada canal de cor através da transformação de níveis.
+    ///
+    /// Valores são limitados a `[in_black, in_white]`, normalizados para
+    /// 0.0-1.0 e elevados a `1 / gamma`. O alpha não é alterado.
+    pub fn adjust_levels(&mut self, in_black: u8, in_white: u8, gamma: f32) {
+        if in_white <= in_black || self.format() == PixelFormat::Alpha8 {
+            return;
+        }
+        let lut = levels_lut(in_black, in_white, gamma);
+        let grayscale = self.format().is_grayscale();
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let color = match self.get_pixel(x, y) {
+                    Some(color) => color,
+                    None => continue,
+                };
+                let adjusted = if grayscale {
+                    Color::gray(lut[color.red() as usize])
+                } else {
+                    Color::argb(
+                        color.alpha(),
+                        lut[color.red() as usize],
+                        lut[color.green() as usize],
+                        lut[color.blue() as usize],
+                    )
+                };
+                self.set_pixel(x, y, adjusted);
+            }
+        }
+    }
+
+    /// Estica o contraste usando os percentis 1% e 99% da luminância.
+    pub fn auto_levels(&mut self) {
+        let hist = self.as_view().histogram(self.descriptor().rect());
+        let black = hist.luma_percentile(AUTO_BLACK_PERCENTILE);
+        let white = hist.luma_percentile(AUTO_WHITE_PERCENTILE);
+        self.adjust_levels(black, white, 1.0);
+    }
+
+    /// Aplica uma LUT por canal a todo o buffer.
+    ///
+    /// Formatos grayscale usam a tabela do canal vermelho; `Alpha8` não é
+    /// alterado.
+    pub fn apply_lut(&mut self, lut: &ColorLut) {
+        let format = self.format();
+        if format == PixelFormat::Alpha8 {
+            return;
+        }
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let color = match self.get_pixel(x, y) {
+                    Some(color) => color,
+                    None => continue,
+                };
+                let mapped = if format.is_grayscale() {
+                    Color::gray(lut.r[color.red() as usize])
+                } else {
+                    lut.apply(color)
+                };
+                self.set_pixel(x, y, mapped);
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! # Mask
+//!
+//! Aplicação de máscaras de opacidade (soft clipping).
+
+use super::{BufferView, BufferViewMut, ConvertError};
+use crate::color::PixelFormat;
+
+impl BufferViewMut<'_> {
+    /// Multiplica o alpha de cada pixel pelo valor da máscara.
+    ///
+    /// A máscara deve ser `Gray8` ou `Alpha8` com as mesmas dimensões; o
+    /// destino precisa ter canal alpha. RGB não é alterado.
+    pub fn apply_mask(&mut self, mask: &BufferView) -> Result<(), ConvertError> {
+        if !matches!(mask.format(), PixelFormat::Gray8 | PixelFormat::Alpha8)
+            || !self.format().has_alpha()
+        {
+            return Err(ConvertError::UnsupportedFormat);
+        }
+        if mask.width() != self.width() || mask.height() != self.height() {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        for y in 0..self.height() {
+            let row = match mask.row(y) {
+                Some(row) => row,
+                None => break,
+            };
+            for (x, &m) in row.iter().enumerate() {
+                let x = x as u32;
+                if let Some(color) = self.get_pixel(x, y) {
+                    let alpha = (color.alpha() as u32 * m as u32 + 127) / 255;
+                    self.set_pixel(x, y, color.with_alpha(alpha as u8));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,14 +2,43 @@
 //!
 //! Buffers de pixels e descritores.
 
+mod age;
+mod builder;
+mod channel;
+mod checksum;
+mod clear;
+mod convert;
+mod copy;
+#[cfg(feature = "alloc")]
+mod crop;
+mod desaturate;
 mod descriptor;
+mod diff;
+mod error;
+mod gradient;
 mod handle;
+mod histogram;
+mod levels;
+mod mask;
+mod raster;
 mod region;
+mod rle;
+mod scale;
+mod swizzle;
+mod tiles;
 mod usage;
 mod view;
+mod words;
 
+pub use age::{BufferAge, MAX_BUFFER_AGE};
+pub use builder::BufferDescriptorBuilder;
+pub use checksum::changed_rows;
+pub use clear::clear_regions;
 pub use descriptor::BufferDescriptor;
+pub use error::{BufferError, ConvertError};
 pub use handle::BufferHandle;
+pub use histogram::Histogram;
 pub use region::BufferRegion;
+pub use rle::{decode_row_rle, encode_row_rle};
 pub use usage::{BufferCapabilities, BufferUsage};
 pub use view::{BufferView, BufferViewMut};
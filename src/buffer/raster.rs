@@ -0,0 +1,175 @@
+//! # Rasterization
+//!
+//! Rasterização de primitivas em software.
+
+use super::BufferViewMut;
+use crate::color::Color;
+use crate::geometry::{
+    FillRule, PointF, Rect, RectF, RoundedRect, StaticPolygon, MAX_STATIC_POINTS,
+};
+
+/// Subamostras verticais por pixel no preenchimento anti-aliased.
+const AA_SUBSAMPLES: usize = 4;
+
+/// Intervalos `[início, fim)` cobertos em uma linha de varredura.
+type Spans = [(f32, f32); MAX_STATIC_POINTS];
+
+impl BufferViewMut<'_> {
+    /// Preenche um retângulo arredondado com bordas anti-aliased.
+    ///
+    /// A cobertura de cada pixel (via `RoundedRect::coverage`) modula o
+    /// alpha de `color`, que é composto sobre o conteúdo existente.
+    pub fn rasterize_rounded_rect(&mut self, rr: RoundedRect, color: Color) {
+        let bounds = match rr.rect.round_out().intersection(&self.descriptor().rect()) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        for y in bounds.y..bounds.bottom() {
+            for x in bounds.x..bounds.right() {
+                let center = PointF::new(x as f32 + 0.5, y as f32 + 0.5);
+                let coverage = rr.coverage(center);
+                if coverage <= 0.0 {
+                    continue;
+                }
+                self.blend_pixel(x as u32, y as u32, color, coverage);
+            }
+        }
+    }
+
+    /// Preenche um polígono (sempre tratado como fechado) sem anti-aliasing.
+    ///
+    /// Um pixel é preenchido se o seu centro estiver dentro segundo `rule`.
+    pub fn fill_polygon(&mut self, poly: &StaticPolygon, color: Color, rule: FillRule) {
+        let bounds = match self.polygon_bounds(poly) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let mut spans = [(0.0, 0.0); MAX_STATIC_POINTS];
+        for y in bounds.y..bounds.bottom() {
+            let count = scanline_spans(poly, y as f32 + 0.5, rule, &mut spans);
+            for &(start, end) in &spans[..count] {
+                // Pixels cujo centro cai em [start, end)
+                let first = (rdsmath::ceilf(start - 0.5) as i32).max(bounds.x);
+                let last = (rdsmath::ceilf(end - 0.5) as i32).min(bounds.right());
+                for x in first..last {
+                    self.blend_pixel(x as u32, y as u32, color, 1.0);
+                }
+            }
+        }
+    }
+
+    /// Preenche um polígono com bordas anti-aliased.
+    ///
+    /// Cada pixel é amostrado em 4 linhas verticais; em cada linha a
+    /// cobertura horizontal é analítica (fração do pixel dentro do span). A
+    /// cobertura média modula o alpha de `color`, composto com `over`.
+    pub fn fill_polygon_aa(&mut self, poly: &StaticPolygon, color: Color, rule: FillRule) {
+        let bounds = match self.polygon_bounds(poly) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let mut spans = [[(0.0, 0.0); MAX_STATIC_POINTS]; AA_SUBSAMPLES];
+        let mut counts = [0usize; AA_SUBSAMPLES];
+        for y in bounds.y..bounds.bottom() {
+            for (s, (spans, count)) in spans.iter_mut().zip(counts.iter_mut()).enumerate() {
+                let sy = y as f32 + (s as f32 + 0.5) / AA_SUBSAMPLES as f32;
+                *count = scanline_spans(poly, sy, rule, spans);
+            }
+
+            for x in bounds.x..bounds.right() {
+                let (left, right) = (x as f32, x as f32 + 1.0);
+                let covered: f32 = spans
+                    .iter()
+                    .zip(counts)
+                    .flat_map(|(spans, count)| &spans[..count])
+                    .map(|&(start, end)| (end.min(right) - start.max(left)).max(0.0))
+                    .sum();
+                let coverage = (covered / AA_SUBSAMPLES as f32).min(1.0);
+                if coverage > 0.0 {
+                    self.blend_pixel(x as u32, y as u32, color, coverage);
+                }
+            }
+        }
+    }
+
+    /// Compõe `color` com alpha escalado por `coverage` sobre o pixel.
+    #[inline]
+    fn blend_pixel(&mut self, x: u32, y: u32, color: Color, coverage: f32) {
+        if let Some(dst) = self.get_pixel(x, y) {
+            let src = color.multiply_alpha(coverage);
+            self.set_pixel(x, y, src.over(dst));
+        }
+    }
+
+    /// Pixels cobertos pela caixa do polígono, recortados ao buffer.
+    fn polygon_bounds(&self, poly: &StaticPolygon) -> Option<Rect> {
+        if poly.len() < 3 {
+            return None;
+        }
+        let (mut l, mut t) = (f32::MAX, f32::MAX);
+        let (mut r, mut b) = (f32::MIN, f32::MIN);
+        for p in poly.iter() {
+            l = l.min(p.x);
+            t = t.min(p.y);
+            r = r.max(p.x);
+            b = b.max(p.y);
+        }
+        RectF::from_ltrb(l, t, r, b)
+            .round_out()
+            .intersection(&self.descriptor().rect())
+    }
+}
+
+/// Intervalos da linha horizontal `y` que ficam dentro do polígono.
+///
+/// Retorna o número de intervalos escritos em `spans`, ordenados por x.
+fn scanline_spans(poly: &StaticPolygon, y: f32, rule: FillRule, spans: &mut Spans) -> usize {
+    // Cruzamentos (x, direção) com as arestas; regra semiaberta nos vértices
+    let mut crossings = [(0.0f32, 0i32); MAX_STATIC_POINTS];
+    let mut count = 0;
+    let n = poly.len();
+    for i in 0..n {
+        let (a, b) = match (poly.get(i), poly.get((i + 1) % n)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => continue,
+        };
+        if (a.y <= y) == (b.y <= y) {
+            continue;
+        }
+        let x = a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y);
+        let dir = if b.y > a.y { 1 } else { -1 };
+
+        // Inserção ordenada (no máximo MAX_STATIC_POINTS arestas)
+        let mut j = count;
+        while j > 0 && crossings[j - 1].0 > x {
+            crossings[j] = crossings[j - 1];
+            j -= 1;
+        }
+        crossings[j] = (x, dir);
+        count += 1;
+    }
+
+    let inside = |winding: i32| match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding & 1 != 0,
+    };
+    let mut winding = 0;
+    let mut start = 0.0;
+    let mut written = 0;
+    for &(x, dir) in &crossings[..count] {
+        let was_inside = inside(winding);
+        winding += dir;
+        match (was_inside, inside(winding)) {
+            (false, true) => start = x,
+            (true, false) => {
+                spans[written] = (start, x);
+                written += 1;
+            }
+            _ => {}
+        }
+    }
+    written
+}
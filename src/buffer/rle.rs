@@ -0,0 +1,60 @@
+//! # Run-Length Encoding
+//!
+//! Compressão RLE de uma linha de pixels, para transferir UI de cores
+//! sólidas com pouco custo.
+//!
+//! Formato: sequência de blocos `[n, pixel...]`, onde `n` (1-255) é o
+//! número de repetições e `pixel` ocupa `bpp` bytes. Linhas ruidosas crescem
+//! no máximo `1 / bpp` em relação ao original.
+
+/// Comprime uma linha de pixels com `bpp` bytes cada.
+///
+/// Retorna o número de bytes escritos em `out`, ou `None` se `out` for
+/// pequeno demais, `bpp` for zero ou a linha não tiver pixels inteiros.
+pub fn encode_row_rle(row: &[u8], bpp: u32, out: &mut [u8]) -> Option<usize> {
+    let bpp = bpp as usize;
+    if bpp == 0 || !row.len().is_multiple_of(bpp) {
+        return None;
+    }
+
+    let mut written = 0;
+    let mut pixels = row.chunks_exact(bpp).peekable();
+    while let Some(pixel) = pixels.next() {
+        let mut run = 1u8;
+        while run < u8::MAX && pixels.peek() == Some(&pixel) {
+            pixels.next();
+            run += 1;
+        }
+        let block = out.get_mut(written..written + 1 + bpp)?;
+        block[0] = run;
+        block[1..].copy_from_slice(pixel);
+        written += 1 + bpp;
+    }
+    Some(written)
+}
+
+/// Descomprime uma linha gerada por [`encode_row_rle`].
+///
+/// Retorna o número de bytes escritos em `out`, ou `None` se `out` for
+/// pequeno demais, `bpp` for zero ou `src` estiver malformado.
+pub fn decode_row_rle(src: &[u8], bpp: u32, out: &mut [u8]) -> Option<usize> {
+    let bpp = bpp as usize;
+    if bpp == 0 || !src.len().is_multiple_of(1 + bpp) {
+        return None;
+    }
+
+    let mut written = 0;
+    for block in src.chunks_exact(1 + bpp) {
+        let run = block[0] as usize;
+        if run == 0 {
+            return None;
+        }
+        let pixel = &block[1..];
+        let span = out.get_mut(written..written + run * bpp)?;
+        for px in span.chunks_exact_mut(bpp) {
+            px.copy_from_slice(pixel);
+        }
+        written += run * bpp;
+    }
+    Some(written)
+}
@@ -0,0 +1,77 @@
+//! # Scale
+//!
+//! Redimensionamento de buffers.
+
+use super::{BufferView, BufferViewMut, ConvertError};
+use crate::color::{linear_to_srgb, srgb_to_linear, Color};
+
+/// Intervalo de origem coberto pelo pixel `d` de destino, com o peso de
+/// cada pixel de origem chamado via `f(indice, peso)`.
+#[inline]
+fn for_each_covered(d: u32, src_len: u32, dst_len: u32, mut f: impl FnMut(u32, f32)) {
+    let scale = src_len as f32 / dst_len as f32;
+    let start = d as f32 * scale;
+    let end = ((d + 1) as f32 * scale).min(src_len as f32);
+    let mut s = start as u32;
+    while (s as f32) < end && s < src_len {
+        let weight = end.min((s + 1) as f32) - start.max(s as f32);
+        if weight > 0.0 {
+            f(s, weight);
+        }
+        s += 1;
+    }
+}
+
+impl BufferViewMut<'_> {
+    /// Reduz `src` para o tamanho deste buffer com filtro de caixa.
+    ///
+    /// Cada pixel de destino é a média dos pixels de origem que ele cobre,
+    /// ponderada pela área de cobertura. A média é feita em luz linear e com
+    /// alpha premultiplicado, então pixels transparentes não escurecem as
+    /// bordas.
+    pub fn downscale_box(&mut self, src: &BufferView) -> Result<(), ConvertError> {
+        let (dw, dh) = (self.width(), self.height());
+        let (sw, sh) = (src.width(), src.height());
+        if dw == 0 || dh == 0 || sw == 0 || sh == 0 {
+            return Err(ConvertError::SizeMismatch);
+        }
+
+        for dy in 0..dh {
+            for dx in 0..dw {
+                // Acumuladores: RGB premultiplicado linear, alpha e peso
+                let mut acc = [0.0f32; 5];
+                for_each_covered(dy, sh, dh, |sy, wy| {
+                    for_each_covered(dx, sw, dw, |sx, wx| {
+                        let c = match src.get_pixel(sx, sy) {
+                            Some(c) => c,
+                            None => return,
+                        };
+                        let w = wx * wy;
+                        let a = c.alpha() as f32 / 255.0;
+                        acc[0] += srgb_to_linear(c.red() as f32 / 255.0) * a * w;
+                        acc[1] += srgb_to_linear(c.green() as f32 / 255.0) * a * w;
+                        acc[2] += srgb_to_linear(c.blue() as f32 / 255.0) * a * w;
+                        acc[3] += a * w;
+                        acc[4] += w;
+                    });
+                });
+
+                let color = if acc[3] <= 0.0 || acc[4] <= 0.0 {
+                    Color::TRANSPARENT
+                } else {
+                    let encode = |v: f32| {
+                        rdsmath::roundf(linear_to_srgb((v / acc[3]).clamp(0.0, 1.0)) * 255.0) as u8
+                    };
+                    Color::argb(
+                        rdsmath::roundf(acc[3] / acc[4] * 255.0) as u8,
+                        encode(acc[0]),
+                        encode(acc[1]),
+                        encode(acc[2]),
+                    )
+                };
+                self.set_pixel(dx, dy, color);
+            }
+        }
+        Ok(())
+    }
+}
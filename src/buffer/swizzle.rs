@@ -0,0 +1,36 @@
+//! # Swizzle
+//!
+//! Reordenação de canais em todo o buffer.
+
+use super::BufferViewMut;
+use crate::color::PixelFormat;
+
+impl BufferViewMut<'_> {
+    /// Troca os canais vermelho e azul de todo o buffer.
+    ///
+    /// Opera palavra a palavra em formatos de 32 bits; outros formatos não
+    /// são alterados. Útil quando o driver reporta a ordem de bytes errada.
+    pub fn swap_rb_inplace(&mut self) {
+        // Posição (em bits) dos bytes R e B dentro da palavra little-endian
+        let (low, high) = match self.format() {
+            PixelFormat::ARGB8888 | PixelFormat::XRGB8888 => (8, 24),
+            PixelFormat::BGRA8888 | PixelFormat::RGBA8888 => (0, 16),
+            _ => return,
+        };
+        let keep = !((0xFFu32 << low) | (0xFFu32 << high));
+
+        for y in 0..self.height() {
+            let row = match self.row_mut(y) {
+                Some(row) => row,
+                None => break,
+            };
+            for px in row.chunks_exact_mut(4) {
+                let w = u32::from_le_bytes([px[0], px[1], px[2], px[3]]);
+                let lo = (w >> low) & 0xFF;
+                let hi = (w >> high) & 0xFF;
+                let swapped = (w & keep) | (lo << high) | (hi << low);
+                px.copy_from_slice(&swapped.to_le_bytes());
+            }
+        }
+    }
+}
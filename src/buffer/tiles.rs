@@ -0,0 +1,38 @@
+//! # Buffer Tiles
+//!
+//! Iteração de um buffer em blocos (tiles).
+
+use super::BufferView;
+use crate::geometry::{Rect, Size};
+
+impl<'a> BufferView<'a> {
+    /// Percorre o buffer em tiles do tamanho dado, em ordem de linhas.
+    ///
+    /// Tiles nas bordas direita e inferior são recortados. Um tamanho de
+    /// tile vazio não produz nenhum tile.
+    pub fn tiles(&self, tile: Size) -> impl Iterator<Item = (Rect, BufferView<'a>)> {
+        let view = *self;
+        let (cols, rows) = if tile.width == 0 || tile.height == 0 {
+            (0, 0)
+        } else {
+            (
+                view.width().div_ceil(tile.width),
+                view.height().div_ceil(tile.height),
+            )
+        };
+
+        (0..rows).flat_map(move |row| {
+            (0..cols).filter_map(move |col| {
+                let x = col * tile.width;
+                let y = row * tile.height;
+                let rect = Rect::new(
+                    x as i32,
+                    y as i32,
+                    tile.width.min(view.width() - x),
+                    tile.height.min(view.height() - y),
+                );
+                view.sub_view(rect).map(|sub| (rect, sub))
+            })
+        })
+    }
+}
@@ -3,7 +3,8 @@
 //! Views para acesso a buffers.
 
 use crate::buffer::BufferDescriptor;
-use crate::color::PixelFormat;
+use crate::color::{Color, PixelFormat};
+use crate::geometry::Rect;
 
 /// View imutável de um buffer de pixels.
 #[derive(Clone, Copy, Debug)]
@@ -16,9 +17,12 @@ pub struct BufferView<'a> {
 
 impl<'a> BufferView<'a> {
     /// Cria nova view.
+    ///
+    /// Exige `desc.min_data_len()` bytes: a última linha não precisa do
+    /// padding do stride (como em uma sub-view).
     #[inline]
     pub fn new(data: &'a [u8], desc: BufferDescriptor) -> Option<Self> {
-        if data.len() >= desc.size_bytes() {
+        if data.len() >= desc.min_data_len() {
             Some(Self { data, desc })
         } else {
             None
@@ -28,7 +32,7 @@ impl<'a> BufferView<'a> {
     /// Cria view sem verificação de tamanho.
     ///
     /// # Safety
-    /// O slice deve ter pelo menos `desc.size_bytes()` bytes.
+    /// O slice deve ter pelo menos `desc.min_data_len()` bytes.
     #[inline]
     pub unsafe fn new_unchecked(data: &'a [u8], desc: BufferDescriptor) -> Self {
         Self { data, desc }
@@ -89,6 +93,26 @@ impl<'a> BufferView<'a> {
         }
         Some(self.desc.pixel_offset(x, y))
     }
+
+    /// Lê a cor de um pixel.
+    #[inline]
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        let offset = self.pixel_offset(x, y)?;
+        Some(self.desc.format.read_color(&self.data[offset..]))
+    }
+
+    /// Cria view de uma sub-região (deve estar inteiramente dentro do buffer).
+    ///
+    /// A sub-view compartilha o stride do buffer original; seus dados são
+    /// recortados para exatamente `min_data_len()` bytes.
+    #[inline]
+    pub fn sub_view(&self, rect: Rect) -> Option<BufferView<'a>> {
+        let (desc, offset) = self.desc.sub_region(rect)?;
+        Some(BufferView {
+            data: &self.data[offset..offset + desc.min_data_len()],
+            desc,
+        })
+    }
 }
 
 /// View mutável de um buffer de pixels.
@@ -104,7 +128,7 @@ impl<'a> BufferViewMut<'a> {
     /// Cria nova view mutável.
     #[inline]
     pub fn new(data: &'a mut [u8], desc: BufferDescriptor) -> Option<Self> {
-        if data.len() >= desc.size_bytes() {
+        if data.len() >= desc.min_data_len() {
             Some(Self { data, desc })
         } else {
             None
@@ -114,7 +138,7 @@ impl<'a> BufferViewMut<'a> {
     /// Cria view sem verificação de tamanho.
     ///
     /// # Safety
-    /// O slice deve ter pelo menos `desc.size_bytes()` bytes.
+    /// O slice deve ter pelo menos `desc.min_data_len()` bytes.
     #[inline]
     pub unsafe fn new_unchecked(data: &'a mut [u8], desc: BufferDescriptor) -> Self {
         Self { data, desc }
@@ -173,6 +197,45 @@ impl<'a> BufferViewMut<'a> {
         Some(&mut self.data[start..end])
     }
 
+    /// Obtém offset de um pixel.
+    #[inline]
+    pub fn pixel_offset(&self, x: u32, y: u32) -> Option<usize> {
+        if x >= self.desc.width || y >= self.desc.height {
+            return None;
+        }
+        Some(self.desc.pixel_offset(x, y))
+    }
+
+    /// Lê a cor de um pixel.
+    #[inline]
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        let offset = self.pixel_offset(x, y)?;
+        Some(self.desc.format.read_color(&self.data[offset..]))
+    }
+
+    /// Escreve a cor de um pixel. Retorna `false` se fora dos limites.
+    #[inline]
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) -> bool {
+        match self.pixel_offset(x, y) {
+            Some(offset) => {
+                self.desc
+                    .format
+                    .write_color(color, &mut self.data[offset..]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// View imutável sobre os mesmos dados.
+    #[inline]
+    pub fn as_view(&self) -> BufferView<'_> {
+        BufferView {
+            data: self.data,
+            desc: self.desc,
+        }
+    }
+
     /// Preenche o buffer com um valor.
     #[inline]
     pub fn fill(&mut self, value: u8) {
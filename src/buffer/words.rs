@@ -0,0 +1,72 @@
+//! # Word Views
+//!
+//! Acesso aos pixels de 32 bits como palavras `u32`.
+
+use super::{BufferDescriptor, BufferView, BufferViewMut};
+
+/// Número de bytes cobertos pela view de palavras, se o buffer permitir.
+#[inline]
+fn word_bytes(desc: &BufferDescriptor) -> Option<usize> {
+    if !desc.format.is_32bit() || !desc.is_tightly_packed() {
+        return None;
+    }
+    Some(desc.width as usize * desc.height as usize * 4)
+}
+
+/// `bytes` como palavras `u32`, se estiver alinhado e tiver tamanho
+/// múltiplo de 4.
+#[inline]
+fn words_mut(bytes: &mut [u8]) -> Option<&mut [u32]> {
+    // SAFETY: qualquer padrão de bits é um u32 válido; `align_to_mut` só
+    // reinterpreta a parte alinhada e exigimos que ela cubra tudo.
+    let (prefix, words, suffix) = unsafe { bytes.align_to_mut::<u32>() };
+    if prefix.is_empty() && suffix.is_empty() {
+        Some(words)
+    } else {
+        None
+    }
+}
+
+/// Preenche `bytes` com `word` palavra a palavra.
+///
+/// Retorna `false` (sem escrever nada) se `bytes` não estiver alinhado a 4
+/// ou não tiver tamanho múltiplo de 4.
+#[inline]
+pub(crate) fn fill_words(bytes: &mut [u8], word: u32) -> bool {
+    match words_mut(bytes) {
+        Some(words) => {
+            words.fill(word);
+            true
+        }
+        None => false,
+    }
+}
+
+impl BufferView<'_> {
+    /// Pixels como `&[u32]` (um elemento por pixel, em ordem de linhas).
+    ///
+    /// Exige formato de 32 bits, stride sem padding e dados alinhados a 4
+    /// bytes; caso contrário retorna `None`. As palavras estão na ordem de
+    /// bytes nativa: um pixel `ARGB8888` (bytes `A, R, G, B`) lê como
+    /// `0xBBGGRRAA` em hosts little-endian e `0xAARRGGBB` em big-endian.
+    pub fn as_u32_slice(&self) -> Option<&[u32]> {
+        let bytes = self.data().get(..word_bytes(self.descriptor())?)?;
+        // SAFETY: qualquer padrão de bits é um u32 válido; `align_to` só
+        // reinterpreta a parte alinhada e exigimos que ela cubra tudo.
+        let (prefix, words, suffix) = unsafe { bytes.align_to::<u32>() };
+        if prefix.is_empty() && suffix.is_empty() {
+            Some(words)
+        } else {
+            None
+        }
+    }
+}
+
+impl BufferViewMut<'_> {
+    /// Pixels como `&mut [u32]`; mesmas condições de
+    /// [`BufferView::as_u32_slice`].
+    pub fn as_u32_slice_mut(&mut self) -> Option<&mut [u32]> {
+        let len = word_bytes(self.descriptor())?;
+        words_mut(self.data_mut().get_mut(..len)?)
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Modos de blending e composição de cores.
 
+use super::{Color, ColorF};
+
 // =============================================================================
 // BLEND MODE
 // =============================================================================
@@ -92,6 +94,28 @@ impl BlendMode {
         !matches!(self, Self::Normal | Self::Clear)
     }
 
+    /// Modo que desfaz este quando aplicado com a mesma fonte.
+    ///
+    /// Apenas `Add` e `Subtract` se desfazem mutuamente, e só de forma exata
+    /// com fonte e destino opacos e sem saturar em 0 ou 1: com alpha
+    /// translúcido `blend` pondera cada lado pelo seu alpha e compõe o alpha
+    /// do resultado, então a volta não reproduz o destino. Os demais perdem
+    /// informação: `Difference`, por exemplo, descarta o sinal de `dst - src`.
+    #[inline]
+    pub const fn inverse(&self) -> Option<BlendMode> {
+        match self {
+            Self::Add => Some(Self::Subtract),
+            Self::Subtract => Some(Self::Add),
+            _ => None,
+        }
+    }
+
+    /// Verifica se o modo pode ser desfeito (ver [`inverse`](Self::inverse)).
+    #[inline]
+    pub const fn is_invertible(&self) -> bool {
+        self.inverse().is_some()
+    }
+
     /// Converte de u8.
     #[inline]
     pub fn from_u8(value: u8) -> Option<Self> {
@@ -156,6 +180,296 @@ impl BlendMode {
     }
 }
 
+// =============================================================================
+// BLEND OPERATIONS
+// =============================================================================
+
+impl BlendMode {
+    /// Combina `src` sobre `dst` em ponto flutuante (alpha straight).
+    ///
+    /// Porter-Duff usa os fatores clássicos; os modos separáveis seguem a
+    /// fórmula da especificação W3C Compositing; `Add` soma e `Subtract`
+    /// subtrai as cores premultiplicadas.
+    pub fn blend(&self, src: ColorF, dst: ColorF) -> ColorF {
+        let src = src.saturate();
+        let dst = dst.saturate();
+        let (sa, da) = (src.a, dst.a);
+
+        let (a, channel): (f32, &dyn Fn(f32, f32) -> f32) = match self {
+            Self::Normal => return src,
+            Self::Add => ((sa + da).min(1.0), &|cs: f32, cb: f32| {
+                (cs * sa + cb * da).min(1.0)
+            }),
+            Self::Subtract => (sa + da * (1.0 - sa), &|cs: f32, cb: f32| {
+                (cb * da - cs * sa).max(0.0)
+            }),
+            mode if mode.is_porter_duff() => {
+                let (fa, fb) = mode.porter_duff_factors(sa, da, 1.0);
+                (sa * fa + da * fb, &move |cs: f32, cb: f32| {
+                    cs * sa * fa + cb * da * fb
+                })
+            }
+            mode => (sa + da * (1.0 - sa), &move |cs: f32, cb: f32| {
+                cs * sa * (1.0 - da) + cb * da * (1.0 - sa) + sa * da * mode.separable_f(cb, cs)
+            }),
+        };
+
+        if a <= 0.0 {
+            return ColorF::TRANSPARENT;
+        }
+        ColorF::new(
+            (channel(src.r, dst.r) / a).min(1.0),
+            (channel(src.g, dst.g) / a).min(1.0),
+            (channel(src.b, dst.b) / a).min(1.0),
+            a,
+        )
+    }
+
+    /// Combina `src` sobre `dst` em aritmética inteira (alpha straight).
+    ///
+    /// Equivale a [`blend`](Self::blend) com erro de no máximo ±1 por canal:
+    /// cada divisão por 255 arredonda para o inteiro mais próximo. `SoftLight`
+    /// (que exige raiz quadrada) delega para a versão em ponto flutuante.
+    pub fn blend_u8(&self, src: Color, dst: Color) -> Color {
+        let (sa, da) = (src.alpha() as u32, dst.alpha() as u32);
+
+        // `a2` é o alpha resultante na escala 255*255; `channel` devolve a
+        // cor premultiplicada na escala 255*255*255.
+        let (a2, channel): (u32, &dyn Fn(u32, u32) -> u32) = match self {
+            Self::Normal => return src,
+            Self::SourceOver => return src.over(dst),
+            Self::SoftLight => {
+                let out = self.blend(src.to_float(), dst.to_float());
+                let to_u8 = |v: f32| rdsmath::roundf(v * 255.0) as u8;
+                return Color::argb(to_u8(out.a), to_u8(out.r), to_u8(out.g), to_u8(out.b));
+            }
+            Self::Add => ((sa + da).min(255) * 255, &|cs: u32, cb: u32| {
+                (cs * sa + cb * da).min(255 * 255) * 255
+            }),
+            Self::Subtract => (sa * 255 + da * (255 - sa), &|cs: u32, cb: u32| {
+                (cb * da).saturating_sub(cs * sa) * 255
+            }),
+            mode if mode.is_porter_duff() => {
+                let (fa, fb) = mode.porter_duff_factors(sa, da, 255);
+                (sa * fa + da * fb, &move |cs: u32, cb: u32| {
+                    cs * sa * fa + cb * da * fb
+                })
+            }
+            mode => (sa * 255 + da * (255 - sa), &move |cs: u32, cb: u32| {
+                cs * sa * (255 - da) + cb * da * (255 - sa) + sa * da * mode.separable_u8(cb, cs)
+            }),
+        };
+
+        if a2 == 0 {
+            return Color::TRANSPARENT;
+        }
+        let unpremultiply = |cs: u8, cb: u8| {
+            let num = channel(cs as u32, cb as u32);
+            ((num + a2 / 2) / a2).min(255) as u8
+        };
+        Color::argb(
+            div_255(a2) as u8,
+            unpremultiply(src.red(), dst.red()),
+            unpremultiply(src.green(), dst.green()),
+            unpremultiply(src.blue(), dst.blue()),
+        )
+    }
+
+    /// Fatores Porter-Duff `(Fa, Fb)` para alphas na escala `one`.
+    #[inline]
+    fn porter_duff_factors<T>(&self, sa: T, da: T, one: T) -> (T, T)
+    where
+        T: Copy + Default + core::ops::Sub<Output = T>,
+    {
+        let zero = T::default();
+        match self {
+            Self::SourceOver => (one, one - sa),
+            Self::SourceIn => (da, zero),
+            Self::SourceOut => (one - da, zero),
+            Self::SourceAtop => (da, one - sa),
+            Self::DestOver => (one - da, one),
+            Self::DestIn => (zero, sa),
+            Self::DestOut => (zero, one - sa),
+            Self::DestAtop => (one - da, sa),
+            Self::Xor => (one - da, one - sa),
+            _ => (zero, zero),
+        }
+    }
+
+    /// Função de mistura separável `B(cb, cs)` em ponto flutuante.
+    ///
+    /// Modos Porter-Duff e `Normal` retornam a cor de origem.
+    pub(crate) fn separable_f(&self, cb: f32, cs: f32) -> f32 {
+        match self {
+            Self::Multiply => cb * cs,
+            Self::Screen => cb + cs - cb * cs,
+            Self::Overlay => Self::HardLight.separable_f(cs, cb),
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+            Self::ColorDodge => {
+                if cb <= 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if cs <= 0.5 {
+                    Self::Multiply.separable_f(cb, 2.0 * cs)
+                } else {
+                    Self::Screen.separable_f(cb, 2.0 * cs - 1.0)
+                }
+            }
+            Self::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        rdsmath::sqrtf(cb)
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            Self::Difference => (cb - cs).abs(),
+            Self::Exclusion => cb + cs - 2.0 * cb * cs,
+            Self::Add => (cb + cs).min(1.0),
+            Self::Subtract => (cb - cs).max(0.0),
+            _ => cs,
+        }
+    }
+
+    /// Função de mistura separável `B(cb, cs)` na escala 0-255.
+    fn separable_u8(&self, cb: u32, cs: u32) -> u32 {
+        match self {
+            Self::Multiply => div_255(cb * cs),
+            Self::Screen => cb + cs - div_255(cb * cs),
+            Self::Overlay => Self::HardLight.separable_u8(cs, cb),
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+            Self::ColorDodge => {
+                if cb == 0 {
+                    0
+                } else if cs >= 255 {
+                    255
+                } else {
+                    (cb * 255 / (255 - cs)).min(255)
+                }
+            }
+            Self::ColorBurn => {
+                if cb >= 255 {
+                    255
+                } else {
+                    ((255 - cb) * 255)
+                        .checked_div(cs)
+                        .map_or(0, |v| 255 - v.min(255))
+                }
+            }
+            Self::HardLight => {
+                if cs * 2 <= 255 {
+                    div_255(cb * cs * 2)
+                } else {
+                    Self::Screen.separable_u8(cb, cs * 2 - 255)
+                }
+            }
+            Self::Difference => cb.abs_diff(cs),
+            Self::Exclusion => cb + cs - div_255(2 * cb * cs),
+            _ => cs,
+        }
+    }
+}
+
+/// Divide por 255 arredondando para o inteiro mais próximo.
+#[inline]
+const fn div_255(value: u32) -> u32 {
+    (value + 127) / 255
+}
+
+// =============================================================================
+// BLEND TABLE
+// =============================================================================
+
+/// Tabela pré-computada para `SourceOver` de uma cor fixa.
+///
+/// Útil para overlays sólidos aplicados sobre muitos fundos: a cor de origem
+/// é premultiplicada uma vez e o termo `d * (255 - alpha) / 255` vem de uma
+/// tabela de 256 entradas. Cada termo é arredondado separadamente, então o
+/// resultado pode diferir de [`Color::over`] em ±1 por canal.
+#[derive(Clone, Copy, Debug)]
+pub struct BlendTable {
+    /// Cor de origem.
+    src: Color,
+    /// Canais RGB e alpha da origem premultiplicados.
+    premultiplied: [u8; 4],
+    /// `round(v * (255 - alpha) / 255)` para cada valor `v`.
+    inverse: [u8; 256],
+}
+
+impl BlendTable {
+    /// Pré-computa a tabela para a cor de origem.
+    pub fn new(src: Color) -> Self {
+        let sa = src.alpha() as u32;
+        let premultiply = |c: u8| div_255(c as u32 * sa) as u8;
+        let mut inverse = [0u8; 256];
+        for (value, entry) in inverse.iter_mut().enumerate() {
+            *entry = div_255(value as u32 * (255 - sa)) as u8;
+        }
+        Self {
+            src,
+            premultiplied: [
+                premultiply(src.red()),
+                premultiply(src.green()),
+                premultiply(src.blue()),
+                src.alpha(),
+            ],
+            inverse,
+        }
+    }
+
+    /// Cor de origem.
+    #[inline]
+    pub const fn source(&self) -> Color {
+        self.src
+    }
+
+    /// Entrada da tabela: `round(value * (255 - alpha) / 255)`.
+    #[inline]
+    pub const fn factor(&self, value: u8) -> u8 {
+        self.inverse[value as usize]
+    }
+
+    /// Aplica `SourceOver` da origem sobre `dst`.
+    ///
+    /// Destinos opacos usam apenas a tabela; os demais delegam para
+    /// [`Color::over`].
+    #[inline]
+    pub fn blend(&self, dst: Color) -> Color {
+        if !dst.is_opaque() {
+            return self.src.over(dst);
+        }
+        let [r, g, b, _] = self.premultiplied;
+        let add = |s: u8, d: u8| s.saturating_add(self.factor(d));
+        Color::argb(
+            255,
+            add(r, dst.red()),
+            add(g, dst.green()),
+            add(b, dst.blue()),
+        )
+    }
+}
+
 // =============================================================================
 // ALPHA MODE
 // =============================================================================
@@ -0,0 +1,69 @@
+//! # Color Channel
+//!
+//! Canais individuais de uma cor.
+
+use super::Color;
+
+/// Canal de cor.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// Vermelho.
+    Red = 0,
+    /// Verde.
+    Green = 1,
+    /// Azul.
+    Blue = 2,
+    /// Alpha.
+    Alpha = 3,
+}
+
+impl Channel {
+    /// Todos os canais.
+    pub const ALL: [Channel; 4] = [Self::Red, Self::Green, Self::Blue, Self::Alpha];
+
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Red),
+            1 => Some(Self::Green),
+            2 => Some(Self::Blue),
+            3 => Some(Self::Alpha),
+            _ => None,
+        }
+    }
+
+    /// Lê o valor do canal de uma cor.
+    #[inline]
+    pub const fn get(&self, color: Color) -> u8 {
+        match self {
+            Self::Red => color.red(),
+            Self::Green => color.green(),
+            Self::Blue => color.blue(),
+            Self::Alpha => color.alpha(),
+        }
+    }
+
+    /// Retorna a cor com o canal substituído.
+    #[inline]
+    pub const fn set(&self, color: Color, value: u8) -> Color {
+        match self {
+            Self::Red => color.with_red(value),
+            Self::Green => color.with_green(value),
+            Self::Blue => color.with_blue(value),
+            Self::Alpha => color.with_alpha(value),
+        }
+    }
+
+    /// Nome do canal.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Red => "Red",
+            Self::Green => "Green",
+            Self::Blue => "Blue",
+            Self::Alpha => "Alpha",
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Representação de cores ARGB.
 
+use super::{linear_to_srgb, srgb_to_linear, PixelFormat};
+
 // =============================================================================
 // COLOR (32-bit ARGB)
 // =============================================================================
@@ -137,6 +139,54 @@ impl Color {
         self.0
     }
 
+    // =========================================================================
+    // BYTE ORDER
+    // =========================================================================
+
+    /// Bytes do `u32` interno na ordem nativa do host.
+    ///
+    /// É a representação em memória do próprio `Color`, que muda com a
+    /// endianness do host. Para pixels use [`to_argb_bytes`](Self::to_argb_bytes)
+    /// ou [`PixelFormat::write_color`].
+    #[inline]
+    pub const fn to_native_bytes(&self) -> [u8; 4] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Cria a partir de bytes na ordem nativa do host.
+    #[inline]
+    pub const fn from_native_bytes(bytes: [u8; 4]) -> Self {
+        Self(u32::from_ne_bytes(bytes))
+    }
+
+    /// Bytes na ordem fixa A, R, G, B (independente do host).
+    ///
+    /// É o layout em memória de um pixel `ARGB8888`.
+    #[inline]
+    pub const fn to_argb_bytes(&self) -> [u8; 4] {
+        [self.alpha(), self.red(), self.green(), self.blue()]
+    }
+
+    /// Cria a partir de bytes na ordem fixa A, R, G, B.
+    #[inline]
+    pub const fn from_argb_bytes(bytes: [u8; 4]) -> Self {
+        Self::argb(bytes[0], bytes[1], bytes[2], bytes[3])
+    }
+
+    /// Bytes na ordem fixa B, G, R, A (independente do host).
+    ///
+    /// É o layout em memória de um pixel `BGRA8888`.
+    #[inline]
+    pub const fn to_bgra_bytes(&self) -> [u8; 4] {
+        [self.blue(), self.green(), self.red(), self.alpha()]
+    }
+
+    /// Cria a partir de bytes na ordem fixa B, G, R, A.
+    #[inline]
+    pub const fn from_bgra_bytes(bytes: [u8; 4]) -> Self {
+        Self::argb(bytes[3], bytes[2], bytes[1], bytes[0])
+    }
+
     // =========================================================================
     // PREDICATES
     // =========================================================================
@@ -163,6 +213,12 @@ impl Color {
         Self((self.0 & 0x00FFFFFF) | ((a as u32) << 24))
     }
 
+    /// Retorna a cor com alpha forçado para 255.
+    #[inline]
+    pub const fn force_opaque(&self) -> Self {
+        Self(self.0 | 0xFF000000)
+    }
+
     /// Retorna cor com novo red.
     #[inline]
     pub const fn with_red(&self, r: u8) -> Self {
@@ -216,6 +272,20 @@ impl Color {
         Self::argb(self.alpha(), lum, lum, lum)
     }
 
+    /// Converte para grayscale em luz linear, mantendo alpha.
+    ///
+    /// Remove o gamma sRGB, aplica os pesos de luma Rec.709 e recodifica.
+    /// Mais correto que [`to_grayscale`](Self::to_grayscale), que pondera os
+    /// valores ainda em espaço gamma; cinzas puros não mudam.
+    pub fn to_grayscale_linear(&self) -> Self {
+        let linear = |c: u8| srgb_to_linear(c as f32 / 255.0);
+        let y = 0.2126 * linear(self.red())
+            + 0.7152 * linear(self.green())
+            + 0.0722 * linear(self.blue());
+        let level = rdsmath::roundf(linear_to_srgb(y.clamp(0.0, 1.0)) * 255.0) as u8;
+        Self::argb(self.alpha(), level, level, level)
+    }
+
     /// Interpolação linear entre duas cores.
     #[inline]
     pub fn lerp(&self, other: &Color, t: f32) -> Self {
@@ -230,6 +300,49 @@ impl Color {
         Self::argb(a, r, g, b)
     }
 
+    /// Interpolação linear em alpha pré-multiplicado.
+    ///
+    /// Ao esmaecer para transparente, a cor mantém o matiz em vez de
+    /// escurecer na direção do preto (como acontece em `lerp`).
+    pub fn lerp_premultiplied(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let a = self.to_float().premultiply();
+        let b = other.to_float().premultiply();
+        let mixed = a.lerp(&b, t).unpremultiply().saturate();
+        let to_u8 = |v: f32| rdsmath::roundf(v * 255.0) as u8;
+        Self::argb(
+            to_u8(mixed.a),
+            to_u8(mixed.r),
+            to_u8(mixed.g),
+            to_u8(mixed.b),
+        )
+    }
+
+    /// Compõe esta cor sobre `dst` (Porter-Duff source-over, alpha direto).
+    pub fn over(&self, dst: Color) -> Self {
+        let sa = self.alpha() as u32;
+        if sa == 255 {
+            return *self;
+        }
+        if sa == 0 {
+            return dst;
+        }
+        let da = dst.alpha() as u32;
+        let inv = 255 - sa;
+        // Alpha resultante em escala 0..255*255
+        let out_a = sa * 255 + da * inv;
+        let channel = |s: u8, d: u8| {
+            let num = s as u32 * sa * 255 + d as u32 * da * inv;
+            ((num + out_a / 2) / out_a) as u8
+        };
+        Self::argb(
+            ((out_a + 127) / 255) as u8,
+            channel(self.red(), dst.red()),
+            channel(self.green(), dst.green()),
+            channel(self.blue(), dst.blue()),
+        )
+    }
+
     /// Converte para ColorF.
     #[inline]
     pub fn to_float(&self) -> ColorF {
@@ -240,6 +353,143 @@ impl Color {
             a: self.alpha() as f32 / 255.0,
         }
     }
+
+    // =========================================================================
+    // HSL
+    // =========================================================================
+
+    /// Cria cor opaca a partir de HSL.
+    ///
+    /// `h` em graus (qualquer valor, normalizado para 0-360), `s` e `l`
+    /// em 0.0-1.0.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let h = h - rdsmath::floorf(h / 360.0) * 360.0;
+
+        let chroma = (1.0 - rdsmath::absf(2.0 * l - 1.0)) * s;
+        let sector = h / 60.0;
+        let x = chroma * (1.0 - rdsmath::absf(sector % 2.0 - 1.0));
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma * 0.5;
+        let to_u8 = |v: f32| rdsmath::roundf((v + m) * 255.0).clamp(0.0, 255.0) as u8;
+        Self::rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Converte para HSL (`h` em graus, `s` e `l` em 0.0-1.0).
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let r = self.red() as f32 / 255.0;
+        let g = self.green() as f32 / 255.0;
+        let b = self.blue() as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) * 0.5;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - rdsmath::absf(2.0 * l - 1.0));
+        let h = if max == r {
+            60.0 * ((g - b) / delta)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        (h, s, l)
+    }
+
+    /// Cor de depuração estável para um identificador.
+    ///
+    /// Usa hashing de Fibonacci (razão áurea) no matiz, de modo que IDs
+    /// consecutivos recebem cores visualmente distantes. Sempre opaca.
+    pub fn from_debug_id(id: u64) -> Self {
+        const GOLDEN: u64 = 0x9E37_79B9_7F4A_7C15;
+        let hash = id.wrapping_add(1).wrapping_mul(GOLDEN);
+        let hue = (hash >> 40) as f32 / (1u64 << 24) as f32 * 360.0;
+        Self::from_hsl(hue, 0.65, 0.55)
+    }
+
+    /// Índice xterm-256 mais próximo (alpha é ignorado).
+    ///
+    /// Considera o cubo 6x6x6 (16-231) e a rampa de cinzas (232-255); as 16
+    /// cores de sistema variam por terminal e nunca são escolhidas.
+    pub fn to_ansi256(&self) -> u8 {
+        let (r, g, b) = self.to_rgb();
+        let cube = |v: u8| -> u8 {
+            if v < 48 {
+                0
+            } else if v < 115 {
+                1
+            } else {
+                (v - 35) / 40
+            }
+        };
+        let (cr, cg, cb) = (cube(r), cube(g), cube(b));
+        let cube_index = 16 + 36 * cr + 6 * cg + cb;
+
+        let average = (r as u32 + g as u32 + b as u32) / 3;
+        let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_index = 232 + gray_step;
+
+        let distance = |c: Color| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(r, c.red()) + d(g, c.green()) + d(b, c.blue())
+        };
+        if distance(Self::from_ansi256(gray_index)) < distance(Self::from_ansi256(cube_index)) {
+            gray_index
+        } else {
+            cube_index
+        }
+    }
+
+    /// Cor (opaca) de um índice xterm-256.
+    ///
+    /// 0-15 usam as cores de sistema padrão do xterm.
+    pub const fn from_ansi256(index: u8) -> Self {
+        const SYSTEM: [u32; 16] = [
+            0x000000, 0x800000, 0x008000, 0x808000, 0x000080, 0x800080, 0x008080, 0xC0C0C0,
+            0x808080, 0xFF0000, 0x00FF00, 0xFFFF00, 0x0000FF, 0xFF00FF, 0x00FFFF, 0xFFFFFF,
+        ];
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        match index {
+            0..=15 => Self::from_hex(SYSTEM[index as usize]),
+            16..=231 => {
+                let i = index - 16;
+                Self::rgb(
+                    LEVELS[(i / 36) as usize],
+                    LEVELS[(i / 6 % 6) as usize],
+                    LEVELS[(i % 6) as usize],
+                )
+            }
+            _ => Self::gray(8 + (index - 232) * 10),
+        }
+    }
+
+    /// Erro quadrático introduzido ao codificar nesta `format` e decodificar.
+    ///
+    /// Soma dos quadrados das diferenças por canal (ARGB); 0 quando a cor
+    /// sobrevive intacta ao formato.
+    pub fn quantization_error(&self, format: PixelFormat) -> u32 {
+        let mut bytes = [0u8; 4];
+        format.write_color(*self, &mut bytes);
+        let decoded = format.read_color(&bytes);
+        let d = |a: u8, b: u8| (a as i32 - b as i32).unsigned_abs().pow(2);
+        d(self.alpha(), decoded.alpha())
+            + d(self.red(), decoded.red())
+            + d(self.green(), decoded.green())
+            + d(self.blue(), decoded.blue())
+    }
 }
 
 impl From<u32> for Color {
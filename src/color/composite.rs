@@ -0,0 +1,156 @@
+//! # Compositing
+//!
+//! Composição em duas etapas: função de blend (cor) e operador Porter-Duff
+//! (cobertura), aplicados de forma independente como em SVG/PDF.
+
+use super::{BlendMode, ColorF};
+
+// =============================================================================
+// COMPOSITE OP
+// =============================================================================
+
+/// Operador Porter-Duff de cobertura.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum CompositeOp {
+    /// Resultado transparente.
+    Clear = 0,
+    /// Apenas a origem.
+    Source = 1,
+    /// Origem sobre o destino.
+    #[default]
+    SourceOver = 2,
+    /// Origem onde há destino.
+    SourceIn = 3,
+    /// Origem onde não há destino.
+    SourceOut = 4,
+    /// Origem por cima do destino, limitada a ele.
+    SourceAtop = 5,
+    /// Apenas o destino.
+    Dest = 6,
+    /// Destino sobre a origem.
+    DestOver = 7,
+    /// Destino onde há origem.
+    DestIn = 8,
+    /// Destino onde não há origem.
+    DestOut = 9,
+    /// Destino por cima da origem, limitado a ela.
+    DestAtop = 10,
+    /// Origem e destino onde não se sobrepõem.
+    Xor = 11,
+}
+
+impl CompositeOp {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Clear),
+            1 => Some(Self::Source),
+            2 => Some(Self::SourceOver),
+            3 => Some(Self::SourceIn),
+            4 => Some(Self::SourceOut),
+            5 => Some(Self::SourceAtop),
+            6 => Some(Self::Dest),
+            7 => Some(Self::DestOver),
+            8 => Some(Self::DestIn),
+            9 => Some(Self::DestOut),
+            10 => Some(Self::DestAtop),
+            11 => Some(Self::Xor),
+            _ => None,
+        }
+    }
+
+    /// Nome do operador.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Clear => "Clear",
+            Self::Source => "Source",
+            Self::SourceOver => "SourceOver",
+            Self::SourceIn => "SourceIn",
+            Self::SourceOut => "SourceOut",
+            Self::SourceAtop => "SourceAtop",
+            Self::Dest => "Dest",
+            Self::DestOver => "DestOver",
+            Self::DestIn => "DestIn",
+            Self::DestOut => "DestOut",
+            Self::DestAtop => "DestAtop",
+            Self::Xor => "Xor",
+        }
+    }
+
+    /// Fatores `(Fa, Fb)` aplicados à origem e ao destino.
+    #[inline]
+    pub fn factors(&self, sa: f32, da: f32) -> (f32, f32) {
+        match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Source => (1.0, 0.0),
+            Self::SourceOver => (1.0, 1.0 - sa),
+            Self::SourceIn => (da, 0.0),
+            Self::SourceOut => (1.0 - da, 0.0),
+            Self::SourceAtop => (da, 1.0 - sa),
+            Self::Dest => (0.0, 1.0),
+            Self::DestOver => (1.0 - da, 1.0),
+            Self::DestIn => (0.0, sa),
+            Self::DestOut => (0.0, 1.0 - sa),
+            Self::DestAtop => (1.0 - da, sa),
+            Self::Xor => (1.0 - da, 1.0 - sa),
+        }
+    }
+}
+
+// =============================================================================
+// COMPOSITOR
+// =============================================================================
+
+/// Par função de blend + operador de composição.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Compositor {
+    /// Função de mistura de cor.
+    pub blend: BlendMode,
+    /// Operador de cobertura.
+    pub op: CompositeOp,
+}
+
+impl Compositor {
+    /// Cria compositor.
+    #[inline]
+    pub const fn new(blend: BlendMode, op: CompositeOp) -> Self {
+        Self { blend, op }
+    }
+
+    /// Compõe `src` sobre `dst` (ver [`composite`]).
+    #[inline]
+    pub fn apply(&self, src: ColorF, dst: ColorF) -> ColorF {
+        composite(src, dst, self.blend, self.op)
+    }
+}
+
+/// Compõe `src` sobre `dst` (alpha straight).
+///
+/// Primeiro mistura a cor de origem com o fundo,
+/// `Cs' = (1 - ab) * Cs + ab * B(Cb, Cs)`, e depois aplica o operador
+/// Porter-Duff: `co = as * Fa * Cs' + ab * Fb * Cb`, `ao = as * Fa + ab * Fb`.
+/// Modos Porter-Duff usados como `blend` valem como `Normal`.
+pub fn composite(src: ColorF, dst: ColorF, blend: BlendMode, op: CompositeOp) -> ColorF {
+    let src = src.saturate();
+    let dst = dst.saturate();
+    let (sa, da) = (src.a, dst.a);
+    let (fa, fb) = op.factors(sa, da);
+
+    let a = sa * fa + da * fb;
+    if a <= 0.0 {
+        return ColorF::TRANSPARENT;
+    }
+    let channel = |cs: f32, cb: f32| {
+        let mixed = (1.0 - da) * cs + da * blend.separable_f(cb, cs);
+        ((sa * fa * mixed + da * fb * cb) / a).min(1.0)
+    };
+    ColorF::new(
+        channel(src.r, dst.r),
+        channel(src.g, dst.g),
+        channel(src.b, dst.b),
+        a.min(1.0),
+    )
+}
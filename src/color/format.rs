@@ -2,9 +2,15 @@
 //!
 //! Formatos de pixel suportados pelo sistema gráfico.
 
+use super::Color;
+
 /// Formato de pixel suportado pelo sistema gráfico.
 ///
 /// Define como os bytes de cor são organizados na memória.
+///
+/// Nos formatos com 8 bits por canal o nome descreve a ordem dos bytes na
+/// memória, independente da endianness do host (ex: `ARGB8888` fica
+/// `A, R, G, B`). `RGB565` e `Gray16` são palavras de 16 bits little-endian.
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 pub enum PixelFormat {
@@ -32,6 +38,20 @@ pub enum PixelFormat {
 }
 
 impl PixelFormat {
+    /// Todos os formatos, na ordem dos valores `u32`.
+    pub const ALL: [PixelFormat; 10] = [
+        Self::ARGB8888,
+        Self::XRGB8888,
+        Self::RGB565,
+        Self::BGRA8888,
+        Self::RGBA8888,
+        Self::RGB888,
+        Self::BGR888,
+        Self::Gray8,
+        Self::Gray16,
+        Self::Alpha8,
+    ];
+
     /// Retorna o número de bytes por pixel para este formato.
     #[inline]
     pub const fn bytes_per_pixel(&self) -> u32 {
@@ -71,6 +91,57 @@ impl PixelFormat {
         matches!(self, Self::Gray8 | Self::Gray16 | Self::Alpha8)
     }
 
+    /// Verifica se é formato de 32 bits por pixel.
+    #[inline]
+    pub const fn is_32bit(&self) -> bool {
+        self.bytes_per_pixel() == 4
+    }
+
+    /// Verifica se é formato de 24 bits por pixel.
+    #[inline]
+    pub const fn is_24bit(&self) -> bool {
+        self.bytes_per_pixel() == 3
+    }
+
+    /// Número de componentes significativos (bytes de padding não contam).
+    #[inline]
+    pub const fn component_count(&self) -> u8 {
+        match self {
+            Self::ARGB8888 | Self::BGRA8888 | Self::RGBA8888 => 4,
+            Self::XRGB8888 | Self::RGB565 | Self::RGB888 | Self::BGR888 => 3,
+            Self::Gray8 | Self::Gray16 | Self::Alpha8 => 1,
+        }
+    }
+
+    /// Verifica se a conversão para `to` é apenas reordenação de bytes.
+    ///
+    /// Vale para formatos RGB com 8 bits por canal (24 e 32 bits), onde um
+    /// shuffle (mais, no máximo, um byte constante de alpha) basta. Formatos
+    /// 565 e grayscale exigem o caminho geral por pixel.
+    #[inline]
+    pub const fn can_fast_convert(&self, to: PixelFormat) -> bool {
+        if *self as u32 == to as u32 {
+            return true;
+        }
+        (self.is_32bit() || self.is_24bit()) && (to.is_32bit() || to.is_24bit())
+    }
+
+    /// Verifica se `BufferViewMut::convert_from` suporta `from` -> `to`.
+    ///
+    /// Conversões entre formatos de cor (RGB e grayscale) são suportadas,
+    /// mesmo com perda de precisão. `Alpha8` só converte de/para formatos
+    /// com alpha: de/para os demais o resultado seria uma cor constante.
+    #[inline]
+    pub const fn can_convert(from: PixelFormat, to: PixelFormat) -> bool {
+        if from as u32 == to as u32 {
+            return true;
+        }
+        match (from, to) {
+            (Self::Alpha8, other) | (other, Self::Alpha8) => other.has_alpha(),
+            _ => true,
+        }
+    }
+
     /// Calcula stride mínimo para uma largura.
     #[inline]
     pub const fn min_stride(&self, width: u32) -> u32 {
@@ -84,6 +155,53 @@ impl PixelFormat {
         ((min + alignment - 1) / alignment) * alignment
     }
 
+    /// Como `min_stride`, mas `None` se estourar `u32`.
+    #[inline]
+    pub const fn checked_min_stride(&self, width: u32) -> Option<u32> {
+        width.checked_mul(self.bytes_per_pixel())
+    }
+
+    /// Como `aligned_stride`, mas `None` se estourar `u32` (ou `alignment`
+    /// for zero).
+    #[inline]
+    pub const fn checked_aligned_stride(&self, width: u32, alignment: u32) -> Option<u32> {
+        if alignment == 0 {
+            return None;
+        }
+        let min = match self.checked_min_stride(width) {
+            Some(min) => min,
+            None => return None,
+        };
+        match min.checked_next_multiple_of(alignment) {
+            Some(stride) => Some(stride),
+            None => None,
+        }
+    }
+
+    /// Alinhamento de linha recomendado (em bytes) para alocadores.
+    ///
+    /// Formatos de 32 bits já ficam alinhados a palavra com 4; formatos de
+    /// 16 e 24 bits usam 8 para que cada linha comece em palavra mesmo com
+    /// larguras ímpares.
+    #[inline]
+    pub const fn recommended_alignment(&self) -> u32 {
+        match self.bytes_per_pixel() {
+            2 | 3 => 8,
+            _ => 4,
+        }
+    }
+
+    /// Quantos pixels cabem exatamente em uma palavra de 32 bits.
+    ///
+    /// `None` para formatos de 24 bits, que não dividem a palavra.
+    #[inline]
+    pub const fn pixels_per_word(&self) -> Option<u32> {
+        match self.bytes_per_pixel() {
+            3 => None,
+            bpp => Some(4 / bpp),
+        }
+    }
+
     /// Calcula tamanho de buffer para dimensões.
     #[inline]
     pub const fn buffer_size(&self, width: u32, height: u32) -> usize {
@@ -130,4 +248,69 @@ impl PixelFormat {
             Self::Alpha8 => "Alpha8",
         }
     }
+
+    /// Codifica uma cor em um pixel deste formato.
+    ///
+    /// A ordem dos bytes é fixa por formato e não depende da endianness do
+    /// host.
+    /// Formatos sem alpha descartam o alpha da cor; em `XRGB8888` o byte
+    /// ignorado é sempre escrito como `0xFF`.
+    ///
+    /// # Panics
+    /// Se `out` tiver menos que `bytes_per_pixel()` bytes.
+    pub fn write_color(&self, c: Color, out: &mut [u8]) {
+        let (a, r, g, b) = c.to_argb();
+        match self {
+            Self::ARGB8888 => out[..4].copy_from_slice(&c.to_argb_bytes()),
+            Self::XRGB8888 => out[..4].copy_from_slice(&[0xFF, r, g, b]),
+            Self::BGRA8888 => out[..4].copy_from_slice(&c.to_bgra_bytes()),
+            Self::RGBA8888 => out[..4].copy_from_slice(&[r, g, b, a]),
+            Self::RGB888 => out[..3].copy_from_slice(&[r, g, b]),
+            Self::BGR888 => out[..3].copy_from_slice(&[b, g, r]),
+            Self::RGB565 => {
+                let packed = ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3);
+                out[..2].copy_from_slice(&packed.to_le_bytes());
+            }
+            Self::Gray8 => out[0] = c.luminance(),
+            Self::Gray16 => {
+                let level = c.luminance() as u16 * 257;
+                out[..2].copy_from_slice(&level.to_le_bytes());
+            }
+            Self::Alpha8 => out[0] = a,
+        }
+    }
+
+    /// Decodifica um pixel deste formato em uma cor.
+    ///
+    /// Formatos sem alpha retornam cores opacas; `Alpha8` retorna preto com
+    /// o alpha do pixel.
+    ///
+    /// # Panics
+    /// Se `bytes` tiver menos que `bytes_per_pixel()` bytes.
+    pub fn read_color(&self, bytes: &[u8]) -> Color {
+        match self {
+            Self::ARGB8888 => Color::from_argb_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            // O byte X é ignorado: alpha sempre 255
+            Self::XRGB8888 => Color::rgb(bytes[1], bytes[2], bytes[3]),
+            Self::BGRA8888 => Color::from_bgra_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            Self::RGBA8888 => Color::argb(bytes[3], bytes[0], bytes[1], bytes[2]),
+            Self::RGB888 => Color::rgb(bytes[0], bytes[1], bytes[2]),
+            Self::BGR888 => Color::rgb(bytes[2], bytes[1], bytes[0]),
+            Self::RGB565 => {
+                let packed = u16::from_le_bytes([bytes[0], bytes[1]]);
+                let r = ((packed >> 11) & 0x1F) as u8;
+                let g = ((packed >> 5) & 0x3F) as u8;
+                let b = (packed & 0x1F) as u8;
+                // Expande replicando os bits mais significativos
+                Color::rgb(
+                    (r << 3) | (r >> 2),
+                    (g << 2) | (g >> 4),
+                    (b << 3) | (b >> 2),
+                )
+            }
+            Self::Gray8 => Color::gray(bytes[0]),
+            Self::Gray16 => Color::gray(bytes[1]),
+            Self::Alpha8 => Color::argb(bytes[0], 0, 0, 0),
+        }
+    }
 }
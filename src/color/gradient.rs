@@ -0,0 +1,85 @@
+//! # Gradient
+//!
+//! Gradientes lineares e radiais sobre uma `ColorRamp`.
+
+use super::{Color, ColorRamp};
+use crate::geometry::PointF;
+
+/// Geometria do gradiente.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// Linear de `start` (t = 0) até `end` (t = 1).
+    Linear {
+        /// Ponto inicial.
+        start: PointF,
+        /// Ponto final.
+        end: PointF,
+    },
+    /// Radial de `center` (t = 0) até a distância `radius` (t = 1).
+    Radial {
+        /// Centro.
+        center: PointF,
+        /// Raio.
+        radius: f32,
+    },
+}
+
+/// Gradiente: geometria + rampa de cores (gamma-correct).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient {
+    /// Geometria.
+    pub kind: GradientKind,
+    /// Rampa de cores.
+    pub ramp: ColorRamp,
+}
+
+impl Gradient {
+    /// Cria gradiente linear.
+    #[inline]
+    pub const fn linear(start: PointF, end: PointF, ramp: ColorRamp) -> Self {
+        Self {
+            kind: GradientKind::Linear { start, end },
+            ramp,
+        }
+    }
+
+    /// Cria gradiente radial.
+    #[inline]
+    pub const fn radial(center: PointF, radius: f32, ramp: ColorRamp) -> Self {
+        Self {
+            kind: GradientKind::Radial { center, radius },
+            ramp,
+        }
+    }
+
+    /// Parâmetro `t` (limitado a `[0, 1]`) no ponto `p`.
+    ///
+    /// Geometrias degeneradas (início igual ao fim, raio zero) retornam 0.
+    pub fn t_at(&self, p: PointF) -> f32 {
+        let t = match self.kind {
+            GradientKind::Linear { start, end } => {
+                let axis = end - start;
+                let len_sq = axis.dot(&axis);
+                if len_sq <= 0.0 {
+                    0.0
+                } else {
+                    (p - start).dot(&axis) / len_sq
+                }
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius <= 0.0 {
+                    0.0
+                } else {
+                    p.distance(&center) / radius
+                }
+            }
+        };
+        t.clamp(0.0, 1.0)
+    }
+
+    /// Cor do gradiente no ponto `p`.
+    #[inline]
+    pub fn sample(&self, p: PointF) -> Color {
+        self.ramp.sample(self.t_at(p))
+    }
+}
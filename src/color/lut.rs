@@ -0,0 +1,66 @@
+//! # Color LUT
+//!
+//! Tabelas de consulta por canal para correção de cor.
+
+use super::{remove_gamma, Color};
+
+/// Tabelas de consulta (256 entradas) para os canais R, G e B.
+///
+/// Pré-computar a curva evita `powf` por pixel. O alpha não é alterado.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorLut {
+    /// Tabela do canal vermelho.
+    pub r: [u8; 256],
+    /// Tabela do canal verde.
+    pub g: [u8; 256],
+    /// Tabela do canal azul.
+    pub b: [u8; 256],
+}
+
+impl Default for ColorLut {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl ColorLut {
+    /// LUT identidade (não altera a cor).
+    pub const fn identity() -> Self {
+        let mut table = [0u8; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = i as u8;
+            i += 1;
+        }
+        Self {
+            r: table,
+            g: table,
+            b: table,
+        }
+    }
+
+    /// LUT de correção gamma (`value^(1/g)` em todos os canais).
+    pub fn gamma(g: f32) -> Self {
+        let mut table = [0u8; 256];
+        for (i, out) in table.iter_mut().enumerate() {
+            let value = remove_gamma(i as f32 / 255.0, g);
+            *out = rdsmath::roundf(value * 255.0) as u8;
+        }
+        Self {
+            r: table,
+            g: table,
+            b: table,
+        }
+    }
+
+    /// Aplica a LUT a uma cor.
+    #[inline]
+    pub const fn apply(&self, c: Color) -> Color {
+        Color::argb(
+            c.alpha(),
+            self.r[c.red() as usize],
+            self.g[c.green() as usize],
+            self.b[c.blue() as usize],
+        )
+    }
+}
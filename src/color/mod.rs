@@ -3,13 +3,26 @@
 //! Sistema de cores e formatos de pixel.
 
 mod blend;
+mod channel;
 mod color;
+mod composite;
 mod format;
+mod gradient;
+mod lut;
+mod oklab;
 mod palette;
+mod ramp;
 mod space;
+mod tonemap;
 
-pub use blend::{AlphaMode, BlendMode};
+pub use blend::{AlphaMode, BlendMode, BlendTable};
+pub use channel::Channel;
 pub use color::{Color, ColorF};
+pub use composite::{composite, CompositeOp, Compositor};
 pub use format::PixelFormat;
+pub use gradient::{Gradient, GradientKind};
+pub use lut::ColorLut;
 pub use palette::{Palette, CATPPUCCIN_LATTE, CATPPUCCIN_MOCHA, DRACULA, NORD, REDSTONE_DEFAULT};
+pub use ramp::{ColorRamp, MAX_RAMP_STOPS};
 pub use space::{apply_gamma, linear_to_srgb, remove_gamma, srgb_to_linear, ColorSpace};
+pub use tonemap::ToneMap;
@@ -0,0 +1,75 @@
+//! # OKLab
+//!
+//! Espaço de cor perceptualmente uniforme (Björn Ottosson, 2020).
+
+use super::{linear_to_srgb, srgb_to_linear, Color, ColorF};
+
+/// Raiz cúbica com sinal (via `powf`, que não aceita base negativa).
+#[inline]
+fn cbrt(x: f32) -> f32 {
+    if x < 0.0 {
+        -rdsmath::powf(-x, 1.0 / 3.0)
+    } else {
+        rdsmath::powf(x, 1.0 / 3.0)
+    }
+}
+
+impl ColorF {
+    /// Converte para OKLab `(L, a, b)` (alpha é ignorado).
+    ///
+    /// Os componentes RGB são tratados como sRGB codificado.
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let r = srgb_to_linear(self.r);
+        let g = srgb_to_linear(self.g);
+        let b = srgb_to_linear(self.b);
+
+        let l = cbrt(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Cria cor opaca a partir de OKLab `(L, a, b)`.
+    ///
+    /// Cores fora do gamut sRGB são saturadas para [0.0, 1.0].
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l3, m3, s3) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        let r = 4.076_741_7 * l3 - 3.307_711_6 * m3 + 0.230_969_94 * s3;
+        let g = -1.268_438 * l3 + 2.609_757_4 * m3 - 0.341_319_38 * s3;
+        let b = -0.004_196_086_3 * l3 - 0.703_418_6 * m3 + 1.707_614_7 * s3;
+
+        let encode = |v: f32| linear_to_srgb(v.clamp(0.0, 1.0));
+        Self::rgb(encode(r), encode(g), encode(b))
+    }
+}
+
+impl Color {
+    /// Interpola até `other` em OKLab.
+    ///
+    /// Produz gradientes perceptualmente uniformes, sem o meio acinzentado
+    /// da interpolação em RGB. Alpha é interpolado linearmente.
+    pub fn mix_oklab(&self, other: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (l0, a0, b0) = self.to_float().to_oklab();
+        let (l1, a1, b1) = other.to_float().to_oklab();
+        let mixed = ColorF::from_oklab(l0 + (l1 - l0) * t, a0 + (a1 - a0) * t, b0 + (b1 - b0) * t);
+
+        let to_u8 = |v: f32| rdsmath::roundf(v * 255.0).clamp(0.0, 255.0) as u8;
+        let alpha = self.alpha() as f32 + (other.alpha() as f32 - self.alpha() as f32) * t;
+        Color::argb(
+            rdsmath::roundf(alpha) as u8,
+            to_u8(mixed.r),
+            to_u8(mixed.g),
+            to_u8(mixed.b),
+        )
+    }
+}
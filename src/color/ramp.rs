@@ -0,0 +1,153 @@
+//! # Color Ramp
+//!
+//! Gradiente de cores com paradas em posições arbitrárias.
+
+use super::{linear_to_srgb, srgb_to_linear, Color, ColorF};
+
+/// Número máximo de paradas em uma ColorRamp.
+pub const MAX_RAMP_STOPS: usize = 16;
+
+/// Rampa de cores com paradas ordenadas em `[0, 1]` (sem alocação).
+///
+/// A interpolação é feita em espaço linear (gamma-correct).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorRamp {
+    /// Paradas (posição, cor), ordenadas por posição.
+    stops: [(f32, Color); MAX_RAMP_STOPS],
+    /// Número de paradas válidas.
+    count: usize,
+}
+
+impl Default for ColorRamp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorRamp {
+    /// Cria rampa vazia.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            stops: [(0.0, Color::TRANSPARENT); MAX_RAMP_STOPS],
+            count: 0,
+        }
+    }
+
+    /// Cria rampa a partir de paradas (em qualquer ordem).
+    ///
+    /// Retorna `None` se alguma posição estiver fora de `[0, 1]` ou se
+    /// houver mais que `MAX_RAMP_STOPS` paradas.
+    pub fn from_stops(stops: &[(f32, Color)]) -> Option<Self> {
+        let mut ramp = Self::new();
+        for &(position, color) in stops {
+            if !ramp.push(position, color) {
+                return None;
+            }
+        }
+        Some(ramp)
+    }
+
+    /// Número de paradas.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazia.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Paradas válidas como slice.
+    #[inline]
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops[..self.count]
+    }
+
+    /// Insere uma parada mantendo a ordem.
+    ///
+    /// Retorna `false` se a posição for inválida ou a rampa estiver cheia.
+    pub fn push(&mut self, position: f32, color: Color) -> bool {
+        if !(0.0..=1.0).contains(&position) || self.count >= MAX_RAMP_STOPS {
+            return false;
+        }
+        let index = self.stops[..self.count]
+            .iter()
+            .position(|&(p, _)| p > position)
+            .unwrap_or(self.count);
+        self.stops.copy_within(index..self.count, index + 1);
+        self.stops[index] = (position, color);
+        self.count += 1;
+        true
+    }
+
+    /// Localiza o segmento para `t`: (cor inicial, cor final, fração local).
+    fn segment(&self, t: f32) -> Option<(Color, Color, f32)> {
+        let stops = self.stops();
+        let (first, last) = (stops.first()?, stops.last()?);
+        if t <= first.0 {
+            return Some((first.1, first.1, 0.0));
+        }
+        if t >= last.0 {
+            return Some((last.1, last.1, 0.0));
+        }
+        let end = stops.iter().position(|&(p, _)| p > t)?;
+        let (p0, c0) = stops[end - 1];
+        let (p1, c1) = stops[end];
+        let span = p1 - p0;
+        let u = if span > 0.0 { (t - p0) / span } else { 0.0 };
+        Some((c0, c1, u))
+    }
+
+    /// Amostra a rampa em `t` (limitado às paradas extremas).
+    ///
+    /// Rampas vazias retornam `Color::TRANSPARENT`.
+    pub fn sample(&self, t: f32) -> Color {
+        match self.segment(t) {
+            Some((c0, c1, u)) => to_srgb(&to_linear(c0).lerp(&to_linear(c1), u)),
+            None => Color::TRANSPARENT,
+        }
+    }
+
+    /// Amostra a rampa interpolando em alpha pré-multiplicado.
+    ///
+    /// Evita que a cor de uma parada transparente "suje" o gradiente. O
+    /// resultado é retornado com alpha não-multiplicado.
+    pub fn sample_premultiplied(&self, t: f32) -> Color {
+        match self.segment(t) {
+            Some((c0, c1, u)) => {
+                let a = to_linear(c0).premultiply();
+                let b = to_linear(c1).premultiply();
+                to_srgb(&a.lerp(&b, u).unpremultiply())
+            }
+            None => Color::TRANSPARENT,
+        }
+    }
+}
+
+/// Converte cor sRGB para ColorF linear.
+#[inline]
+fn to_linear(c: Color) -> ColorF {
+    let f = c.to_float();
+    ColorF::new(
+        srgb_to_linear(f.r),
+        srgb_to_linear(f.g),
+        srgb_to_linear(f.b),
+        f.a,
+    )
+}
+
+/// Converte ColorF linear para cor sRGB (com arredondamento).
+#[inline]
+fn to_srgb(c: &ColorF) -> Color {
+    let s = c.saturate();
+    let to_u8 = |v: f32| rdsmath::roundf(v * 255.0) as u8;
+    Color::argb(
+        to_u8(s.a),
+        to_u8(linear_to_srgb(s.r)),
+        to_u8(linear_to_srgb(s.g)),
+        to_u8(linear_to_srgb(s.b)),
+    )
+}
@@ -0,0 +1,49 @@
+//! # Tone Mapping
+//!
+//! Compressão de cores HDR (componentes acima de 1.0) para a faixa exibível.
+
+use super::ColorF;
+
+/// Operador de tone mapping.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ToneMap {
+    /// Reinhard clássico: `c / (1 + c)`.
+    #[default]
+    Reinhard,
+    /// Reinhard estendido: `white_point` (e acima) mapeia para 1.0.
+    ReinhardExtended(f32),
+    /// Aproximação da curva filmic ACES (Krzysztof Narkowicz).
+    AcesApprox,
+}
+
+impl ToneMap {
+    /// Aplica o operador a um componente (negativos viram 0).
+    #[inline]
+    pub fn apply(&self, c: f32) -> f32 {
+        let c = c.max(0.0);
+        match *self {
+            Self::Reinhard => c / (1.0 + c),
+            Self::ReinhardExtended(white) => {
+                if white <= 0.0 {
+                    return Self::Reinhard.apply(c);
+                }
+                (c * (1.0 + c / (white * white)) / (1.0 + c)).min(1.0)
+            }
+            Self::AcesApprox => {
+                let mapped = (c * (2.51 * c + 0.03)) / (c * (2.43 * c + 0.59) + 0.14);
+                mapped.clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+impl ColorF {
+    /// Aplica tone mapping aos componentes RGB (alpha é preservado).
+    ///
+    /// Deve ser chamado antes de `to_color()` para entradas HDR, que de
+    /// outra forma seriam simplesmente saturadas em 1.0.
+    #[inline]
+    pub fn tonemap(&self, op: ToneMap) -> ColorF {
+        ColorF::new(op.apply(self.r), op.apply(self.g), op.apply(self.b), self.a)
+    }
+}
@@ -0,0 +1,226 @@
+//! # Damage Buffer
+//!
+//! Lista limitada de regiões danificadas (sem alocação).
+
+use super::DamageRegion;
+use crate::geometry::{Rect, Transform2D};
+
+/// Número máximo de regiões em um DamageBuffer.
+pub const MAX_DAMAGE_REGIONS: usize = 64;
+
+/// Lista de regiões danificadas com capacidade fixa.
+///
+/// Quando a capacidade é excedida, todas as regiões são colapsadas em uma
+/// única bounding box, garantindo que nenhuma área danificada seja perdida.
+#[derive(Clone, Copy, Debug)]
+pub struct DamageBuffer {
+    /// Regiões armazenadas.
+    regions: [DamageRegion; MAX_DAMAGE_REGIONS],
+    /// Número de regiões válidas.
+    count: usize,
+}
+
+impl Default for DamageBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DamageBuffer {
+    /// Cria buffer vazio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            regions: [DamageRegion::EMPTY; MAX_DAMAGE_REGIONS],
+            count: 0,
+        }
+    }
+
+    /// Número de regiões.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazio.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Verifica se está cheio.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.count >= MAX_DAMAGE_REGIONS
+    }
+
+    /// Adiciona um retângulo danificado (retângulos vazios são ignorados).
+    ///
+    /// Se o buffer estiver cheio, colapsa tudo em uma única bounding box.
+    pub fn push(&mut self, rect: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+        if self.is_full() {
+            let bounds = self.bounds().union(&rect);
+            self.count = 1;
+            self.regions[0] = DamageRegion::new(bounds);
+            return;
+        }
+        self.regions[self.count] = DamageRegion::new(rect);
+        self.count += 1;
+    }
+
+    /// Adiciona uma região danificada.
+    #[inline]
+    pub fn push_region(&mut self, region: DamageRegion) {
+        self.push(region.rect);
+    }
+
+    /// Obtém uma região por índice.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<DamageRegion> {
+        if index < self.count {
+            Some(self.regions[index])
+        } else {
+            None
+        }
+    }
+
+    /// Regiões válidas como slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[DamageRegion] {
+        &self.regions[..self.count]
+    }
+
+    /// Regiões válidas como slice mutável (uso interno do crate).
+    #[inline]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [DamageRegion] {
+        &mut self.regions[..self.count]
+    }
+
+    /// Iterador sobre as regiões.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &DamageRegion> {
+        self.regions[..self.count].iter()
+    }
+
+    /// Bounding box de todas as regiões.
+    pub fn bounds(&self) -> Rect {
+        self.iter()
+            .fold(Rect::ZERO, |acc, region| acc.union(&region.rect))
+    }
+
+    /// Área total (pode contar sobreposições mais de uma vez).
+    pub fn total_area(&self) -> u64 {
+        self.iter().map(|region| region.area()).sum()
+    }
+
+    /// Remove a região no índice (a última ocupa seu lugar).
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> Option<DamageRegion> {
+        if index >= self.count {
+            return None;
+        }
+        let removed = self.regions[index];
+        self.count -= 1;
+        self.regions[index] = self.regions[self.count];
+        Some(removed)
+    }
+
+    /// Funde regiões enquanto a fusão for barata.
+    ///
+    /// A cada passo funde o par cuja bounding box desperdiça menos área
+    /// extra em relação à soma das áreas do par. Para quando o melhor custo
+    /// excede `merge_cost_threshold` (0.0 = apenas fusões sem desperdício).
+    pub fn optimize(&mut self, merge_cost_threshold: f32) {
+        while let Some((i, j, cost)) = self.cheapest_pair(merge_cost) {
+            if cost <= merge_cost_threshold {
+                self.merge_pair(i, j);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Reduz o número de regiões para no máximo `max_rects`.
+    ///
+    /// A cada passo funde o par cuja bounding box acrescenta menos área
+    /// absoluta (`union - a - b`). Como cada fusão troca duas regiões pela
+    /// sua bounding box, a cobertura nunca diminui. `max_rects` 0 é tratado
+    /// como 1: uma lista não vazia não pode ser coberta por zero regiões.
+    pub fn simplify_to(&mut self, max_rects: usize) {
+        let max_rects = max_rects.max(1);
+        while self.count > max_rects {
+            match self.cheapest_pair(added_area) {
+                Some((i, j, _)) => self.merge_pair(i, j),
+                None => break,
+            }
+        }
+    }
+
+    /// Par `(i, j, custo)` de menor custo segundo `cost` (`None` com menos
+    /// de duas regiões). Empates ficam com o primeiro par encontrado.
+    fn cheapest_pair<T: PartialOrd>(
+        &self,
+        cost: impl Fn(&Rect, &Rect) -> T,
+    ) -> Option<(usize, usize, T)> {
+        let mut best: Option<(usize, usize, T)> = None;
+        for i in 0..self.count {
+            for j in (i + 1)..self.count {
+                let c = cost(&self.regions[i].rect, &self.regions[j].rect);
+                if best.as_ref().is_none_or(|(_, _, b)| c < *b) {
+                    best = Some((i, j, c));
+                }
+            }
+        }
+        best
+    }
+
+    /// Substitui a região `i` pela união com `j` e remove `j` (`i < j`).
+    #[inline]
+    fn merge_pair(&mut self, i: usize, j: usize) {
+        let merged = self.regions[i].rect.union(&self.regions[j].rect);
+        self.regions[i] = DamageRegion::new(merged);
+        self.swap_remove(j);
+    }
+
+    /// Mapeia todas as regiões por uma transformação de superfície
+    /// (ver `DamageRegion::transform`). Regiões que ficam vazias são removidas.
+    pub fn transform_all(&mut self, t: &Transform2D) {
+        for region in self.as_mut_slice() {
+            *region = region.transform(t);
+        }
+        let mut i = 0;
+        while i < self.count {
+            if self.regions[i].is_empty() {
+                self.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Limpa o buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
+
+/// Custo de fundir dois retângulos: área extra da bounding box relativa à
+/// soma das áreas (negativo quando há sobreposição).
+fn merge_cost(a: &Rect, b: &Rect) -> f32 {
+    let combined = a.area() + b.area();
+    if combined == 0 {
+        return 0.0;
+    }
+    let union = a.union(b).area();
+    (union as f32 - combined as f32) / combined as f32
+}
+
+/// Área absoluta acrescentada pela bounding box do par (negativa quando há
+/// sobreposição).
+fn added_area(a: &Rect, b: &Rect) -> i64 {
+    a.union(b).area() as i64 - a.area() as i64 - b.area() as i64
+}
@@ -2,6 +2,10 @@
 //!
 //! Damage tracking para composição.
 
+mod buffer;
+mod occlusion;
 mod region;
 
+pub use buffer::{DamageBuffer, MAX_DAMAGE_REGIONS};
+pub use occlusion::{compute_visible, frame_damage};
 pub use region::{DamageHint, DamageRegion};
@@ -0,0 +1,98 @@
+//! # Occlusion
+//!
+//! Cálculo de regiões visíveis (occlusion culling).
+
+use super::{DamageBuffer, MAX_DAMAGE_REGIONS};
+use crate::geometry::Rect;
+
+/// Calcula os fragmentos visíveis de uma lista de janelas opacas.
+///
+/// `rects` deve estar ordenado da frente para trás. Para cada retângulo, a
+/// área já coberta pelos anteriores é subtraída e apenas os fragmentos
+/// recém-visíveis (recortados a `screen`) são adicionados a `out`.
+pub fn compute_visible(rects: &[Rect], screen: Rect, out: &mut DamageBuffer) {
+    for (i, rect) in rects.iter().enumerate() {
+        let clipped = match rect.intersection(&screen) {
+            Some(r) => r,
+            None => continue,
+        };
+
+        let mut frags = [Rect::ZERO; MAX_DAMAGE_REGIONS];
+        frags[0] = clipped;
+        let mut count = 1;
+
+        for front in &rects[..i] {
+            count = subtract_from_all(&mut frags, count, front);
+            if count == 0 {
+                break;
+            }
+        }
+
+        for frag in &frags[..count] {
+            out.push(*frag);
+        }
+    }
+}
+
+/// Calcula as áreas cuja visibilidade mudou entre dois frames.
+///
+/// Adiciona a `out` a diferença simétrica dos dois conjuntos: áreas de
+/// `cur_visible` que não estavam em `prev_visible` (recém-cobertas) e áreas
+/// de `prev_visible` que não estão mais em `cur_visible` (recém-expostas).
+pub fn frame_damage(prev_visible: &[Rect], cur_visible: &[Rect], out: &mut DamageBuffer) {
+    push_difference(cur_visible, prev_visible, out);
+    push_difference(prev_visible, cur_visible, out);
+}
+
+/// Adiciona a `out` as partes de cada retângulo de `from` fora de `minus`.
+fn push_difference(from: &[Rect], minus: &[Rect], out: &mut DamageBuffer) {
+    for rect in from {
+        if rect.is_empty() {
+            continue;
+        }
+        let mut frags = [Rect::ZERO; MAX_DAMAGE_REGIONS];
+        frags[0] = *rect;
+        let mut count = 1;
+
+        for cut in minus {
+            count = subtract_from_all(&mut frags, count, cut);
+            if count == 0 {
+                break;
+            }
+        }
+
+        for frag in &frags[..count] {
+            out.push(*frag);
+        }
+    }
+}
+
+/// Subtrai `cut` de todos os fragmentos, retornando a nova contagem.
+///
+/// Se a capacidade estourar, os fragmentos são colapsados na bounding box
+/// (estimativa conservadora: nunca perde área visível).
+pub(crate) fn subtract_from_all(
+    frags: &mut [Rect; MAX_DAMAGE_REGIONS],
+    count: usize,
+    cut: &Rect,
+) -> usize {
+    let mut next = [Rect::ZERO; MAX_DAMAGE_REGIONS];
+    let mut n = 0;
+
+    for (i, frag) in frags[..count].iter().enumerate() {
+        let (parts, k) = frag.subtract(cut);
+        if n + k > MAX_DAMAGE_REGIONS {
+            let bounds = next[..n]
+                .iter()
+                .chain(frags[i..count].iter())
+                .fold(Rect::ZERO, |acc, r| acc.union(r));
+            frags[0] = bounds;
+            return 1;
+        }
+        next[n..n + k].copy_from_slice(&parts[..k]);
+        n += k;
+    }
+
+    frags[..n].copy_from_slice(&next[..n]);
+    n
+}
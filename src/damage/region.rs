@@ -2,7 +2,7 @@
 //!
 //! Regiões danificadas para composição.
 
-use crate::geometry::Rect;
+use crate::geometry::{Rect, Transform2D};
 
 // =============================================================================
 // DAMAGE REGION
@@ -85,6 +85,20 @@ impl DamageRegion {
             rect: self.rect.expand(amount),
         }
     }
+
+    /// Mapeia a região por uma transformação de superfície.
+    ///
+    /// Retorna a bounding box alinhada aos eixos, arredondada para fora para
+    /// nunca perder pixels danificados. Rotações inflam a região.
+    #[inline]
+    pub fn transform(&self, t: &Transform2D) -> DamageRegion {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        Self {
+            rect: t.transform_rect(self.rect.to_float()).round_out(),
+        }
+    }
 }
 
 impl From<Rect> for DamageRegion {
@@ -2,9 +2,10 @@
 //!
 //! Informações sobre displays/monitores.
 
+use super::Rotation;
 use crate::buffer::BufferDescriptor;
 use crate::color::PixelFormat;
-use crate::geometry::Size;
+use crate::geometry::{Size, Transform2D};
 
 // =============================================================================
 // DISPLAY INFO
@@ -89,6 +90,63 @@ impl DisplayInfo {
     pub const fn framebuffer_size(&self) -> usize {
         (self.stride as usize) * (self.height as usize)
     }
+
+    /// Índice do intervalo de refresh que contém `time_us` (desde t=0).
+    #[inline]
+    const fn refresh_index(&self, time_us: u64) -> u64 {
+        (time_us as u128 * self.refresh_rate_mhz as u128 / 1_000_000_000) as u64
+    }
+
+    /// Posição fracionária `[0, 1)` de `time_us` dentro do intervalo de refresh.
+    ///
+    /// Retorna 0.0 se a taxa de atualização for zero.
+    pub fn phase_at(&self, time_us: u64) -> f32 {
+        if self.refresh_rate_mhz == 0 {
+            return 0.0;
+        }
+        // Em unidades de 1e-9 intervalo, para manter precisão inteira
+        let ticks = time_us as u128 * self.refresh_rate_mhz as u128 % 1_000_000_000;
+        // Arredondamento de f32 nunca pode chegar a 1.0
+        (ticks as f32 / 1_000_000_000.0).min(1.0 - f32::EPSILON / 2.0)
+    }
+
+    /// Número de limites de refresh (vblanks) entre `start_us` e `end_us`.
+    ///
+    /// Retorna 0 se a taxa de atualização for zero ou `end_us < start_us`.
+    pub fn frames_between(&self, start_us: u64, end_us: u64) -> u32 {
+        if self.refresh_rate_mhz == 0 || end_us < start_us {
+            return 0;
+        }
+        let frames = self.refresh_index(end_us) - self.refresh_index(start_us);
+        frames.min(u32::MAX as u64) as u32
+    }
+
+    /// Tamanho lógico do display sob `rotation` (dimensões trocadas em 90/270).
+    #[inline]
+    pub const fn logical_size(&self, rotation: Rotation) -> Size {
+        if rotation.swaps_dimensions() {
+            Size::new(self.height, self.width)
+        } else {
+            Size::new(self.width, self.height)
+        }
+    }
+
+    /// Transformação de coordenadas lógicas para a orientação física do painel.
+    ///
+    /// O espaço lógico tem o tamanho de [`logical_size`](Self::logical_size);
+    /// o resultado cobre exatamente `0..width` x `0..height` do painel. Os
+    /// coeficientes são inteiros, então compor as transformações é exato.
+    pub fn rotation_transform(&self, rotation: Rotation) -> Transform2D {
+        let (w, h) = (self.width as f32, self.height as f32);
+        match rotation {
+            Rotation::None => Transform2D::identity(),
+            Rotation::Rotate90 => Transform2D::new(0.0, 1.0, -1.0, 0.0, w, 0.0),
+            Rotation::Rotate180 => Transform2D::new(-1.0, 0.0, 0.0, -1.0, w, h),
+            Rotation::Rotate270 => Transform2D::new(0.0, -1.0, 1.0, 0.0, 0.0, h),
+            Rotation::FlipH => Transform2D::new(-1.0, 0.0, 0.0, 1.0, w, 0.0),
+            Rotation::FlipV => Transform2D::new(1.0, 0.0, 0.0, -1.0, 0.0, h),
+        }
+    }
 }
 
 // =============================================================================
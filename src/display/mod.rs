@@ -4,6 +4,13 @@
 
 mod info;
 mod output;
+mod record;
+mod rotation;
 
 pub use info::{DisplayInfo, DisplayMode, VsyncMode};
-pub use output::{ConnectorType, OutputInfo};
+pub use output::{
+    filter_modes_for_connector, ConnectorType, OutputInfo, ANALOG_MAX_HEIGHT, ANALOG_MAX_WIDTH,
+    REFERENCE_DPI,
+};
+pub use record::{DisplayRecord, DISPLAY_RECORD_VERSION};
+pub use rotation::Rotation;
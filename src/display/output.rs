@@ -2,6 +2,16 @@
 //!
 //! Tipos de conectores e outputs de display.
 
+use super::DisplayMode;
+use crate::color::PixelFormat;
+
+/// Largura máxima aceita em conectores analógicos.
+pub const ANALOG_MAX_WIDTH: u32 = 2048;
+/// Altura máxima aceita em conectores analógicos.
+pub const ANALOG_MAX_HEIGHT: u32 = 1536;
+/// Densidade (DPI) que corresponde à escala de buffer 1.
+pub const REFERENCE_DPI: f32 = 96.0;
+
 /// Tipo de conector de display.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
@@ -108,6 +118,25 @@ impl ConnectorType {
         )
     }
 
+    /// Banda útil aproximada do link, em bits por segundo.
+    ///
+    /// Considera a versão mais comum de cada padrão (DP 1.4 HBR3, HDMI 2.0,
+    /// DVI dual-link, MIPI DSI com 4 lanes). `Unknown` e `Virtual` não têm
+    /// limite.
+    #[inline]
+    pub const fn max_bandwidth(&self) -> u64 {
+        match self {
+            Self::Unknown | Self::Virtual => u64::MAX,
+            Self::VGA | Self::DVIA | Self::Component => 9_600_000_000,
+            Self::DVII | Self::DVID => 7_920_000_000,
+            Self::Composite | Self::SVideo | Self::TV => 324_000_000,
+            Self::LVDS => 5_400_000_000,
+            Self::DisplayPort | Self::EDP | Self::USBC => 25_920_000_000,
+            Self::HDMIA | Self::HDMIB => 14_400_000_000,
+            Self::DSI => 10_000_000_000,
+        }
+    }
+
     /// Verifica se suporta áudio.
     #[inline]
     pub const fn supports_audio(&self) -> bool {
@@ -118,6 +147,43 @@ impl ConnectorType {
     }
 }
 
+/// Banda necessária (bits/s) para um modo, sem contar blanking.
+///
+/// Alpha e padding não trafegam no link, então formatos de 32 bits contam
+/// como 24 bits por pixel.
+#[inline]
+fn mode_bandwidth(mode: &DisplayMode, format: PixelFormat) -> u64 {
+    let bits = (format.bytes_per_pixel() as u64 * 8).min(24);
+    mode.width as u64 * mode.height as u64 * mode.refresh_rate_mhz as u64 * bits / 1000
+}
+
+/// Filtra os modos que o conector consegue exibir no formato dado.
+///
+/// Descarta modos acima de [`ConnectorType::max_bandwidth`] e, em
+/// conectores analógicos, acima de `ANALOG_MAX_WIDTH` x `ANALOG_MAX_HEIGHT`.
+/// Mantém a ordem original e retorna quantos modos foram escritos em `out`.
+pub fn filter_modes_for_connector(
+    modes: &[DisplayMode],
+    conn: ConnectorType,
+    format: PixelFormat,
+    out: &mut [DisplayMode],
+) -> usize {
+    let analog =
+        !conn.is_digital() && !matches!(conn, ConnectorType::Unknown | ConnectorType::Virtual);
+    let accepted = modes.iter().filter(|mode| {
+        let too_large =
+            analog && (mode.width > ANALOG_MAX_WIDTH || mode.height > ANALOG_MAX_HEIGHT);
+        !too_large && mode_bandwidth(mode, format) <= conn.max_bandwidth()
+    });
+
+    let mut count = 0;
+    for (slot, mode) in out.iter_mut().zip(accepted) {
+        *slot = *mode;
+        count += 1;
+    }
+    count
+}
+
 /// Informações de um output/conector.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default)]
@@ -165,4 +231,16 @@ impl OutputInfo {
             (None, None) => None,
         }
     }
+
+    /// Escala de buffer inteira para um modo de `width_px` x `height_px`.
+    ///
+    /// Arredonda a densidade física para o múltiplo mais próximo de
+    /// [`REFERENCE_DPI`] (mínimo 1). Sem dimensões físicas, retorna 1.
+    #[inline]
+    pub fn buffer_scale(&self, width_px: u32, height_px: u32) -> u32 {
+        match self.dpi(width_px, height_px) {
+            Some(dpi) => (rdsmath::floorf(dpi / REFERENCE_DPI + 0.5) as u32).max(1),
+            None => 1,
+        }
+    }
 }
@@ -0,0 +1,106 @@
+//! # Display Record
+//!
+//! Registro versionado de display para enumeração via ABI.
+
+use super::{ConnectorType, DisplayInfo, OutputInfo};
+use crate::color::PixelFormat;
+
+/// Versão atual do formato de `DisplayRecord`.
+pub const DISPLAY_RECORD_VERSION: u16 = 1;
+
+/// Registro de display com layout fixo e versionado.
+///
+/// Todos os campos são inteiros simples, de modo que qualquer sequência
+/// de bytes é um registro válido para leitura. Campos novos devem ocupar
+/// `reserved` e incrementar a versão, sem alterar o tamanho.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayRecord {
+    /// Versão do formato.
+    pub version: u16,
+    /// Tamanho do registro em bytes.
+    pub size: u16,
+    /// ID do display.
+    pub id: u32,
+    /// Largura em pixels.
+    pub width: u32,
+    /// Altura em pixels.
+    pub height: u32,
+    /// Taxa de atualização em milihertz.
+    pub refresh_rate_mhz: u32,
+    /// Formato de pixel (`PixelFormat` como u32).
+    pub format: u32,
+    /// Stride do framebuffer em bytes.
+    pub stride: u32,
+    /// ID do output.
+    pub output_id: u32,
+    /// Tipo de conector (`ConnectorType` como u8).
+    pub connector: u8,
+    /// Conectado (0 ou 1).
+    pub connected: u8,
+    /// Padding explícito.
+    pub _pad: [u8; 2],
+    /// Largura física em mm.
+    pub width_mm: u32,
+    /// Altura física em mm.
+    pub height_mm: u32,
+    /// Reservado para versões futuras (deve ser zero).
+    pub reserved: [u32; 8],
+}
+
+impl DisplayRecord {
+    /// Tamanho do registro em bytes.
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Decodifica o registro, rejeitando versões ou valores desconhecidos.
+    pub fn decode(&self) -> Option<(DisplayInfo, OutputInfo)> {
+        if self.version != DISPLAY_RECORD_VERSION || self.size as usize != Self::SIZE {
+            return None;
+        }
+        let display = DisplayInfo::new(
+            self.id,
+            self.width,
+            self.height,
+            self.refresh_rate_mhz,
+            PixelFormat::from_u32(self.format)?,
+            self.stride,
+        );
+        let output = OutputInfo {
+            id: self.output_id,
+            connector: ConnectorType::from_u8(self.connector)?,
+            connected: self.connected != 0,
+            width_mm: self.width_mm,
+            height_mm: self.height_mm,
+        };
+        Some((display, output))
+    }
+}
+
+impl DisplayInfo {
+    /// Serializa junto com o output em um registro versionado.
+    pub const fn to_record(&self, output: &OutputInfo) -> DisplayRecord {
+        DisplayRecord {
+            version: DISPLAY_RECORD_VERSION,
+            size: DisplayRecord::SIZE as u16,
+            id: self.id,
+            width: self.width,
+            height: self.height,
+            refresh_rate_mhz: self.refresh_rate_mhz,
+            format: self.format.as_u32(),
+            stride: self.stride,
+            output_id: output.id,
+            connector: output.connector as u8,
+            connected: output.connected as u8,
+            _pad: [0; 2],
+            width_mm: output.width_mm,
+            height_mm: output.height_mm,
+            reserved: [0; 8],
+        }
+    }
+
+    /// Reconstrói display e output a partir de um registro.
+    #[inline]
+    pub fn from_record(record: &DisplayRecord) -> Option<(DisplayInfo, OutputInfo)> {
+        record.decode()
+    }
+}
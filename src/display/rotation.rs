@@ -0,0 +1,63 @@
+//! # Display Rotation
+//!
+//! Orientação física do painel em relação ao espaço lógico.
+
+// =============================================================================
+// ROTATION
+// =============================================================================
+
+/// Rotação/espelhamento aplicado ao conteúdo lógico para chegar ao painel.
+///
+/// Rotações são no sentido horário (Y para baixo).
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum Rotation {
+    /// Sem rotação.
+    #[default]
+    None = 0,
+    /// 90° no sentido horário.
+    Rotate90 = 1,
+    /// 180°.
+    Rotate180 = 2,
+    /// 270° no sentido horário (90° anti-horário).
+    Rotate270 = 3,
+    /// Espelhamento horizontal.
+    FlipH = 4,
+    /// Espelhamento vertical.
+    FlipV = 5,
+}
+
+impl Rotation {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::None),
+            1 => Some(Self::Rotate90),
+            2 => Some(Self::Rotate180),
+            3 => Some(Self::Rotate270),
+            4 => Some(Self::FlipH),
+            5 => Some(Self::FlipV),
+            _ => None,
+        }
+    }
+
+    /// Nome da rotação.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Rotate90 => "Rotate90",
+            Self::Rotate180 => "Rotate180",
+            Self::Rotate270 => "Rotate270",
+            Self::FlipH => "FlipH",
+            Self::FlipV => "FlipV",
+        }
+    }
+
+    /// Verifica se largura e altura lógicas são trocadas em relação ao painel.
+    #[inline]
+    pub const fn swaps_dimensions(&self) -> bool {
+        matches!(self, Self::Rotate90 | Self::Rotate270)
+    }
+}
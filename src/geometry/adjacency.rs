@@ -0,0 +1,40 @@
+//! # Adjacency
+//!
+//! Relação de vizinhança entre retângulos que não se sobrepõem.
+
+/// Como outro retângulo encosta neste (do ponto de vista de `self`).
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// O outro está logo acima, compartilhando a borda superior.
+    SharesTopEdge = 0,
+    /// O outro está logo abaixo, compartilhando a borda inferior.
+    SharesBottomEdge = 1,
+    /// O outro está logo à esquerda, compartilhando a borda esquerda.
+    SharesLeftEdge = 2,
+    /// O outro está logo à direita, compartilhando a borda direita.
+    SharesRightEdge = 3,
+    /// Os dois se tocam apenas em um canto.
+    DiagonalTouch = 4,
+}
+
+impl Adjacency {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::SharesTopEdge),
+            1 => Some(Self::SharesBottomEdge),
+            2 => Some(Self::SharesLeftEdge),
+            3 => Some(Self::SharesRightEdge),
+            4 => Some(Self::DiagonalTouch),
+            _ => None,
+        }
+    }
+
+    /// Verifica se compartilham um segmento de borda (não só um canto).
+    #[inline]
+    pub const fn shares_edge(&self) -> bool {
+        !matches!(self, Self::DiagonalTouch)
+    }
+}
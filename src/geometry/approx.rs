@@ -0,0 +1,90 @@
+//! # Approximate Equality
+//!
+//! Comparação com tolerância para tipos de ponto flutuante.
+
+use super::{Circle, Ellipse, LineF, PointF, RectF, RoundedRect, SizeF, Transform2D};
+
+/// Igualdade aproximada, componente a componente.
+///
+/// Dois valores são iguais se cada componente difere no máximo `epsilon`.
+/// `NaN` nunca é igual a nada.
+pub trait ApproxEq {
+    /// Compara com tolerância `epsilon`.
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+impl ApproxEq for f32 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        rdsmath::absf(self - other) <= epsilon
+    }
+}
+
+impl ApproxEq for PointF {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.x.approx_eq(&other.x, epsilon) && self.y.approx_eq(&other.y, epsilon)
+    }
+}
+
+impl ApproxEq for SizeF {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.width.approx_eq(&other.width, epsilon) && self.height.approx_eq(&other.height, epsilon)
+    }
+}
+
+impl ApproxEq for RectF {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.origin().approx_eq(&other.origin(), epsilon)
+            && self.size().approx_eq(&other.size(), epsilon)
+    }
+}
+
+impl ApproxEq for RoundedRect {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.rect.approx_eq(&other.rect, epsilon) && self.radius.approx_eq(&other.radius, epsilon)
+    }
+}
+
+impl ApproxEq for LineF {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.start.approx_eq(&other.start, epsilon) && self.end.approx_eq(&other.end, epsilon)
+    }
+}
+
+impl ApproxEq for Circle {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && self.radius.approx_eq(&other.radius, epsilon)
+    }
+}
+
+impl ApproxEq for Ellipse {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && self.radius_x.approx_eq(&other.radius_x, epsilon)
+            && self.radius_y.approx_eq(&other.radius_y, epsilon)
+    }
+}
+
+impl ApproxEq for Transform2D {
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        [
+            (self.a, other.a),
+            (self.b, other.b),
+            (self.c, other.c),
+            (self.d, other.d),
+            (self.tx, other.tx),
+            (self.ty, other.ty),
+        ]
+        .iter()
+        .all(|(x, y)| x.approx_eq(y, epsilon))
+    }
+}
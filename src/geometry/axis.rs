@@ -0,0 +1,44 @@
+//! # Axis
+//!
+//! Eixos do plano 2D.
+
+/// Eixo do plano 2D.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum Axis {
+    /// Eixo horizontal (X).
+    #[default]
+    X = 0,
+    /// Eixo vertical (Y).
+    Y = 1,
+}
+
+impl Axis {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::X),
+            1 => Some(Self::Y),
+            _ => None,
+        }
+    }
+
+    /// Nome do eixo.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::X => "X",
+            Self::Y => "Y",
+        }
+    }
+
+    /// Eixo perpendicular.
+    #[inline]
+    pub const fn cross(&self) -> Self {
+        match self {
+            Self::X => Self::Y,
+            Self::Y => Self::X,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! # Content Fit
+//!
+//! Posicionamento de conteúdo dentro de uma área (estilo CSS
+//! `object-fit` / `object-position`).
+
+/// Como o conteúdo é escalado para a área de destino.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum ContentFit {
+    /// Cabe inteiro, mantendo proporção (letterbox).
+    #[default]
+    Contain = 0,
+    /// Cobre toda a área, mantendo proporção (recorta o excesso).
+    Cover = 1,
+    /// Estica para preencher a área (ignora proporção).
+    Fill = 2,
+    /// Como `Contain`, mas nunca aumenta o conteúdo.
+    ScaleDown = 3,
+    /// Tamanho original, sem escala.
+    None = 4,
+}
+
+impl ContentFit {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Contain),
+            1 => Some(Self::Cover),
+            2 => Some(Self::Fill),
+            3 => Some(Self::ScaleDown),
+            4 => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Nome do modo.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Contain => "Contain",
+            Self::Cover => "Cover",
+            Self::Fill => "Fill",
+            Self::ScaleDown => "ScaleDown",
+            Self::None => "None",
+        }
+    }
+}
+
+/// Posição do conteúdo dentro da área de destino.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum Alignment {
+    /// Canto superior esquerdo.
+    TopLeft = 0,
+    /// Topo, centralizado na horizontal.
+    Top = 1,
+    /// Canto superior direito.
+    TopRight = 2,
+    /// Esquerda, centralizado na vertical.
+    Left = 3,
+    /// Centro.
+    #[default]
+    Center = 4,
+    /// Direita, centralizado na vertical.
+    Right = 5,
+    /// Canto inferior esquerdo.
+    BottomLeft = 6,
+    /// Base, centralizado na horizontal.
+    Bottom = 7,
+    /// Canto inferior direito.
+    BottomRight = 8,
+}
+
+impl Alignment {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::TopLeft),
+            1 => Some(Self::Top),
+            2 => Some(Self::TopRight),
+            3 => Some(Self::Left),
+            4 => Some(Self::Center),
+            5 => Some(Self::Right),
+            6 => Some(Self::BottomLeft),
+            7 => Some(Self::Bottom),
+            8 => Some(Self::BottomRight),
+            _ => None,
+        }
+    }
+
+    /// Fração do espaço livre à esquerda e acima (0.0, 0.5 ou 1.0).
+    #[inline]
+    pub const fn factors(&self) -> (f32, f32) {
+        let index = *self as u8;
+        let fx = (index % 3) as f32 * 0.5;
+        let fy = (index / 3) as f32 * 0.5;
+        (fx, fy)
+    }
+}
@@ -0,0 +1,35 @@
+//! # Hit
+//!
+//! Classificação de pontos em relação a formas com borda.
+
+/// Região atingida por um ponto em uma forma com borda.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum HitRegion {
+    /// Fora da forma.
+    #[default]
+    Outside = 0,
+    /// Na faixa de borda (ex: área de redimensionamento).
+    Border = 1,
+    /// No interior (conteúdo).
+    Interior = 2,
+}
+
+impl HitRegion {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Outside),
+            1 => Some(Self::Border),
+            2 => Some(Self::Interior),
+            _ => None,
+        }
+    }
+
+    /// Verifica se o ponto está dentro da forma (borda ou interior).
+    #[inline]
+    pub const fn is_inside(&self) -> bool {
+        !matches!(self, Self::Outside)
+    }
+}
@@ -4,6 +4,8 @@
 
 use core::ops::{Add, Sub};
 
+use super::Rect;
+
 /// Margens em todas as direções.
 ///
 /// Usado para padding, margens e bordas.
@@ -102,6 +104,20 @@ impl Insets {
         }
     }
 
+    /// Margens entre `outer` e `inner`, tais que
+    /// `outer.inset(Insets::between(outer, inner)) == inner`.
+    ///
+    /// Valores negativos indicam que `inner` ultrapassa `outer` naquela borda.
+    #[inline]
+    pub const fn between(outer: Rect, inner: Rect) -> Self {
+        Self {
+            top: inner.top().saturating_sub(outer.top()),
+            right: outer.right().saturating_sub(inner.right()),
+            bottom: outer.bottom().saturating_sub(inner.bottom()),
+            left: inner.left().saturating_sub(outer.left()),
+        }
+    }
+
     /// Soma horizontal (left + right).
     #[inline]
     pub const fn horizontal(&self) -> i32 {
@@ -195,6 +195,65 @@ impl LineF {
             end: self.end.round(),
         }
     }
+
+    /// Divide a linha em traços segundo `pattern` (comprimentos alternados
+    /// de traço e espaço), começando `phase` unidades dentro do padrão.
+    ///
+    /// Escreve os sub-segmentos "ligados" em `out` e retorna quantos foram
+    /// escritos (no máximo `out.len()`). Padrões de tamanho ímpar são
+    /// repetidos duas vezes, como em SVG. Um padrão vazio, com valores
+    /// negativos ou de soma zero desenha a linha sólida, assim como valores
+    /// positivos pequenos demais para avançar a posição em `f32` (abaixo de
+    /// `f32::EPSILON` vezes o maior entre o comprimento e o período).
+    pub fn dashed(&self, pattern: &[f32], phase: f32, out: &mut [LineF]) -> usize {
+        let len = self.length();
+        if out.is_empty() || len <= 0.0 || !len.is_finite() {
+            return 0;
+        }
+        let sum: f32 = pattern.iter().sum();
+        if pattern.iter().any(|&d| d < 0.0) || sum <= 0.0 || !sum.is_finite() {
+            out[0] = *self;
+            return 1;
+        }
+
+        // Padrão efetivo: ímpar é repetido para alternar corretamente
+        let n = if pattern.len() % 2 == 1 {
+            pattern.len() * 2
+        } else {
+            pattern.len()
+        };
+        let period = sum * (n / pattern.len()) as f32;
+
+        // Um passo menor que um ULP de `pos` (ou da fase) não avança
+        let min_step = len.max(period) * f32::EPSILON;
+        if pattern.iter().any(|&d| d > 0.0 && d < min_step) {
+            out[0] = *self;
+            return 1;
+        }
+
+        // Posicionar a fase dentro do período
+        let mut offset = phase - rdsmath::floorf(phase / period) * period;
+        let mut i = 0;
+        while offset >= pattern[i % pattern.len()] && offset > 0.0 {
+            offset -= pattern[i % pattern.len()];
+            i = (i + 1) % n;
+        }
+        let mut remaining = pattern[i % pattern.len()] - offset;
+
+        let mut count = 0;
+        let mut pos = 0.0;
+        while pos < len && count < out.len() {
+            let end = (pos + remaining).min(len);
+            if i % 2 == 0 && end > pos {
+                out[count] = LineF::new(self.point_at(pos / len), self.point_at(end / len));
+                count += 1;
+            }
+            pos = end;
+            i = (i + 1) % n;
+            remaining = pattern[i % pattern.len()];
+        }
+        count
+    }
 }
 
 impl From<Line> for LineF {
@@ -2,20 +2,37 @@
 //!
 //! Primitivas geométricas para operações gráficas.
 
+mod adjacency;
+mod approx;
+mod axis;
 mod circle;
+mod fit;
+mod hit;
 mod insets;
 mod line;
 mod point;
 mod polygon;
+mod popup;
 mod rect;
+mod rect_list;
 mod size;
 mod transform;
 
+pub use adjacency::Adjacency;
+pub use approx::ApproxEq;
+pub use axis::Axis;
 pub use circle::{Circle, Ellipse};
+pub use fit::{Alignment, ContentFit};
+pub use hit::HitRegion;
 pub use insets::Insets;
 pub use line::{Line, LineF};
 pub use point::{Point, PointF};
 pub use polygon::{FillRule, PathSegment, StaticPolygon, MAX_STATIC_POINTS};
+pub use popup::PopupGravity;
 pub use rect::{Rect, RectF, RoundedRect};
+pub use rect_list::{
+    bounding_box, covered_area, flow_layout, nearest_by_center, nearest_by_edge, snap_layout,
+    total_area,
+};
 pub use size::{Size, SizeF};
-pub use transform::Transform2D;
+pub use transform::{DecomposedTransform, Transform2D};
@@ -5,6 +5,8 @@
 use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 use rdsmath::{ceilf, floorf, roundf, sqrtf};
 
+use super::{Axis, Rect};
+
 // =============================================================================
 // POINT (Integer)
 // =============================================================================
@@ -55,6 +57,43 @@ impl Point {
         }
     }
 
+    /// Coordenada ao longo de um eixo.
+    #[inline]
+    pub const fn coord(&self, axis: Axis) -> i32 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+        }
+    }
+
+    /// Retorna a coordenada no eixo, atraída para a guia mais próxima.
+    ///
+    /// Se nenhuma guia estiver a até `threshold` pixels, retorna a
+    /// coordenada original.
+    pub fn snap_to_guides(&self, guides: &[i32], axis: Axis, threshold: i32) -> i32 {
+        let value = self.coord(axis);
+        guides
+            .iter()
+            .copied()
+            .filter(|g| (g - value).abs() <= threshold)
+            .min_by_key(|g| (g - value).abs())
+            .unwrap_or(value)
+    }
+
+    /// Limita o ponto ao pixel mais próximo dentro de `r`.
+    ///
+    /// O resultado satisfaz `r.contains_point`, exceto para retângulos
+    /// vazios, onde retorna a origem de `r`.
+    pub fn clamp_to_rect(&self, r: Rect) -> Point {
+        if r.is_empty() {
+            return Point::new(r.x, r.y);
+        }
+        Point {
+            x: self.x.clamp(r.x, r.right() - 1),
+            y: self.y.clamp(r.y, r.bottom() - 1),
+        }
+    }
+
     /// Converte para PointF.
     #[inline]
     pub const fn to_float(&self) -> PointF {
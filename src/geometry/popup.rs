@@ -0,0 +1,52 @@
+//! # Popup Placement
+//!
+//! Lado preferido de um popup em relação à sua âncora.
+
+/// Lado da âncora onde um popup (menu, tooltip) é posicionado.
+///
+/// Equivale ao `gravity` do xdg-positioner do Wayland, restrito aos quatro
+/// lados.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum PopupGravity {
+    /// Abaixo da âncora, alinhado à esquerda.
+    #[default]
+    Bottom = 0,
+    /// Acima da âncora, alinhado à esquerda.
+    Top = 1,
+    /// À direita da âncora, alinhado ao topo.
+    Right = 2,
+    /// À esquerda da âncora, alinhado ao topo.
+    Left = 3,
+}
+
+impl PopupGravity {
+    /// Converte de u8.
+    #[inline]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Bottom),
+            1 => Some(Self::Top),
+            2 => Some(Self::Right),
+            3 => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Lado oposto (usado ao inverter um popup que não cabe).
+    #[inline]
+    pub const fn opposite(&self) -> Self {
+        match self {
+            Self::Bottom => Self::Top,
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Verifica se posiciona acima ou abaixo.
+    #[inline]
+    pub const fn is_vertical(&self) -> bool {
+        matches!(self, Self::Bottom | Self::Top)
+    }
+}
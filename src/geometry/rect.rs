@@ -2,7 +2,11 @@
 //!
 //! Retângulos definidos por posição e tamanho.
 
-use super::{Point, PointF, Size, SizeF};
+use super::{
+    Adjacency, Alignment, Axis, ContentFit, HitRegion, Insets, Point, PointF, PopupGravity, Size,
+    SizeF, StaticPolygon, MAX_STATIC_POINTS,
+};
+use crate::window::ResizeEdge;
 
 // =============================================================================
 // RECT (Integer)
@@ -61,20 +65,67 @@ impl Rect {
     }
 
     /// Cria a partir de dois pontos (canto superior esquerdo e inferior direito).
+    ///
+    /// Dimensões acima de `i32::MAX` são saturadas nesse valor, para que
+    /// `right()`/`bottom()` continuem representáveis (a borda fica aquém do
+    /// ponto). Use [`from_points_checked`](Self::from_points_checked) para
+    /// detectar esse caso.
     #[inline]
     pub fn from_points(p1: Point, p2: Point) -> Self {
         let x1 = p1.x.min(p2.x);
         let y1 = p1.y.min(p2.y);
-        let x2 = p1.x.max(p2.x);
-        let y2 = p1.y.max(p2.y);
+        let width = (p1.x as i64 - p2.x as i64).unsigned_abs();
+        let height = (p1.y as i64 - p2.y as i64).unsigned_abs();
         Self {
             x: x1,
             y: y1,
-            width: (x2 - x1) as u32,
-            height: (y2 - y1) as u32,
+            width: width.min(i32::MAX as u64) as u32,
+            height: height.min(i32::MAX as u64) as u32,
         }
     }
 
+    /// Cria a partir de dois pontos, retornando `None` se a largura ou a
+    /// altura passar de `i32::MAX`.
+    ///
+    /// Acima disso `width as i32` fica negativo e `right()`/`bottom()` deixam
+    /// de reproduzir o ponto de origem; dentro do limite, `right()` e
+    /// `bottom()` são exatamente as maiores coordenadas dos pontos.
+    #[inline]
+    pub fn from_points_checked(p1: Point, p2: Point) -> Option<Self> {
+        let width = (p1.x as i64 - p2.x as i64).unsigned_abs();
+        let height = (p1.y as i64 - p2.y as i64).unsigned_abs();
+        if width > i32::MAX as u64 || height > i32::MAX as u64 {
+            return None;
+        }
+        Some(Self {
+            x: p1.x.min(p2.x),
+            y: p1.y.min(p2.y),
+            width: width as u32,
+            height: height as u32,
+        })
+    }
+
+    /// Cria a partir das bordas esquerda, superior, direita e inferior.
+    ///
+    /// Extensões negativas (`r < l` ou `b < t`) viram zero.
+    #[inline]
+    pub const fn from_ltrb(l: i32, t: i32, r: i32, b: i32) -> Self {
+        let width = r as i64 - l as i64;
+        let height = b as i64 - t as i64;
+        Self {
+            x: l,
+            y: t,
+            width: if width > 0 { width as u32 } else { 0 },
+            height: if height > 0 { height as u32 } else { 0 },
+        }
+    }
+
+    /// Bordas `(esquerda, topo, direita, fundo)`; direita e fundo exclusivos.
+    #[inline]
+    pub const fn to_ltrb(&self) -> (i32, i32, i32, i32) {
+        (self.x, self.y, self.right(), self.bottom())
+    }
+
     /// Retorna o canto superior esquerdo.
     #[inline]
     pub const fn origin(&self) -> Point {
@@ -170,6 +221,86 @@ impl Rect {
         }
     }
 
+    /// Distância horizontal e vertical até `other`.
+    ///
+    /// Cada componente é negativo quando as projeções se sobrepõem naquele
+    /// eixo (o valor é menos o comprimento da sobreposição) e 0 quando as
+    /// bordas se tocam.
+    #[inline]
+    pub fn gap_to(&self, other: &Rect) -> (i32, i32) {
+        let gx = self.x.max(other.x) - self.right().min(other.right());
+        let gy = self.y.max(other.y) - self.bottom().min(other.bottom());
+        (gx, gy)
+    }
+
+    /// Como `other` encosta neste retângulo, se encostar.
+    ///
+    /// Retorna `None` se houver sobreposição, espaço entre eles ou algum
+    /// retângulo vazio.
+    pub fn adjacency(&self, other: &Rect) -> Option<Adjacency> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        match self.gap_to(other) {
+            (0, 0) => Some(Adjacency::DiagonalTouch),
+            (0, gy) if gy < 0 => Some(if other.x >= self.right() {
+                Adjacency::SharesRightEdge
+            } else {
+                Adjacency::SharesLeftEdge
+            }),
+            (gx, 0) if gx < 0 => Some(if other.y >= self.bottom() {
+                Adjacency::SharesBottomEdge
+            } else {
+                Adjacency::SharesTopEdge
+            }),
+            _ => None,
+        }
+    }
+
+    /// Área da interseção dividida pela área do menor retângulo (0.0-1.0).
+    ///
+    /// Retorna 0.0 se algum dos retângulos for vazio.
+    pub fn overlap_ratio(&self, other: &Rect) -> f32 {
+        let smaller = self.area().min(other.area());
+        if smaller == 0 {
+            return 0.0;
+        }
+        let overlap = self.intersection(other).map_or(0, |r| r.area());
+        overlap as f32 / smaller as f32
+    }
+
+    /// Interseção sobre união (IoU) das áreas (0.0-1.0).
+    ///
+    /// Retorna 0.0 se algum dos retângulos for vazio.
+    pub fn iou(&self, other: &Rect) -> f32 {
+        if self.is_empty() || other.is_empty() {
+            return 0.0;
+        }
+        let overlap = self.intersection(other).map_or(0, |r| r.area());
+        let union = self.area() + other.area() - overlap;
+        overlap as f32 / union as f32
+    }
+
+    /// Distância ao quadrado entre os centros de dois retângulos.
+    #[inline]
+    pub fn center_distance_sq(&self, other: &Rect) -> i64 {
+        let a = self.center();
+        let b = other.center();
+        let dx = a.x as i64 - b.x as i64;
+        let dy = a.y as i64 - b.y as i64;
+        dx * dx + dy * dy
+    }
+
+    /// Distância ao quadrado de um ponto até a borda mais próxima
+    /// (0 se o ponto estiver dentro).
+    #[inline]
+    pub fn edge_distance_sq(&self, p: Point) -> i64 {
+        let (px, py) = (p.x as i64, p.y as i64);
+        let dx = (self.x as i64 - px).max(px - self.right() as i64).max(0);
+        let dy = (self.y as i64 - py).max(py - self.bottom() as i64).max(0);
+        dx * dx + dy * dy
+    }
+
     /// Calcula a união (bounding box) de dois retângulos.
     pub fn union(&self, other: &Rect) -> Rect {
         if self.is_empty() {
@@ -215,6 +346,19 @@ impl Rect {
         self.expand(-amount)
     }
 
+    /// Recua cada borda pelo inset correspondente (valores negativos
+    /// expandem). Extensões que ficariam negativas viram zero.
+    #[inline]
+    pub const fn inset(&self, insets: Insets) -> Self {
+        let (l, t, r, b) = self.to_ltrb();
+        Self::from_ltrb(
+            l.saturating_add(insets.left),
+            t.saturating_add(insets.top),
+            r.saturating_sub(insets.right),
+            b.saturating_sub(insets.bottom),
+        )
+    }
+
     /// Divide horizontalmente em duas partes.
     #[inline]
     pub fn split_horizontal(&self, at: u32) -> (Rect, Rect) {
@@ -245,6 +389,223 @@ impl Rect {
         )
     }
 
+    /// Divide em quatro quadrantes `[NW, NE, SW, SE]` (células de quadtree).
+    ///
+    /// Os quadrantes ladrilham o retângulo exatamente; em dimensões ímpares
+    /// o pixel restante vai para os quadrantes da direita/de baixo.
+    #[inline]
+    pub fn quad_split(&self) -> [Rect; 4] {
+        let (west, east) = self.split_horizontal(self.width / 2);
+        let (nw, sw) = west.split_vertical(self.height / 2);
+        let (ne, se) = east.split_vertical(self.height / 2);
+        [nw, ne, sw, se]
+    }
+
+    /// Divide em uma fração (0.0-1.0) da largura (`horizontal`) ou altura.
+    ///
+    /// O ponto de corte é arredondado para o pixel mais próximo e as duas
+    /// metades sempre somam a extensão original.
+    #[inline]
+    pub fn split_at_fraction(&self, fraction: f32, horizontal: bool) -> (Rect, Rect) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let extent = if horizontal { self.width } else { self.height };
+        let at = rdsmath::roundf(extent as f32 * fraction) as u32;
+        if horizontal {
+            self.split_horizontal(at)
+        } else {
+            self.split_vertical(at)
+        }
+    }
+
+    /// Gera `count` keyframes interpolando linearmente até `target`.
+    ///
+    /// Inclui os extremos: o primeiro é `self` e o último é `target`. Os
+    /// intermediários são arredondados para fora com [`RectF::round_out`].
+    pub fn steps_to(&self, target: Rect, count: u32) -> impl Iterator<Item = Rect> {
+        let from = self.to_float();
+        let to = target.to_float();
+        let start = *self;
+        (0..count).map(move |i| {
+            if i == 0 {
+                start
+            } else if i + 1 == count {
+                target
+            } else {
+                from.lerp(&to, i as f32 / (count - 1) as f32).round_out()
+            }
+        })
+    }
+
+    /// Recorta uma faixa de espessura `amount` de uma borda.
+    ///
+    /// Retorna `(faixa, restante)`. Se `amount` exceder a extensão, a faixa
+    /// ocupa tudo e o restante fica vazio. Cantos não definem uma faixa:
+    /// retornam uma faixa vazia e o retângulo intacto.
+    pub fn dock(&self, edge: ResizeEdge, amount: u32) -> (Rect, Rect) {
+        match edge {
+            ResizeEdge::Left => self.split_horizontal(amount),
+            ResizeEdge::Top => self.split_vertical(amount),
+            ResizeEdge::Right => {
+                let (rest, strip) = self.split_horizontal(self.width.saturating_sub(amount));
+                (strip, rest)
+            }
+            ResizeEdge::Bottom => {
+                let (rest, strip) = self.split_vertical(self.height.saturating_sub(amount));
+                (strip, rest)
+            }
+            _ => (Rect::new(self.x, self.y, 0, 0), *self),
+        }
+    }
+
+    /// Subtrai outro retângulo, retornando até 4 fragmentos restantes.
+    ///
+    /// Os fragmentos não se sobrepõem: faixas superior e inferior com a
+    /// largura total, e faixas esquerda e direita na altura da interseção.
+    pub fn subtract(&self, other: &Rect) -> ([Rect; 4], usize) {
+        let mut out = [Rect::ZERO; 4];
+        let inter = match self.intersection(other) {
+            Some(i) => i,
+            None => {
+                if self.is_empty() {
+                    return (out, 0);
+                }
+                out[0] = *self;
+                return (out, 1);
+            }
+        };
+
+        let mut count = 0;
+        // Faixa superior
+        if inter.y > self.y {
+            out[count] = Rect::new(self.x, self.y, self.width, (inter.y - self.y) as u32);
+            count += 1;
+        }
+        // Faixa inferior
+        if inter.bottom() < self.bottom() {
+            out[count] = Rect::new(
+                self.x,
+                inter.bottom(),
+                self.width,
+                (self.bottom() - inter.bottom()) as u32,
+            );
+            count += 1;
+        }
+        // Faixa esquerda (altura da interseção)
+        if inter.x > self.x {
+            out[count] = Rect::new(self.x, inter.y, (inter.x - self.x) as u32, inter.height);
+            count += 1;
+        }
+        // Faixa direita (altura da interseção)
+        if inter.right() < self.right() {
+            out[count] = Rect::new(
+                inter.right(),
+                inter.y,
+                (self.right() - inter.right()) as u32,
+                inter.height,
+            );
+            count += 1;
+        }
+
+        (out, count)
+    }
+
+    /// Alinha as bordas às bordas de alvos próximos (snapping de janelas).
+    ///
+    /// Cada borda é avaliada independentemente contra as bordas dos alvos;
+    /// o menor deslocamento dentro de `threshold` vence. O tamanho é mantido:
+    /// o snap horizontal usa a borda esquerda ou direita (a mais próxima), e
+    /// o vertical a superior ou inferior.
+    pub fn snap_to(&self, targets: &[Rect], threshold: i32) -> Rect {
+        let mut best_dx: Option<i32> = None;
+        let mut best_dy: Option<i32> = None;
+
+        let consider = |best: &mut Option<i32>, from: i32, to: i32| {
+            let delta = to - from;
+            if delta.abs() <= threshold && best.is_none_or(|b| delta.abs() < b.abs()) {
+                *best = Some(delta);
+            }
+        };
+
+        for t in targets {
+            for edge in [t.left(), t.right()] {
+                consider(&mut best_dx, self.left(), edge);
+                consider(&mut best_dx, self.right(), edge);
+            }
+            for edge in [t.top(), t.bottom()] {
+                consider(&mut best_dy, self.top(), edge);
+                consider(&mut best_dy, self.bottom(), edge);
+            }
+        }
+
+        self.offset(best_dx.unwrap_or(0), best_dy.unwrap_or(0))
+    }
+
+    /// Encaixa o retângulo dentro de `bounds`.
+    ///
+    /// Diferente de `intersection`, primeiro desliza o retângulo para
+    /// dentro dos limites; só recorta se ele for maior que `bounds`.
+    pub fn clamp_to(&self, bounds: Rect) -> Rect {
+        let (x, width) = Self::clamp_span(self.x, self.width, bounds.x, bounds.width);
+        let (y, height) = Self::clamp_span(self.y, self.height, bounds.y, bounds.height);
+        Rect::new(x, y, width, height)
+    }
+
+    /// Encaixa um intervalo 1D `[start, start + len)` em `[min, min + max_len)`.
+    #[inline]
+    fn clamp_span(start: i32, len: u32, min: i32, max_len: u32) -> (i32, u32) {
+        if len >= max_len {
+            return (min, max_len);
+        }
+        let max_start = min + (max_len - len) as i32;
+        (start.clamp(min, max_start), len)
+    }
+
+    /// Posiciona este popup junto a `anchor`, mantendo-o dentro de `screen`.
+    ///
+    /// Usa apenas o tamanho de `self`. Se o popup transbordar a tela no lado
+    /// preferido e couber no oposto, inverte (flip); depois desliza ao longo
+    /// dos eixos para dentro da tela (slide), como o xdg-positioner.
+    pub fn constrain_popup(&self, anchor: Rect, screen: Rect, prefer: PopupGravity) -> Rect {
+        let place = |gravity: PopupGravity| {
+            let (x, y) = match gravity {
+                PopupGravity::Bottom => (anchor.x as i64, anchor.bottom() as i64),
+                PopupGravity::Top => (anchor.x as i64, anchor.y as i64 - self.height as i64),
+                PopupGravity::Right => (anchor.right() as i64, anchor.y as i64),
+                PopupGravity::Left => (anchor.x as i64 - self.width as i64, anchor.y as i64),
+            };
+            let clamp = |v: i64| v.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+            Rect::new(clamp(x), clamp(y), self.width, self.height)
+        };
+        let fits = |r: &Rect| {
+            if prefer.is_vertical() {
+                r.top() >= screen.top() && r.bottom() <= screen.bottom()
+            } else {
+                r.left() >= screen.left() && r.right() <= screen.right()
+            }
+        };
+
+        let mut popup = place(prefer);
+        if !fits(&popup) {
+            let flipped = place(prefer.opposite());
+            if fits(&flipped) {
+                popup = flipped;
+            }
+        }
+        popup.clamp_to(screen)
+    }
+
+    /// Atrai o centro do retângulo para o centro do container.
+    ///
+    /// Cada eixo é ajustado independentemente quando a distância entre os
+    /// centros é de até `threshold` pixels.
+    pub fn snap_center_to(&self, container: Rect, threshold: i32) -> Rect {
+        let center = self.center();
+        let target = container.center();
+        let x = center.snap_to_guides(&[target.x], Axis::X, threshold);
+        let y = center.snap_to_guides(&[target.y], Axis::Y, threshold);
+        self.offset(x - center.x, y - center.y)
+    }
+
     /// Converte para RectF.
     #[inline]
     pub const fn to_float(&self) -> RectF {
@@ -326,6 +687,23 @@ impl RectF {
         self.y + self.height
     }
 
+    /// Cria a partir das bordas (extensões negativas viram zero).
+    #[inline]
+    pub fn from_ltrb(l: f32, t: f32, r: f32, b: f32) -> Self {
+        Self {
+            x: l,
+            y: t,
+            width: (r - l).max(0.0),
+            height: (b - t).max(0.0),
+        }
+    }
+
+    /// Bordas `(esquerda, topo, direita, fundo)`.
+    #[inline]
+    pub fn to_ltrb(&self) -> (f32, f32, f32, f32) {
+        (self.x, self.y, self.right(), self.bottom())
+    }
+
     /// Centro.
     #[inline]
     pub fn center(&self) -> PointF {
@@ -379,6 +757,54 @@ impl RectF {
             height: rdsmath::roundf(self.height) as u32,
         }
     }
+
+    /// Menor Rect inteiro que contém este retângulo.
+    #[inline]
+    pub fn round_out(&self) -> Rect {
+        let x = rdsmath::floorf(self.x);
+        let y = rdsmath::floorf(self.y);
+        let right = rdsmath::ceilf(self.right());
+        let bottom = rdsmath::ceilf(self.bottom());
+        Rect {
+            x: x as i32,
+            y: y as i32,
+            width: (right - x).max(0.0) as u32,
+            height: (bottom - y).max(0.0) as u32,
+        }
+    }
+
+    /// Posiciona conteúdo de tamanho `content` dentro deste retângulo.
+    ///
+    /// Equivalente a CSS `object-fit` + `object-position`. O resultado pode
+    /// exceder este retângulo (`Cover`, `None`); o recorte fica a cargo de
+    /// quem desenha.
+    pub fn fit_content(&self, content: SizeF, fit: ContentFit, align: Alignment) -> RectF {
+        if fit == ContentFit::Fill {
+            return *self;
+        }
+
+        let (width, height) = if content.width <= 0.0 || content.height <= 0.0 {
+            (0.0, 0.0)
+        } else {
+            let sx = self.width / content.width;
+            let sy = self.height / content.height;
+            let scale = match fit {
+                ContentFit::Contain => sx.min(sy),
+                ContentFit::Cover => sx.max(sy),
+                ContentFit::ScaleDown => sx.min(sy).min(1.0),
+                ContentFit::None | ContentFit::Fill => 1.0,
+            };
+            (content.width * scale, content.height * scale)
+        };
+
+        let (fx, fy) = align.factors();
+        RectF {
+            x: self.x + (self.width - width) * fx,
+            y: self.y + (self.height - height) * fy,
+            width,
+            height,
+        }
+    }
 }
 
 impl From<Rect> for RectF {
@@ -451,4 +877,101 @@ impl RoundedRect {
             height: self.rect.height - r * 2.0,
         }
     }
+
+    /// Anel de borda: forma externa e o buraco interno.
+    ///
+    /// O interno é recuado por `thickness` em cada lado, com raio
+    /// `max(0, radius - thickness)`. Para pintar a borda, preencha a externa
+    /// e subtraia a cobertura da interna.
+    pub fn border_ring(&self, thickness: f32) -> (RoundedRect, RoundedRect) {
+        let t = thickness.max(0.0);
+        let inner = RectF {
+            x: self.rect.x + t,
+            y: self.rect.y + t,
+            width: (self.rect.width - t * 2.0).max(0.0),
+            height: (self.rect.height - t * 2.0).max(0.0),
+        };
+        (*self, RoundedRect::new(inner, (self.radius - t).max(0.0)))
+    }
+
+    /// Distância com sinal de um ponto até a borda (negativa dentro).
+    pub fn signed_distance(&self, p: PointF) -> f32 {
+        let r = self.clamped_radius();
+        let center = self.rect.center();
+        let qx = rdsmath::absf(p.x - center.x) - (self.rect.width * 0.5 - r);
+        let qy = rdsmath::absf(p.y - center.y) - (self.rect.height * 0.5 - r);
+        let ox = qx.max(0.0);
+        let oy = qy.max(0.0);
+        rdsmath::sqrtf(ox * ox + oy * oy) + qx.max(qy).min(0.0) - r
+    }
+
+    /// Classifica `p` em relação a uma moldura de espessura `border`.
+    ///
+    /// A faixa de borda acompanha os cantos arredondados: um ponto no canto
+    /// do retângulo base, mas fora do arco, é `Outside`.
+    #[inline]
+    pub fn hit_region(&self, p: PointF, border: f32) -> HitRegion {
+        let d = self.signed_distance(p);
+        if d > 0.0 {
+            HitRegion::Outside
+        } else if d > -border {
+            HitRegion::Border
+        } else {
+            HitRegion::Interior
+        }
+    }
+
+    /// Cobertura anti-aliased (0.0-1.0) de um pixel centrado em `p`.
+    #[inline]
+    pub fn coverage(&self, p: PointF) -> f32 {
+        (0.5 - self.signed_distance(p)).clamp(0.0, 1.0)
+    }
+
+    /// Tessela o retângulo arredondado em um polígono fechado.
+    ///
+    /// Cada canto é aproximado por `segments_per_corner` segmentos de reta,
+    /// limitado para caber em `MAX_STATIC_POINTS`. Sem raio, retorna apenas
+    /// os quatro cantos.
+    pub fn to_polygon(&self, segments_per_corner: u8) -> StaticPolygon {
+        const MAX_SEGMENTS: usize = MAX_STATIC_POINTS / 4 - 1;
+
+        let r = self.clamped_radius();
+        let left = self.rect.x;
+        let top = self.rect.y;
+        let right = self.rect.right();
+        let bottom = self.rect.bottom();
+
+        if r <= 0.0 {
+            return StaticPolygon::quad(
+                PointF::new(left, top),
+                PointF::new(right, top),
+                PointF::new(right, bottom),
+                PointF::new(left, bottom),
+            );
+        }
+
+        let segments = (segments_per_corner as usize).clamp(1, MAX_SEGMENTS);
+        let step = core::f32::consts::FRAC_PI_2 / segments as f32;
+
+        // Centros dos arcos em sentido horário, começando pelo canto superior direito
+        let corners = [
+            PointF::new(right - r, top + r),
+            PointF::new(right - r, bottom - r),
+            PointF::new(left + r, bottom - r),
+            PointF::new(left + r, top + r),
+        ];
+
+        let mut polygon = StaticPolygon::new();
+        for (i, center) in corners.iter().enumerate() {
+            let start = -core::f32::consts::FRAC_PI_2 + i as f32 * core::f32::consts::FRAC_PI_2;
+            for j in 0..=segments {
+                let angle = start + j as f32 * step;
+                polygon.push(PointF::new(
+                    center.x + r * rdsmath::cosf(angle),
+                    center.y + r * rdsmath::sinf(angle),
+                ));
+            }
+        }
+        polygon
+    }
 }
@@ -0,0 +1,146 @@
+//! # Rect Lists
+//!
+//! Consultas sobre listas de retângulos.
+
+use super::{Point, Rect, RectF, Size};
+
+/// Índice do retângulo cujo centro está mais próximo do ponto.
+pub fn nearest_by_center(rects: &[Rect], to: Point) -> Option<usize> {
+    let target = Rect::new(to.x, to.y, 0, 0);
+    rects
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, r)| r.center_distance_sq(&target))
+        .map(|(i, _)| i)
+}
+
+/// Índice do retângulo cuja borda está mais próxima do ponto.
+///
+/// Retângulos que contêm o ponto têm distância zero.
+pub fn nearest_by_edge(rects: &[Rect], to: Point) -> Option<usize> {
+    rects
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, r)| r.edge_distance_sq(to))
+        .map(|(i, _)| i)
+}
+
+/// Bounding box de todos os retângulos (`None` se a lista for vazia).
+pub fn bounding_box(rects: &[Rect]) -> Option<Rect> {
+    let (first, rest) = rects.split_first()?;
+    Some(rest.iter().fold(*first, |acc, r| acc.union(r)))
+}
+
+/// Soma das áreas (sobreposições contam mais de uma vez).
+pub fn total_area(rects: &[Rect]) -> u64 {
+    rects.iter().map(|r| r.area()).sum()
+}
+
+/// Arredonda um layout em ponto flutuante para pixels sem criar frestas.
+///
+/// Cada borda é arredondada isoladamente (meio pixel para cima), então
+/// retângulos que compartilham uma borda continuam adjacentes: sem gaps nem
+/// sobreposições. Escreve em `out` e retorna quantos retângulos couberam.
+pub fn snap_layout(rects: &[RectF], out: &mut [Rect]) -> usize {
+    let snap = |v: f32| rdsmath::floorf(v + 0.5) as i32;
+    let count = rects.len().min(out.len());
+    for (src, dst) in rects.iter().zip(out.iter_mut()) {
+        let left = snap(src.x);
+        let top = snap(src.y);
+        let right = snap(src.right());
+        let bottom = snap(src.bottom());
+        *dst = Rect::new(
+            left,
+            top,
+            (right as i64 - left as i64).max(0) as u32,
+            (bottom as i64 - top as i64).max(0) as u32,
+        );
+    }
+    count
+}
+
+/// Área real coberta pela união dos retângulos (sem dupla contagem).
+///
+/// Varre faixas verticais entre bordas X consecutivas e soma a extensão Y
+/// coberta em cada faixa. Não aloca; custo O(n³), adequado para listas
+/// pequenas como as de damage.
+pub fn covered_area(rects: &[Rect]) -> u64 {
+    let mut area = 0u64;
+    let mut x0 = match min_edge_after(rects, i64::MIN, |r| [r.left(), r.right()], |_| true) {
+        Some(x) => x,
+        None => return 0,
+    };
+
+    while let Some(x1) = min_edge_after(rects, x0, |r| [r.left(), r.right()], |_| true) {
+        let spans = |r: &Rect| (r.left() as i64) <= x0 && (r.right() as i64) >= x1;
+        let mut covered = 0u64;
+        let mut y0 = min_edge_after(rects, i64::MIN, |r| [r.top(), r.bottom()], spans);
+        while let Some(start) = y0 {
+            let next = min_edge_after(rects, start, |r| [r.top(), r.bottom()], spans);
+            if let Some(end) = next {
+                let is_covered = rects.iter().any(|r| {
+                    !r.is_empty()
+                        && spans(r)
+                        && (r.top() as i64) <= start
+                        && (r.bottom() as i64) >= end
+                });
+                if is_covered {
+                    covered += (end - start) as u64;
+                }
+            }
+            y0 = next;
+        }
+        area += covered * (x1 - x0) as u64;
+        x0 = x1;
+    }
+    area
+}
+
+/// Layout em fluxo: itens da esquerda para a direita, quebrando linha
+/// quando o próximo não cabe em `container_width`.
+///
+/// Itens são separados por `gap` nas duas direções e cada linha tem a
+/// altura do seu item mais alto; a origem é `(0, 0)`. Um item mais largo que
+/// o container ocupa sozinho uma linha. Retorna quantos itens foram
+/// posicionados em `out` (no máximo `out.len()`); para no primeiro item
+/// cuja borda direita ou inferior passaria de `i32::MAX`.
+pub fn flow_layout(items: &[Size], container_width: u32, gap: u32, out: &mut [Rect]) -> usize {
+    // Em u64 as somas nunca saturam (cada termo cabe em u32)
+    let limit = i32::MAX as u64;
+    let (mut x, mut y) = (0u64, 0u64);
+    let mut row_height = 0u64;
+    let count = items.len().min(out.len());
+
+    for (i, (item, slot)) in items.iter().zip(out.iter_mut()).enumerate() {
+        let (width, height) = (item.width as u64, item.height as u64);
+        if x > 0 && x + width > container_width as u64 {
+            x = 0;
+            y += row_height + gap as u64;
+            row_height = 0;
+        }
+        if x + width > limit || y + height > limit {
+            return i;
+        }
+        *slot = Rect::new(x as i32, y as i32, item.width, item.height);
+        x += width + gap as u64;
+        row_height = row_height.max(height);
+    }
+    count
+}
+
+/// Menor borda estritamente maior que `after` entre os retângulos não
+/// vazios que satisfazem `filter`.
+fn min_edge_after(
+    rects: &[Rect],
+    after: i64,
+    edges: impl Fn(&Rect) -> [i32; 2],
+    filter: impl Fn(&Rect) -> bool,
+) -> Option<i64> {
+    rects
+        .iter()
+        .filter(|r| !r.is_empty() && filter(r))
+        .flat_map(edges)
+        .map(|e| e as i64)
+        .filter(|&e| e > after)
+        .min()
+}
@@ -9,6 +9,9 @@ use core::ops::{Add, Mul, Sub};
 // =============================================================================
 
 /// Tamanho 2D (largura x altura).
+///
+/// Os operadores `+`, `-` e `* u32` saturam por dimensão em vez de estourar;
+/// use `checked_add`/`checked_mul` para detectar o estouro.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Size {
@@ -128,16 +131,52 @@ impl Size {
     pub const fn to_tuple(&self) -> (u32, u32) {
         (self.width, self.height)
     }
+
+    /// Soma com saturação em `u32::MAX` por dimensão.
+    #[inline]
+    pub const fn saturating_add(&self, rhs: Size) -> Size {
+        Size {
+            width: self.width.saturating_add(rhs.width),
+            height: self.height.saturating_add(rhs.height),
+        }
+    }
+
+    /// Multiplica por escalar com saturação em `u32::MAX` por dimensão.
+    #[inline]
+    pub const fn saturating_mul(&self, rhs: u32) -> Size {
+        Size {
+            width: self.width.saturating_mul(rhs),
+            height: self.height.saturating_mul(rhs),
+        }
+    }
+
+    /// Soma, ou `None` se alguma dimensão estourar.
+    #[inline]
+    pub const fn checked_add(&self, rhs: Size) -> Option<Size> {
+        match (
+            self.width.checked_add(rhs.width),
+            self.height.checked_add(rhs.height),
+        ) {
+            (Some(width), Some(height)) => Some(Size { width, height }),
+            _ => None,
+        }
+    }
+
+    /// Multiplica por escalar, ou `None` se alguma dimensão estourar.
+    #[inline]
+    pub const fn checked_mul(&self, rhs: u32) -> Option<Size> {
+        match (self.width.checked_mul(rhs), self.height.checked_mul(rhs)) {
+            (Some(width), Some(height)) => Some(Size { width, height }),
+            _ => None,
+        }
+    }
 }
 
 impl Add for Size {
     type Output = Self;
     #[inline]
     fn add(self, rhs: Self) -> Self {
-        Self {
-            width: self.width + rhs.width,
-            height: self.height + rhs.height,
-        }
+        self.saturating_add(rhs)
     }
 }
 
@@ -156,10 +195,7 @@ impl Mul<u32> for Size {
     type Output = Self;
     #[inline]
     fn mul(self, rhs: u32) -> Self {
-        Self {
-            width: self.width * rhs,
-            height: self.height * rhs,
-        }
+        self.saturating_mul(rhs)
     }
 }
 
@@ -144,6 +144,114 @@ impl Transform2D {
         self.b == 0.0 && self.c == 0.0
     }
 
+    /// Verifica se a transformação espelha (determinante negativo).
+    ///
+    /// Após um flip a ordem de winding dos polígonos é invertida.
+    #[inline]
+    pub fn is_flipped(&self) -> bool {
+        self.determinant() < 0.0
+    }
+
+    /// Verifica se contém skew (eixos transformados não ortogonais).
+    #[inline]
+    pub fn has_skew(&self) -> bool {
+        // Eixo X vira (a, b), eixo Y vira (c, d)
+        let dot = self.a * self.c + self.b * self.d;
+        rdsmath::absf(dot) > 1e-6
+    }
+
+    /// Retorna a escala se for apenas escala uniforme + translação.
+    #[inline]
+    pub fn uniform_scale(&self) -> Option<f32> {
+        if self.is_scale_translation() && self.a == self.d {
+            Some(self.a)
+        } else {
+            None
+        }
+    }
+
+    /// Decompõe em translação, rotação, escala e skew.
+    ///
+    /// A parte linear é fatorada como `R(rotation) * [[1, skew], [0, 1]] *
+    /// diag(scale_x, scale_y)`. Retorna `None` se não for inversível.
+    pub fn decompose(&self) -> Option<DecomposedTransform> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        // Primeira coluna (a, b) define rotação e escala X
+        let scale_x = rdsmath::sqrtf(self.a * self.a + self.b * self.b);
+        let rotation = rdsmath::atan2f(self.b, self.a);
+        let cos = self.a / scale_x;
+        let sin = self.b / scale_x;
+        // Segunda coluna rotacionada de volta: (shear * scale_y, scale_y)
+        let shear = cos * self.c + sin * self.d;
+        let scale_y = det / scale_x;
+
+        Some(DecomposedTransform {
+            translate_x: self.tx,
+            translate_y: self.ty,
+            rotation,
+            scale_x,
+            scale_y,
+            skew: shear / scale_y,
+        })
+    }
+
+    /// Recompõe a partir dos componentes de `decompose`.
+    pub fn from_decomposed(parts: &DecomposedTransform) -> Self {
+        let cos = rdsmath::cosf(parts.rotation);
+        let sin = rdsmath::sinf(parts.rotation);
+        let shear = parts.skew * parts.scale_y;
+        Self {
+            a: cos * parts.scale_x,
+            b: sin * parts.scale_x,
+            c: cos * shear - sin * parts.scale_y,
+            d: sin * shear + cos * parts.scale_y,
+            tx: parts.translate_x,
+            ty: parts.translate_y,
+        }
+    }
+
+    /// Interpola entre duas transformações.
+    ///
+    /// Interpola os componentes decompostos, seguindo o menor arco de
+    /// rotação. Se alguma das matrizes não for inversível, interpola
+    /// componente a componente.
+    pub fn lerp(&self, other: &Transform2D, t: f32) -> Self {
+        let mix = |from: f32, to: f32| from + (to - from) * t;
+        let (from, to) = match (self.decompose(), other.decompose()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => {
+                return Self {
+                    a: mix(self.a, other.a),
+                    b: mix(self.b, other.b),
+                    c: mix(self.c, other.c),
+                    d: mix(self.d, other.d),
+                    tx: mix(self.tx, other.tx),
+                    ty: mix(self.ty, other.ty),
+                }
+            }
+        };
+
+        // Menor arco entre os ângulos
+        let mut delta = to.rotation - from.rotation;
+        if delta > core::f32::consts::PI {
+            delta -= core::f32::consts::TAU;
+        } else if delta < -core::f32::consts::PI {
+            delta += core::f32::consts::TAU;
+        }
+
+        Self::from_decomposed(&DecomposedTransform {
+            translate_x: mix(from.translate_x, to.translate_x),
+            translate_y: mix(from.translate_y, to.translate_y),
+            rotation: from.rotation + delta * t,
+            scale_x: mix(from.scale_x, to.scale_x),
+            scale_y: mix(from.scale_y, to.scale_y),
+            skew: mix(from.skew, to.skew),
+        })
+    }
+
     /// Concatena com outra transformação (this * other).
     #[inline]
     pub fn then(&self, other: &Transform2D) -> Self {
@@ -247,10 +355,7 @@ impl Transform2D {
             }
         } else {
             // Caso geral: transformar os 4 cantos e calcular bounding box
-            let p1 = self.transform_point(PointF::new(r.x, r.y));
-            let p2 = self.transform_point(PointF::new(r.right(), r.y));
-            let p3 = self.transform_point(PointF::new(r.x, r.bottom()));
-            let p4 = self.transform_point(PointF::new(r.right(), r.bottom()));
+            let [p1, p2, p3, p4] = self.transform_quad(r);
 
             let min_x = p1.x.min(p2.x).min(p3.x).min(p4.x);
             let max_x = p1.x.max(p2.x).max(p3.x).max(p4.x);
@@ -261,6 +366,21 @@ impl Transform2D {
         }
     }
 
+    /// Transforma os quatro cantos de um retângulo.
+    ///
+    /// Ordem preservada: superior esquerdo, superior direito, inferior
+    /// direito e inferior esquerdo. Diferente de `transform_rect`, mantém o
+    /// quad real (útil para desenhar superfícies rotacionadas).
+    #[inline]
+    pub fn transform_quad(&self, r: RectF) -> [PointF; 4] {
+        [
+            self.transform_point(PointF::new(r.x, r.y)),
+            self.transform_point(PointF::new(r.right(), r.y)),
+            self.transform_point(PointF::new(r.right(), r.bottom())),
+            self.transform_point(PointF::new(r.x, r.bottom())),
+        ]
+    }
+
     /// Transforma um retângulo inteiro.
     #[inline]
     pub fn transform_rect_i(&self, r: Rect) -> Rect {
@@ -268,6 +388,28 @@ impl Transform2D {
     }
 }
 
+// =============================================================================
+// DECOMPOSED TRANSFORM
+// =============================================================================
+
+/// Componentes de uma transformação afim (ver `Transform2D::decompose`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DecomposedTransform {
+    /// Translação X.
+    pub translate_x: f32,
+    /// Translação Y.
+    pub translate_y: f32,
+    /// Rotação em radianos.
+    pub rotation: f32,
+    /// Escala X.
+    pub scale_x: f32,
+    /// Escala Y (negativa se houver espelhamento).
+    pub scale_y: f32,
+    /// Fator de skew horizontal.
+    pub skew: f32,
+}
+
 impl core::ops::Mul for Transform2D {
     type Output = Self;
     #[inline]
@@ -2,7 +2,9 @@
 //!
 //! Tipos de cursor do sistema.
 
-use crate::geometry::Point;
+use crate::buffer::{BufferDescriptor, BufferHandle};
+use crate::geometry::{Point, Rect};
+use crate::window::ResizeEdge;
 
 // =============================================================================
 // CURSOR TYPE
@@ -151,6 +153,40 @@ impl CursorType {
         }
     }
 
+    /// Cursor de redimensionamento mais próximo de uma direção de arraste.
+    ///
+    /// Usa coordenadas de tela (Y para baixo): 0 rad aponta para leste e
+    /// PI/2 para sul. O ângulo é arredondado para o octante mais próximo.
+    pub fn for_resize_angle(angle_radians: f32) -> CursorType {
+        const OCTANTS: [CursorType; 8] = [
+            CursorType::ResizeE,
+            CursorType::ResizeSE,
+            CursorType::ResizeS,
+            CursorType::ResizeSW,
+            CursorType::ResizeW,
+            CursorType::ResizeNW,
+            CursorType::ResizeN,
+            CursorType::ResizeNE,
+        ];
+        let octant = rdsmath::roundf(angle_radians / core::f32::consts::FRAC_PI_4) as i32;
+        OCTANTS[octant.rem_euclid(8) as usize]
+    }
+
+    /// Cursor de redimensionamento para uma borda de janela.
+    #[inline]
+    pub const fn for_edge(edge: ResizeEdge) -> CursorType {
+        match edge {
+            ResizeEdge::Top => Self::ResizeN,
+            ResizeEdge::Bottom => Self::ResizeS,
+            ResizeEdge::Left => Self::ResizeW,
+            ResizeEdge::Right => Self::ResizeE,
+            ResizeEdge::TopLeft => Self::ResizeNW,
+            ResizeEdge::TopRight => Self::ResizeNE,
+            ResizeEdge::BottomLeft => Self::ResizeSW,
+            ResizeEdge::BottomRight => Self::ResizeSE,
+        }
+    }
+
     /// Verifica se é um cursor de redimensionamento.
     #[inline]
     pub const fn is_resize(&self) -> bool {
@@ -216,3 +252,146 @@ impl From<CursorHotspot> for Point {
         h.to_point()
     }
 }
+
+// =============================================================================
+// CURSOR IMAGE
+// =============================================================================
+
+/// Imagem de cursor customizado (buffer + hotspot).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CursorImage {
+    /// Descritor do buffer da imagem.
+    pub descriptor: BufferDescriptor,
+    /// Hotspot relativo ao canto superior esquerdo da imagem.
+    pub hotspot: CursorHotspot,
+    /// Handle do buffer com os pixels.
+    pub handle: BufferHandle,
+}
+
+impl CursorImage {
+    /// Cria nova imagem de cursor.
+    #[inline]
+    pub const fn new(
+        descriptor: BufferDescriptor,
+        hotspot: CursorHotspot,
+        handle: BufferHandle,
+    ) -> Self {
+        Self {
+            descriptor,
+            hotspot,
+            handle,
+        }
+    }
+
+    /// Verifica se o hotspot está dentro da imagem.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.hotspot.x >= 0
+            && self.hotspot.y >= 0
+            && (self.hotspot.x as u32) < self.descriptor.width
+            && (self.hotspot.y as u32) < self.descriptor.height
+    }
+
+    /// Retângulo da imagem relativo ao ponto lógico do cursor (origem).
+    #[inline]
+    pub const fn bounds(&self) -> Rect {
+        Rect::new(
+            -self.hotspot.x,
+            -self.hotspot.y,
+            self.descriptor.width,
+            self.descriptor.height,
+        )
+    }
+
+    /// Retângulo da imagem com o cursor na posição dada.
+    #[inline]
+    pub const fn bounds_at(&self, position: Point) -> Rect {
+        self.bounds().offset(position.x, position.y)
+    }
+}
+
+// =============================================================================
+// ANIMATED CURSOR
+// =============================================================================
+
+/// Número máximo de frames em um AnimatedCursor.
+pub const MAX_CURSOR_FRAMES: usize = 16;
+
+/// Cursor animado: sequência cíclica de imagens com duração (sem alocação).
+///
+/// Sempre tem pelo menos um frame, então há sempre uma imagem para exibir.
+#[derive(Clone, Copy, Debug)]
+pub struct AnimatedCursor {
+    /// Frames e suas durações em milissegundos.
+    frames: [(CursorImage, u32); MAX_CURSOR_FRAMES],
+    /// Número de frames válidos.
+    count: usize,
+}
+
+impl AnimatedCursor {
+    /// Cria cursor com o primeiro frame.
+    #[inline]
+    pub const fn new(first: CursorImage, duration_ms: u32) -> Self {
+        Self {
+            frames: [(first, duration_ms); MAX_CURSOR_FRAMES],
+            count: 1,
+        }
+    }
+
+    /// Número de frames.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Sempre `false`: há pelo menos um frame.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Adiciona um frame ao final. Retorna `false` se cheio.
+    pub fn push(&mut self, image: CursorImage, duration_ms: u32) -> bool {
+        if self.count >= MAX_CURSOR_FRAMES {
+            return false;
+        }
+        self.frames[self.count] = (image, duration_ms);
+        self.count += 1;
+        true
+    }
+
+    /// Frames e durações como slice.
+    #[inline]
+    pub fn frames(&self) -> &[(CursorImage, u32)] {
+        &self.frames[..self.count]
+    }
+
+    /// Duração de um ciclo completo em milissegundos.
+    #[inline]
+    pub fn total_duration(&self) -> u64 {
+        self.frames().iter().map(|&(_, d)| d as u64).sum()
+    }
+
+    /// Frame visível após `elapsed_ms` e quanto tempo ele ainda fica.
+    ///
+    /// A animação repete a cada `total_duration()`. Frames de duração zero
+    /// nunca são exibidos; se todas forem zero, o cursor é estático no
+    /// primeiro frame (restante 0).
+    pub fn frame_at(&self, elapsed_ms: u64) -> (&CursorImage, u32) {
+        let total = self.total_duration();
+        if total == 0 {
+            return (&self.frames[0].0, 0);
+        }
+        let mut t = elapsed_ms % total;
+        for (image, duration) in self.frames() {
+            let duration = *duration as u64;
+            if t < duration {
+                return (image, (duration - t) as u32);
+            }
+            t -= duration;
+        }
+        // Inalcançável: t < total
+        (&self.frames[0].0, 0)
+    }
+}
@@ -0,0 +1,444 @@
+//! # Gesture Recognizer
+//!
+//! Máquina de estados que converte toques em gestos (sem alocação).
+
+use core::f32::consts::PI;
+
+use super::{GestureType, SwipeDirection, TouchId, TouchPhase, TouchPoint};
+use crate::geometry::PointF;
+
+/// Número máximo de toques simultâneos rastreados.
+pub const MAX_TOUCH_SLOTS: usize = 10;
+
+// =============================================================================
+// CONFIG
+// =============================================================================
+
+/// Limiares usados pelo reconhecedor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GestureConfig {
+    /// Deslocamento máximo (px) para um toque ainda contar como tap.
+    pub tap_slop: f32,
+    /// Tempo (µs) parado até virar long-press.
+    pub long_press_us: u64,
+    /// Intervalo máximo (µs) entre dois taps para um double-tap.
+    pub double_tap_us: u64,
+    /// Velocidade mínima (px/s) para um pan terminar em swipe.
+    pub swipe_min_velocity: f32,
+    /// Variação relativa de distância para iniciar um pinch.
+    pub pinch_threshold: f32,
+    /// Variação de ângulo (rad) para iniciar um rotate.
+    pub rotate_threshold: f32,
+}
+
+impl GestureConfig {
+    /// Valores padrão.
+    pub const DEFAULT: Self = Self {
+        tap_slop: 10.0,
+        long_press_us: 500_000,
+        double_tap_us: 300_000,
+        swipe_min_velocity: 500.0,
+        pinch_threshold: 0.05,
+        rotate_threshold: 0.15,
+    };
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// =============================================================================
+// GESTURE EVENT
+// =============================================================================
+
+/// Gesto reconhecido com seus parâmetros.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GestureEvent {
+    /// Toque simples.
+    Tap {
+        /// Posição do toque.
+        position: PointF,
+    },
+    /// Dois taps próximos no tempo e no espaço.
+    DoubleTap {
+        /// Posição do segundo toque.
+        position: PointF,
+    },
+    /// Toque mantido parado.
+    LongPress {
+        /// Posição do toque.
+        position: PointF,
+    },
+    /// Pan rápido ao levantar o dedo.
+    Swipe {
+        /// Direção dominante.
+        direction: SwipeDirection,
+        /// Velocidade média (px/s).
+        velocity: f32,
+    },
+    /// Arrasto com um dedo.
+    Pan {
+        /// Deslocamento desde o evento anterior.
+        delta: PointF,
+    },
+    /// Zoom com dois dedos.
+    Pinch {
+        /// Escala relativa ao início do gesto.
+        scale: f32,
+    },
+    /// Rotação com dois dedos.
+    Rotate {
+        /// Ângulo (rad) relativo ao início do gesto.
+        angle: f32,
+    },
+}
+
+impl GestureEvent {
+    /// Tipo do gesto.
+    #[inline]
+    pub const fn gesture_type(&self) -> GestureType {
+        match self {
+            Self::Tap { .. } => GestureType::Tap,
+            Self::DoubleTap { .. } => GestureType::DoubleTap,
+            Self::LongPress { .. } => GestureType::LongPress,
+            Self::Swipe { .. } => GestureType::Swipe,
+            Self::Pan { .. } => GestureType::Pan,
+            Self::Pinch { .. } => GestureType::Pinch,
+            Self::Rotate { .. } => GestureType::Rotate,
+        }
+    }
+}
+
+// =============================================================================
+// RECOGNIZER
+// =============================================================================
+
+/// Histórico de um toque ativo.
+#[derive(Clone, Copy, Debug)]
+struct TouchSlot {
+    id: TouchId,
+    start: PointF,
+    start_time: u64,
+    previous: PointF,
+    position: PointF,
+}
+
+impl TouchSlot {
+    const EMPTY: Self = Self {
+        id: TouchId::INVALID,
+        start: PointF::new(0.0, 0.0),
+        start_time: 0,
+        previous: PointF::new(0.0, 0.0),
+        position: PointF::new(0.0, 0.0),
+    };
+}
+
+/// Estado da sequência de toques atual.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    /// Nenhum toque.
+    Idle,
+    /// Um dedo, ainda dentro do slop (tap ou long-press).
+    Pending,
+    /// Long-press já emitido; aguarda soltar.
+    LongPressed,
+    /// Um dedo arrastando.
+    Panning,
+    /// Dois ou mais dedos.
+    Multi {
+        start_distance: f32,
+        start_angle: f32,
+        locked: Option<GestureType>,
+    },
+    /// Sequência consumida; ignora até soltar todos os dedos.
+    Consumed,
+}
+
+/// Reconhecedor de gestos multi-toque.
+///
+/// Alimente com [`update`](Self::update) a cada `TouchPoint` e chame
+/// [`tick`](Self::tick) periodicamente para detectar long-press sem novos
+/// eventos. Tempos em microssegundos, de um relógio monotônico.
+#[derive(Clone, Copy, Debug)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    slots: [TouchSlot; MAX_TOUCH_SLOTS],
+    count: usize,
+    state: State,
+    /// Posição e instante do último tap (para double-tap).
+    last_tap: Option<(PointF, u64)>,
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GestureRecognizer {
+    /// Cria reconhecedor com a configuração padrão.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_config(GestureConfig::DEFAULT)
+    }
+
+    /// Cria reconhecedor com limiares próprios.
+    #[inline]
+    pub const fn with_config(config: GestureConfig) -> Self {
+        Self {
+            config,
+            slots: [TouchSlot::EMPTY; MAX_TOUCH_SLOTS],
+            count: 0,
+            state: State::Idle,
+            last_tap: None,
+        }
+    }
+
+    /// Configuração atual.
+    #[inline]
+    pub const fn config(&self) -> &GestureConfig {
+        &self.config
+    }
+
+    /// Número de toques ativos.
+    #[inline]
+    pub const fn active_touches(&self) -> usize {
+        self.count
+    }
+
+    /// Descarta todos os toques e o histórico de taps.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.state = State::Idle;
+        self.last_tap = None;
+    }
+
+    /// Processa um ponto de toque no instante `time_us`.
+    pub fn update(&mut self, point: &TouchPoint, time_us: u64) -> Option<GestureEvent> {
+        match point.phase {
+            TouchPhase::Begin => self.begin(point, time_us),
+            TouchPhase::Move => self.move_touch(point, time_us),
+            TouchPhase::End => self.end(point.id, point.position, time_us),
+            TouchPhase::Cancel => {
+                self.remove(point.id);
+                self.state = if self.count == 0 {
+                    State::Idle
+                } else {
+                    State::Consumed
+                };
+                None
+            }
+        }
+    }
+
+    /// Avança o relógio sem novos toques (detecta long-press).
+    pub fn tick(&mut self, time_us: u64) -> Option<GestureEvent> {
+        if self.state != State::Pending {
+            return None;
+        }
+        let slot = self.slots[0];
+        if time_us.saturating_sub(slot.start_time) >= self.config.long_press_us {
+            self.state = State::LongPressed;
+            self.last_tap = None;
+            return Some(GestureEvent::LongPress {
+                position: slot.position,
+            });
+        }
+        None
+    }
+
+    fn begin(&mut self, point: &TouchPoint, time_us: u64) -> Option<GestureEvent> {
+        if self.count >= MAX_TOUCH_SLOTS || self.find(point.id).is_some() {
+            return None;
+        }
+        self.slots[self.count] = TouchSlot {
+            id: point.id,
+            start: point.position,
+            start_time: time_us,
+            previous: point.position,
+            position: point.position,
+        };
+        self.count += 1;
+
+        self.state = match self.state {
+            State::Idle => State::Pending,
+            State::Consumed | State::LongPressed => State::Consumed,
+            State::Multi { .. } => self.state,
+            _ if self.count >= GestureType::Pinch.min_touches() => {
+                let (start_distance, start_angle) = self.pair_geometry();
+                State::Multi {
+                    start_distance,
+                    start_angle,
+                    locked: None,
+                }
+            }
+            state => state,
+        };
+        None
+    }
+
+    fn move_touch(&mut self, point: &TouchPoint, time_us: u64) -> Option<GestureEvent> {
+        let index = self.find(point.id)?;
+        let slot = &mut self.slots[index];
+        slot.previous = slot.position;
+        slot.position = point.position;
+        let slot = *slot;
+
+        if let Some(event) = self.tick(time_us) {
+            return Some(event);
+        }
+
+        match self.state {
+            State::Pending => {
+                if slot.position.distance(&slot.start) <= self.config.tap_slop {
+                    return None;
+                }
+                self.state = State::Panning;
+                Some(GestureEvent::Pan {
+                    delta: slot.position - slot.start,
+                })
+            }
+            State::Panning => Some(GestureEvent::Pan {
+                delta: slot.position - slot.previous,
+            }),
+            State::Multi {
+                start_distance,
+                start_angle,
+                locked,
+            } => {
+                if index >= 2 {
+                    return None;
+                }
+                let (distance, angle) = self.pair_geometry();
+                let scale = if start_distance > 0.0 {
+                    distance / start_distance
+                } else {
+                    1.0
+                };
+                let rotation = normalize_angle(angle - start_angle);
+
+                let locked = locked.or({
+                    if (scale - 1.0).abs() >= self.config.pinch_threshold {
+                        Some(GestureType::Pinch)
+                    } else if rotation.abs() >= self.config.rotate_threshold {
+                        Some(GestureType::Rotate)
+                    } else {
+                        None
+                    }
+                });
+                self.state = State::Multi {
+                    start_distance,
+                    start_angle,
+                    locked,
+                };
+                match locked? {
+                    GestureType::Pinch => Some(GestureEvent::Pinch { scale }),
+                    _ => Some(GestureEvent::Rotate { angle: rotation }),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn end(&mut self, id: TouchId, position: PointF, time_us: u64) -> Option<GestureEvent> {
+        let index = self.find(id)?;
+        let slot = self.slots[index];
+        self.remove(id);
+
+        let event = match self.state {
+            State::Pending => {
+                let held = time_us.saturating_sub(slot.start_time);
+                if held >= self.config.long_press_us {
+                    Some(GestureEvent::LongPress { position })
+                } else {
+                    Some(self.tap(position, time_us))
+                }
+            }
+            State::Panning => self.swipe(&slot, position, time_us),
+            _ => None,
+        };
+
+        self.state = if self.count == 0 {
+            State::Idle
+        } else {
+            State::Consumed
+        };
+        event
+    }
+
+    /// Tap ou double-tap, conforme o tap anterior.
+    fn tap(&mut self, position: PointF, time_us: u64) -> GestureEvent {
+        let is_double = self.last_tap.is_some_and(|(last, at)| {
+            time_us.saturating_sub(at) <= self.config.double_tap_us
+                && last.distance(&position) <= self.config.tap_slop * 2.0
+        });
+        if is_double {
+            self.last_tap = None;
+            GestureEvent::DoubleTap { position }
+        } else {
+            self.last_tap = Some((position, time_us));
+            GestureEvent::Tap { position }
+        }
+    }
+
+    /// Swipe se o pan foi rápido o suficiente.
+    fn swipe(&self, slot: &TouchSlot, end: PointF, time_us: u64) -> Option<GestureEvent> {
+        let elapsed = time_us.saturating_sub(slot.start_time).max(1) as f32 / 1_000_000.0;
+        let delta = end - slot.start;
+        let velocity = end.distance(&slot.start) / elapsed;
+        if velocity < self.config.swipe_min_velocity {
+            return None;
+        }
+        // Eixo Y da tela aponta para baixo
+        let direction = if delta.x.abs() >= delta.y.abs() {
+            if delta.x >= 0.0 {
+                SwipeDirection::Right
+            } else {
+                SwipeDirection::Left
+            }
+        } else if delta.y >= 0.0 {
+            SwipeDirection::Down
+        } else {
+            SwipeDirection::Up
+        };
+        Some(GestureEvent::Swipe {
+            direction,
+            velocity,
+        })
+    }
+
+    /// Distância e ângulo entre os dois primeiros toques.
+    fn pair_geometry(&self) -> (f32, f32) {
+        let a = self.slots[0].position;
+        let b = self.slots[1].position;
+        let d = b - a;
+        (a.distance(&b), rdsmath::atan2f(d.y, d.x))
+    }
+
+    fn find(&self, id: TouchId) -> Option<usize> {
+        self.slots[..self.count].iter().position(|s| s.id == id)
+    }
+
+    /// Remove um toque preservando a ordem dos demais.
+    fn remove(&mut self, id: TouchId) {
+        if let Some(index) = self.find(id) {
+            self.slots.copy_within(index + 1..self.count, index);
+            self.count -= 1;
+        }
+    }
+}
+
+/// Normaliza um ângulo para `[-PI, PI]`.
+#[inline]
+fn normalize_angle(angle: f32) -> f32 {
+    if angle > PI {
+        angle - 2.0 * PI
+    } else if angle < -PI {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
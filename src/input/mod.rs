@@ -3,7 +3,11 @@
 //! Tipos de cursor e input gráfico.
 
 mod cursor;
+mod gesture;
+mod pointer;
 mod touch;
 
-pub use cursor::{CursorHotspot, CursorType};
+pub use cursor::{AnimatedCursor, CursorHotspot, CursorImage, CursorType, MAX_CURSOR_FRAMES};
+pub use gesture::{GestureConfig, GestureEvent, GestureRecognizer, MAX_TOUCH_SLOTS};
+pub use pointer::map_pointer;
 pub use touch::{GestureType, SwipeDirection, TouchId, TouchPhase, TouchPoint};
@@ -0,0 +1,24 @@
+//! # Pointer Mapping
+//!
+//! Mapeamento de posições absolutas do ponteiro entre áreas.
+
+use crate::geometry::{PointF, Rect};
+
+/// Mapeia linearmente `abs` de `from` (ex: superfície do touchpad) para `to`
+/// (ex: área de um monitor).
+///
+/// Pontos fora de `from` são extrapolados; limite com `clamp_to_rect` se
+/// necessário. Um eixo vazio em `from` mapeia para a origem de `to`.
+pub fn map_pointer(abs: PointF, from: Rect, to: Rect) -> PointF {
+    let map = |v: f32, from_start: i32, from_len: u32, to_start: i32, to_len: u32| {
+        if from_len == 0 {
+            to_start as f32
+        } else {
+            to_start as f32 + (v - from_start as f32) * to_len as f32 / from_len as f32
+        }
+    };
+    PointF::new(
+        map(abs.x, from.x, from.width, to.x, to.width),
+        map(abs.y, from.y, from.height, to.y, to.height),
+    )
+}
@@ -2,6 +2,8 @@
 //!
 //! Tipos para entrada por toque.
 
+use core::fmt;
+
 use crate::geometry::PointF;
 
 /// ID único de um toque.
@@ -24,6 +26,22 @@ impl TouchId {
     pub const fn is_valid(&self) -> bool {
         self.0 != u32::MAX
     }
+
+    /// Verifica se é o valor sentinela reservado (`INVALID`).
+    #[inline]
+    pub const fn is_reserved(&self) -> bool {
+        self.0 == Self::INVALID.0
+    }
+}
+
+impl fmt::Display for TouchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_reserved() {
+            f.write_str("Touch(INVALID)")
+        } else {
+            write!(f, "Touch(#{})", self.0)
+        }
+    }
 }
 
 /// Fase de um toque.
@@ -9,6 +9,7 @@
 //!
 //! | Módulo | Descrição |
 //! |--------|-----------|
+//! | [`abi`] | Garantias de layout binário (ABI) |
 //! | [`geometry`] | Primitivas geométricas (Point, Size, Rect, etc.) |
 //! | [`color`] | Sistema de cores e formatos de pixel |
 //! | [`buffer`] | Buffers de pixels e descritores |
@@ -18,6 +19,11 @@
 //! | [`render`] | Comandos e operações de renderização |
 //! | [`input`] | Tipos de cursor e input |
 //! | [`text`] | Tipografia básica |
+//! | [`tile`] | Layouts de tiling para window managers |
+//! | [`stats`] | Estatísticas de frame pacing |
+//! | [`alloc_id`] | Alocação de IDs de superfície e buffer |
+//! | [`anim`] | Curvas de easing para animações |
+//! | [`atlas`] | Empacotamento de retângulos em atlas |
 //!
 //! ## Exemplo
 //!
@@ -41,6 +47,10 @@ extern crate alloc;
 // MODULES
 // =============================================================================
 
+pub mod abi;
+pub mod alloc_id;
+pub mod anim;
+pub mod atlas;
 pub mod buffer;
 pub mod color;
 pub mod damage;
@@ -48,7 +58,9 @@ pub mod display;
 pub mod geometry;
 pub mod input;
 pub mod render;
+pub mod stats;
 pub mod text;
+pub mod tile;
 pub mod window;
 
 // =============================================================================
@@ -59,8 +71,8 @@ pub mod window;
 pub mod prelude {
     // Geometry
     pub use crate::geometry::{
-        Circle, Ellipse, Insets, Line, LineF, Point, PointF, Rect, RectF, RoundedRect, Size, SizeF,
-        Transform2D,
+        ApproxEq, Circle, Ellipse, Insets, Line, LineF, Point, PointF, Rect, RectF, RoundedRect,
+        Size, SizeF, Transform2D,
     };
 
     // Color
@@ -76,7 +88,7 @@ pub mod prelude {
     pub use crate::window::{LayerType, WindowFlags, WindowState, WindowType};
 
     // Damage
-    pub use crate::damage::DamageRegion;
+    pub use crate::damage::{DamageBuffer, DamageRegion};
 
     // Text
     pub use crate::text::{FontStyle, FontWeight, TextAlign};
@@ -105,7 +117,7 @@ pub use display::{DisplayInfo, DisplayMode, VsyncMode};
 pub use window::{LayerType, WindowEffects, WindowFlags, WindowState, WindowType};
 
 // Damage
-pub use damage::DamageRegion;
+pub use damage::{DamageBuffer, DamageRegion};
 
 // Input
 pub use input::{CursorHotspot, CursorType};
@@ -171,4 +171,20 @@ impl BlitParams {
             self.src_rect.height,
         )
     }
+
+    /// Resolve o clipping do blit contra os limites do destino.
+    ///
+    /// Retorna `(src, dst)` com as áreas efetivamente copiadas, ou `None`
+    /// se nada for visível. O trecho cortado do destino (ex: origem
+    /// negativa) é removido também da fonte.
+    pub fn resolve(&self, dst_bounds: Rect) -> Option<(Rect, Rect)> {
+        let dst = self.dst_rect().intersection(&dst_bounds)?;
+        let src = Rect::new(
+            self.src_rect.x + (dst.x - self.dst_x),
+            self.src_rect.y + (dst.y - self.dst_y),
+            dst.width,
+            dst.height,
+        );
+        Some((src, dst))
+    }
 }
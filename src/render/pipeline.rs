@@ -2,7 +2,7 @@
 //!
 //! Tipos para pipeline de renderização.
 
-use crate::color::BlendMode;
+use crate::color::{BlendMode, Color};
 
 /// Operação raster (ROP).
 #[repr(u8)]
@@ -66,6 +66,23 @@ impl RasterOp {
             Self::Nor => "Nor",
         }
     }
+
+    /// Aplica a operação bit a bit às palavras `src` e `dst`.
+    #[inline]
+    pub const fn apply(&self, src: u32, dst: u32) -> u32 {
+        match self {
+            Self::Copy => src,
+            Self::And => src & dst,
+            Self::Or => src | dst,
+            Self::Xor => src ^ dst,
+            Self::NotSrc => !src,
+            Self::NotDst => !dst,
+            Self::Clear => 0,
+            Self::Set => u32::MAX,
+            Self::Nand => !(src & dst),
+            Self::Nor => !(src | dst),
+        }
+    }
 }
 
 /// Estado do pipeline de renderização.
@@ -120,6 +137,29 @@ impl PipelineState {
         self.antialias = aa;
         self
     }
+
+    /// Com operação raster.
+    #[inline]
+    pub const fn with_raster_op(mut self, op: RasterOp) -> Self {
+        self.raster_op = op;
+        self
+    }
+
+    /// Calcula o pixel final de `src` desenhado sobre `dst`.
+    ///
+    /// Ordem: o alpha global escala o alpha de `src`, o modo de blend combina
+    /// com `dst` e, por fim, a operação raster combina o resultado com `dst`
+    /// bit a bit. A ROP atua apenas nos canais RGB; o alpha vem do blend
+    /// (senão `Xor` entre duas cores opacas produziria alpha zero).
+    pub fn apply_pixel(&self, src: Color, dst: Color) -> Color {
+        let alpha = (src.alpha() as u32 * self.global_alpha as u32 + 127) / 255;
+        let blended = self.blend_mode.blend_u8(src.with_alpha(alpha as u8), dst);
+        if self.raster_op == RasterOp::Copy {
+            return blended;
+        }
+        let rgb = self.raster_op.apply(blended.as_u32(), dst.as_u32()) & 0x00FF_FFFF;
+        Color(rgb).with_alpha(blended.alpha())
+    }
 }
 
 /// Qualidade de interpolação para escala.
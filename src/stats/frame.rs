@@ -0,0 +1,142 @@
+//! # Frame Stats
+//!
+//! Acumulador de estatísticas de frame pacing.
+
+use crate::display::DisplayMode;
+
+/// Número de frames mantidos no histórico.
+pub const FRAME_STATS_CAPACITY: usize = 128;
+
+/// Estatísticas dos últimos frames (ring buffer, sem alocação).
+///
+/// Os tempos de frame são armazenados em microssegundos.
+#[derive(Clone, Copy, Debug)]
+pub struct FrameStats {
+    /// Tempos de frame em microssegundos.
+    samples: [u32; FRAME_STATS_CAPACITY],
+    /// Próxima posição de escrita.
+    head: usize,
+    /// Número de amostras válidas.
+    count: usize,
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameStats {
+    /// Cria acumulador vazio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            samples: [0; FRAME_STATS_CAPACITY],
+            head: 0,
+            count: 0,
+        }
+    }
+
+    /// Número de amostras.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazio.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Registra a duração de um frame (a mais antiga é descartada se cheio).
+    #[inline]
+    pub fn push(&mut self, dt_us: u32) {
+        self.samples[self.head] = dt_us;
+        self.head = (self.head + 1) % FRAME_STATS_CAPACITY;
+        if self.count < FRAME_STATS_CAPACITY {
+            self.count += 1;
+        }
+    }
+
+    /// Amostras válidas (ordem de armazenamento, não cronológica).
+    #[inline]
+    fn samples(&self) -> &[u32] {
+        &self.samples[..self.count]
+    }
+
+    /// Tempo médio de frame em microssegundos.
+    pub fn average_frame_time(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let total: u64 = self.samples().iter().map(|&dt| dt as u64).sum();
+        total as f32 / self.count as f32
+    }
+
+    /// FPS médio.
+    pub fn average_fps(&self) -> f32 {
+        let avg = self.average_frame_time();
+        if avg <= 0.0 {
+            0.0
+        } else {
+            1_000_000.0 / avg
+        }
+    }
+
+    /// Tempo de frame no percentil 99 (microssegundos).
+    pub fn p99_frame_time(&self) -> u32 {
+        if self.count == 0 {
+            return 0;
+        }
+        let mut sorted = self.samples;
+        let sorted = &mut sorted[..self.count];
+        sorted.sort_unstable();
+        let index = ((self.count - 1) * 99) / 100;
+        sorted[index]
+    }
+
+    /// Jitter: desvio padrão dos tempos de frame (microssegundos).
+    pub fn jitter(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let avg = self.average_frame_time();
+        let variance = self
+            .samples()
+            .iter()
+            .map(|&dt| {
+                let diff = dt as f32 - avg;
+                diff * diff
+            })
+            .sum::<f32>()
+            / self.count as f32;
+        rdsmath::sqrtf(variance)
+    }
+
+    /// Número de vblanks perdidos para o modo de display alvo.
+    ///
+    /// Um frame que dura N períodos de refresh (arredondado) conta como
+    /// N - 1 frames perdidos.
+    pub fn dropped_frames(&self, target: &DisplayMode) -> u32 {
+        if target.refresh_rate_mhz == 0 {
+            return 0;
+        }
+        // Período em microssegundos (refresh em milihertz)
+        let period = 1_000_000_000u64 / target.refresh_rate_mhz as u64;
+        self.samples()
+            .iter()
+            .map(|&dt| {
+                let periods = (dt as u64 + period / 2) / period;
+                periods.saturating_sub(1) as u32
+            })
+            .sum()
+    }
+
+    /// Limpa o histórico.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.count = 0;
+    }
+}
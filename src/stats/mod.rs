@@ -0,0 +1,7 @@
+//! # Stats Module
+//!
+//! Estatísticas de desempenho do compositor.
+
+mod frame;
+
+pub use frame::{FrameStats, FRAME_STATS_CAPACITY};
@@ -2,6 +2,8 @@
 //!
 //! Tipos para representação de glyphs.
 
+use core::fmt;
+
 /// ID de um glyph em uma fonte.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -22,6 +24,22 @@ impl GlyphId {
     pub const fn is_valid(&self) -> bool {
         self.0 != 0
     }
+
+    /// Verifica se é o valor sentinela reservado (`NOTDEF`).
+    #[inline]
+    pub const fn is_reserved(&self) -> bool {
+        self.0 == Self::NOTDEF.0
+    }
+}
+
+impl fmt::Display for GlyphId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_reserved() {
+            f.write_str("Glyph(NOTDEF)")
+        } else {
+            write!(f, "Glyph(#{})", self.0)
+        }
+    }
 }
 
 impl From<u32> for GlyphId {
@@ -4,6 +4,8 @@
 
 mod font;
 mod glyph;
+mod run;
 
-pub use font::{FontStyle, FontWeight, TextAlign, TextBaseline};
-pub use glyph::{GlyphId, GlyphMetrics};
+pub use font::{FontStyle, FontWeight, TextAlign, TextBaseline, TextDecoration};
+pub use glyph::{ClusterInfo, GlyphId, GlyphMetrics, GlyphPosition};
+pub use run::text_run_bounds;
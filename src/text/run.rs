@@ -0,0 +1,47 @@
+//! # Text Run Bounds
+//!
+//! Caixa de tinta (ink bounds) de uma sequência de glyphs posicionados.
+
+use super::{GlyphMetrics, GlyphPosition};
+use crate::geometry::{PointF, RectF, Transform2D};
+
+/// Bounding box transformada da tinta de um run de texto.
+///
+/// `positions[i]` é a origem na baseline do glyph cujas métricas são
+/// `metrics[i]` (pares além do menor slice são ignorados). A caixa de cada
+/// glyph vai de `(x + bearing_x, y - bearing_y)` com `width` x `height`
+/// (Y para baixo); glyphs sem tinta (ex: espaço) não contribuem.
+///
+/// Os quatro cantos de cada glyph são transformados, então o resultado é
+/// justo para rotações e escalas. Retorna `RectF::ZERO` se não houver tinta.
+pub fn text_run_bounds(
+    positions: &[GlyphPosition],
+    metrics: &[GlyphMetrics],
+    transform: &Transform2D,
+) -> RectF {
+    let mut min = PointF::new(f32::INFINITY, f32::INFINITY);
+    let mut max = PointF::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    for (pos, m) in positions.iter().zip(metrics) {
+        if m.width <= 0.0 || m.height <= 0.0 {
+            continue;
+        }
+        let ink = RectF::new(
+            pos.x + pos.x_offset + m.bearing_x,
+            pos.y + pos.y_offset - m.bearing_y,
+            m.width,
+            m.height,
+        );
+        for p in transform.transform_quad(ink) {
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        }
+    }
+
+    if min.x > max.x {
+        return RectF::ZERO;
+    }
+    RectF::new(min.x, min.y, max.x - min.x, max.y - min.y)
+}
@@ -0,0 +1,88 @@
+//! # Tiling Layouts
+//!
+//! Divide um container em N janelas que o cobrem exatamente.
+//!
+//! O resto da divisão inteira é distribuído um pixel por vez às primeiras
+//! janelas, de modo que a soma das partes sempre iguala o container.
+
+use crate::geometry::Rect;
+
+/// Calcula o segmento `i` de `n` ao dividir `[start, start + len)`.
+#[inline]
+fn split_span(start: i32, len: u32, n: usize, i: usize) -> (i32, u32) {
+    let n = n as u32;
+    let i = i as u32;
+    let base = len / n;
+    let rem = len % n;
+    let offset = i * base + i.min(rem);
+    let size = base + if i < rem { 1 } else { 0 };
+    (start + offset as i32, size)
+}
+
+/// Divide o container em `n` colunas de mesma largura.
+pub fn tile_columns(container: Rect, n: usize) -> impl Iterator<Item = Rect> {
+    (0..n).map(move |i| {
+        let (x, width) = split_span(container.x, container.width, n, i);
+        Rect::new(x, container.y, width, container.height)
+    })
+}
+
+/// Divide o container em `n` linhas de mesma altura.
+pub fn tile_rows(container: Rect, n: usize) -> impl Iterator<Item = Rect> {
+    (0..n).map(move |i| {
+        let (y, height) = split_span(container.y, container.height, n, i);
+        Rect::new(container.x, y, container.width, height)
+    })
+}
+
+/// Divide o container em uma grade de aproximadamente `sqrt(n)` colunas.
+///
+/// A última linha pode ter menos células; elas se esticam para cobrir a
+/// largura inteira.
+pub fn tile_grid(container: Rect, n: usize) -> impl Iterator<Item = Rect> {
+    let mut cols = 1;
+    while cols * cols < n {
+        cols += 1;
+    }
+    let rows = if n == 0 { 0 } else { n.div_ceil(cols) };
+
+    (0..n).map(move |i| {
+        let row = i / cols;
+        let col = i % cols;
+        let in_row = if row == rows - 1 {
+            n - cols * (rows - 1)
+        } else {
+            cols
+        };
+        let (y, height) = split_span(container.y, container.height, rows, row);
+        let (x, width) = split_span(container.x, container.width, in_row, col);
+        Rect::new(x, y, width, height)
+    })
+}
+
+/// Layout master/stack: a primeira janela ocupa `master_fraction` da largura
+/// e as demais dividem a coluna restante em linhas.
+///
+/// Com uma única janela, ela ocupa o container inteiro.
+pub fn tile_master_stack(
+    container: Rect,
+    n: usize,
+    master_fraction: f32,
+) -> impl Iterator<Item = Rect> {
+    let fraction = master_fraction.clamp(0.0, 1.0);
+    let master_width = if n <= 1 {
+        container.width
+    } else {
+        rdsmath::roundf(container.width as f32 * fraction) as u32
+    };
+    let (master, stack) = container.split_horizontal(master_width);
+
+    (0..n).map(move |i| {
+        if i == 0 {
+            master
+        } else {
+            let (y, height) = split_span(stack.y, stack.height, n - 1, i - 1);
+            Rect::new(stack.x, y, stack.width, height)
+        }
+    })
+}
@@ -0,0 +1,7 @@
+//! # Tile Module
+//!
+//! Layouts de tiling para window managers.
+
+mod layout;
+
+pub use layout::{tile_columns, tile_grid, tile_master_stack, tile_rows};
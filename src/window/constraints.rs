@@ -0,0 +1,118 @@
+//! # Size Constraints
+//!
+//! Restrições de tamanho de janela (size hints de X11/Wayland).
+
+use crate::geometry::Size;
+
+/// Restrições de tamanho que o window manager deve respeitar.
+///
+/// Um componente `0` em `max` significa "sem limite" e `0` em `increment`
+/// significa "sem passo". Quando `min` e `max` conflitam, `min` prevalece.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SizeConstraints {
+    /// Tamanho mínimo.
+    pub min: Size,
+    /// Tamanho máximo (0 = ilimitado).
+    pub max: Size,
+    /// Passo de redimensionamento (ex: célula de um terminal).
+    pub increment: Size,
+    /// Proporção largura/altura a manter.
+    pub aspect: Option<f32>,
+    /// Tamanho base a partir do qual os passos são contados.
+    pub base: Size,
+}
+
+impl SizeConstraints {
+    /// Sem restrições.
+    pub const NONE: Self = Self {
+        min: Size::new(0, 0),
+        max: Size::new(0, 0),
+        increment: Size::new(0, 0),
+        aspect: None,
+        base: Size::new(0, 0),
+    };
+
+    /// Cria restrições de mínimo e máximo.
+    #[inline]
+    pub const fn new(min: Size, max: Size) -> Self {
+        Self {
+            min,
+            max,
+            increment: Size::new(0, 0),
+            aspect: None,
+            base: Size::new(0, 0),
+        }
+    }
+
+    /// Define o passo de redimensionamento contado a partir de `base`.
+    #[inline]
+    pub const fn with_increment(mut self, base: Size, increment: Size) -> Self {
+        self.base = base;
+        self.increment = increment;
+        self
+    }
+
+    /// Define a proporção largura/altura.
+    #[inline]
+    pub const fn with_aspect(mut self, aspect: f32) -> Self {
+        self.aspect = Some(aspect);
+        self
+    }
+
+    /// Ajusta um tamanho pedido às restrições.
+    ///
+    /// Ordem: limita ao máximo, reduz uma dimensão para manter a proporção,
+    /// arredonda para baixo ao passo e por fim sobe até o mínimo (ainda em
+    /// passos inteiros).
+    pub fn apply(&self, requested: Size) -> Size {
+        let mut width = requested.width.min(unbounded(self.max.width));
+        let mut height = requested.height.min(unbounded(self.max.height));
+
+        if let Some(aspect) = self.aspect.filter(|a| a.is_finite() && *a > 0.0) {
+            if width as f32 > height as f32 * aspect {
+                width = rdsmath::roundf(height as f32 * aspect) as u32;
+            } else {
+                height = rdsmath::roundf(width as f32 / aspect) as u32;
+            }
+        }
+
+        Size::new(
+            step(width, self.min.width, self.base.width, self.increment.width),
+            step(
+                height,
+                self.min.height,
+                self.base.height,
+                self.increment.height,
+            ),
+        )
+    }
+}
+
+/// Converte máximo `0` em ilimitado.
+#[inline]
+const fn unbounded(max: u32) -> u32 {
+    if max == 0 {
+        u32::MAX
+    } else {
+        max
+    }
+}
+
+/// Arredonda `value` para baixo em passos de `increment` a partir de `base`
+/// e garante `min`, subindo em passos inteiros.
+#[inline]
+fn step(value: u32, min: u32, base: u32, increment: u32) -> u32 {
+    let increment = increment.max(1);
+    let snapped = if value > base {
+        base + (value - base) / increment * increment
+    } else {
+        value
+    };
+    if snapped >= min {
+        snapped
+    } else if min > base {
+        base.saturating_add((min - base).div_ceil(increment).saturating_mul(increment))
+    } else {
+        min
+    }
+}
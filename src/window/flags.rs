@@ -59,6 +59,9 @@ impl WindowFlags {
     /// Janela com sombra.
     pub const HAS_SHADOW: Self = Self(1 << 15);
 
+    /// Janela transparente para input (cliques passam para baixo).
+    pub const INPUT_TRANSPARENT: Self = Self(1 << 16);
+
     // =========================================================================
     // METHODS
     // =========================================================================
@@ -111,6 +114,12 @@ impl WindowFlags {
         self.has(Self::OVERLAY)
     }
 
+    /// Verifica se a janela deve receber eventos de ponteiro.
+    #[inline]
+    pub const fn accepts_pointer(&self) -> bool {
+        !self.has(Self::NO_FOCUS) && !self.has(Self::INPUT_TRANSPARENT)
+    }
+
     /// Verifica se deve ter decorações.
     #[inline]
     pub const fn has_decorations(&self) -> bool {
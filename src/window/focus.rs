@@ -0,0 +1,45 @@
+//! # Focus Navigation
+//!
+//! Navegação direcional de foco entre retângulos (D-pad, controle remoto).
+
+use crate::geometry::Rect;
+use crate::input::SwipeDirection;
+
+/// Peso da distância no eixo de movimento em relação ao desalinhamento.
+const MAJOR_AXIS_WEIGHT: i128 = 13;
+
+/// Escolhe o melhor retângulo para mover o foco na direção dada.
+///
+/// Só são candidatos retângulos cujo centro está além do centro atual na
+/// direção. A pontuação combina a distância entre bordas no eixo de
+/// movimento (com peso maior) e o desalinhamento dos centros no eixo
+/// perpendicular. Retorna `None` se não houver candidato.
+pub fn next_focusable(rects: &[Rect], current: usize, direction: SwipeDirection) -> Option<usize> {
+    let cur = rects.get(current)?;
+    let cc = cur.center();
+
+    rects
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != current)
+        .filter_map(|(i, cand)| {
+            let c = cand.center();
+            // Diferenças em i64 (estouram em i32 para retângulos distantes)
+            // e pontuação em i128: 13 * (2^33)^2 cabe sem saturar, então a
+            // ordem entre candidatos distantes é preservada
+            let d = |a: i32, b: i32| a as i64 - b as i64;
+            let (ahead, major, minor) = match direction {
+                SwipeDirection::Right => (c.x > cc.x, d(cand.left(), cur.right()), d(c.y, cc.y)),
+                SwipeDirection::Left => (c.x < cc.x, d(cur.left(), cand.right()), d(c.y, cc.y)),
+                SwipeDirection::Down => (c.y > cc.y, d(cand.top(), cur.bottom()), d(c.x, cc.x)),
+                SwipeDirection::Up => (c.y < cc.y, d(cur.top(), cand.bottom()), d(c.x, cc.x)),
+            };
+            if !ahead {
+                return None;
+            }
+            let (major, minor) = (major.max(0) as i128, minor as i128);
+            Some((i, MAJOR_AXIS_WEIGHT * major * major + minor * minor))
+        })
+        .min_by_key(|&(_, score)| score)
+        .map(|(i, _)| i)
+}
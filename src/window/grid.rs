@@ -0,0 +1,179 @@
+//! # Spatial Grid
+//!
+//! Grade uniforme para acelerar hit-testing e interseção de damage com
+//! muitas janelas.
+
+use super::SurfaceId;
+use crate::geometry::{Point, Rect};
+
+/// Número máximo de entradas em uma SpatialGrid (uma por bit de célula).
+pub const MAX_GRID_ENTRIES: usize = 64;
+
+/// Número de células por eixo.
+pub const GRID_DIM: usize = 8;
+
+/// Grade espacial limitada de `(SurfaceId, Rect)` (sem alocação).
+///
+/// `bounds` é dividido em `GRID_DIM` x `GRID_DIM` células; cada célula
+/// guarda um bitmask das entradas que a tocam. Coordenadas fora de `bounds`
+/// caem nas células da borda, então nada é perdido, só fica menos seletivo.
+#[derive(Clone, Copy, Debug)]
+pub struct SpatialGrid {
+    /// Área coberta pela grade.
+    bounds: Rect,
+    /// Largura de uma célula.
+    cell_width: u32,
+    /// Altura de uma célula.
+    cell_height: u32,
+    /// Bitmask de entradas por célula (linha por linha).
+    cells: [u64; GRID_DIM * GRID_DIM],
+    /// Entradas armazenadas.
+    entries: [(SurfaceId, Rect); MAX_GRID_ENTRIES],
+    /// Número de entradas válidas.
+    count: usize,
+}
+
+impl SpatialGrid {
+    /// Cria grade vazia sobre `bounds` (ex: a área do output).
+    #[inline]
+    pub const fn new(bounds: Rect) -> Self {
+        let dim = GRID_DIM as u32;
+        let cell_width = bounds.width.div_ceil(dim);
+        let cell_height = bounds.height.div_ceil(dim);
+        Self {
+            bounds,
+            cell_width: if cell_width == 0 { 1 } else { cell_width },
+            cell_height: if cell_height == 0 { 1 } else { cell_height },
+            cells: [0; GRID_DIM * GRID_DIM],
+            entries: [(SurfaceId::INVALID, Rect::ZERO); MAX_GRID_ENTRIES],
+            count: 0,
+        }
+    }
+
+    /// Área coberta pela grade.
+    #[inline]
+    pub const fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Número de entradas.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazia.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Remove todas as entradas.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells = [0; GRID_DIM * GRID_DIM];
+        self.count = 0;
+    }
+
+    /// Insere uma superfície. Retorna `false` se cheia ou `rect` vazio.
+    pub fn insert(&mut self, surface: SurfaceId, rect: Rect) -> bool {
+        if self.count >= MAX_GRID_ENTRIES || rect.is_empty() {
+            return false;
+        }
+        self.entries[self.count] = (surface, rect);
+        self.mark(self.count, rect);
+        self.count += 1;
+        true
+    }
+
+    /// Remove todas as entradas de `surface`. Retorna `true` se alguma saiu.
+    pub fn remove(&mut self, surface: SurfaceId) -> bool {
+        let before = self.count;
+        let mut kept = 0;
+        for i in 0..self.count {
+            if self.entries[i].0 != surface {
+                self.entries[kept] = self.entries[i];
+                kept += 1;
+            }
+        }
+        if kept == before {
+            return false;
+        }
+        // Índices mudaram: reconstruir os bitmasks
+        self.cells = [0; GRID_DIM * GRID_DIM];
+        self.count = kept;
+        for i in 0..kept {
+            self.mark(i, self.entries[i].1);
+        }
+        true
+    }
+
+    /// Superfícies cujo retângulo contém `p`, na ordem de inserção.
+    pub fn query_point(&self, p: Point) -> impl Iterator<Item = SurfaceId> + '_ {
+        let mask = self.cells[self.cell_y(p.y) * GRID_DIM + self.cell_x(p.x)];
+        self.candidates(mask)
+            .filter(move |(_, rect)| rect.contains_point(p))
+            .map(|(surface, _)| surface)
+    }
+
+    /// Superfícies cujo retângulo intersecta `r`, na ordem de inserção.
+    pub fn query_rect(&self, r: Rect) -> impl Iterator<Item = SurfaceId> + '_ {
+        let mut mask = 0;
+        if !r.is_empty() {
+            let (x0, x1, y0, y1) = self.cell_span(r);
+            for cy in y0..=y1 {
+                for cx in x0..=x1 {
+                    mask |= self.cells[cy * GRID_DIM + cx];
+                }
+            }
+        }
+        self.candidates(mask)
+            .filter(move |(_, rect)| rect.intersects(&r))
+            .map(|(surface, _)| surface)
+    }
+
+    /// Entradas selecionadas pelos bits de `mask`.
+    #[inline]
+    fn candidates(&self, mask: u64) -> impl Iterator<Item = (SurfaceId, Rect)> + '_ {
+        self.entries[..self.count]
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| mask & (1 << i) != 0)
+            .map(|(_, entry)| *entry)
+    }
+
+    /// Marca o bit `index` em todas as células tocadas por `rect`.
+    fn mark(&mut self, index: usize, rect: Rect) {
+        let (x0, x1, y0, y1) = self.cell_span(rect);
+        for cy in y0..=y1 {
+            for cx in x0..=x1 {
+                self.cells[cy * GRID_DIM + cx] |= 1 << index;
+            }
+        }
+    }
+
+    /// Células (inclusivas) tocadas por um retângulo não vazio.
+    #[inline]
+    fn cell_span(&self, rect: Rect) -> (usize, usize, usize, usize) {
+        (
+            self.cell_x(rect.x),
+            self.cell_x(rect.right() - 1),
+            self.cell_y(rect.y),
+            self.cell_y(rect.bottom() - 1),
+        )
+    }
+
+    /// Coluna da célula que contém `x` (limitada à grade).
+    #[inline]
+    fn cell_x(&self, x: i32) -> usize {
+        let offset = x as i64 - self.bounds.x as i64;
+        (offset.div_euclid(self.cell_width as i64)).clamp(0, GRID_DIM as i64 - 1) as usize
+    }
+
+    /// Linha da célula que contém `y` (limitada à grade).
+    #[inline]
+    fn cell_y(&self, y: i32) -> usize {
+        let offset = y as i64 - self.bounds.y as i64;
+        (offset.div_euclid(self.cell_height as i64)).clamp(0, GRID_DIM as i64 - 1) as usize
+    }
+}
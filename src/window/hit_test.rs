@@ -0,0 +1,20 @@
+//! # Hit Testing
+//!
+//! Roteamento de ponteiro entre janelas em camadas.
+
+use super::{LayerType, WindowFlags};
+use crate::geometry::{Point, Rect};
+
+/// Retorna o índice da janela mais acima que contém o ponto.
+///
+/// A ordem é pela camada (maior primeiro) e, dentro da mesma camada, pela
+/// posição na lista (mais tarde = mais acima). Janelas com `NO_FOCUS` ou
+/// `INPUT_TRANSPARENT` são ignoradas.
+pub fn hit_test(windows: &[(LayerType, Rect, WindowFlags)], p: Point) -> Option<usize> {
+    windows
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, rect, flags))| flags.accepts_pointer() && rect.contains_point(p))
+        .max_by_key(|&(i, (layer, _, _))| (*layer, i))
+        .map(|(i, _)| i)
+}
@@ -72,4 +72,12 @@ impl LayerType {
     pub const fn blocks_below(&self) -> bool {
         matches!(self, Self::Lock)
     }
+
+    /// Chave de empilhamento: camada primeiro, depois `z` dentro da camada.
+    ///
+    /// Chaves menores são desenhadas antes (mais ao fundo).
+    #[inline]
+    pub const fn stacking_key(&self, z: u32) -> u64 {
+        ((*self as u64) << 32) | z as u64
+    }
 }
@@ -2,14 +2,28 @@
 //!
 //! Tipos relacionados a janelas e superfícies.
 
+mod constraints;
 mod effects;
 mod flags;
+mod focus;
+mod grid;
+mod hit_test;
 mod layer;
+mod paint;
 mod state;
 mod surface;
+mod tree;
 
+pub use crate::geometry::PopupGravity;
+
+pub use constraints::SizeConstraints;
 pub use effects::{BlurParams, BlurType, OpacityParams, ShadowParams, WindowEffects};
 pub use flags::WindowFlags;
+pub use focus::next_focusable;
+pub use grid::{SpatialGrid, GRID_DIM, MAX_GRID_ENTRIES};
+pub use hit_test::hit_test;
 pub use layer::LayerType;
-pub use state::{ResizeEdge, WindowState, WindowType};
+pub use paint::{PaintEntry, PaintList, MAX_PAINT_ENTRIES};
+pub use state::{resolve_window_rect, ResizeEdge, WindowState, WindowType};
 pub use surface::{BufferMode, SurfaceCommit, SurfaceConfig, SurfaceId, SurfaceType};
+pub use tree::{SurfaceNode, SurfaceTree, MAX_TREE_NODES};
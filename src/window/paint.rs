@@ -0,0 +1,121 @@
+//! # Paint List
+//!
+//! Ordem de pintura das superfícies no compositor.
+
+use super::{LayerType, SurfaceId};
+use crate::geometry::Rect;
+
+/// Número máximo de entradas em uma PaintList.
+pub const MAX_PAINT_ENTRIES: usize = 64;
+
+/// Entrada da lista de pintura.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PaintEntry {
+    /// Camada da superfície.
+    pub layer: LayerType,
+    /// Ordem dentro da camada (maior = mais acima).
+    pub z: u32,
+    /// Superfície.
+    pub surface: SurfaceId,
+    /// Retângulo em coordenadas globais.
+    pub rect: Rect,
+}
+
+impl PaintEntry {
+    /// Chave de empilhamento (ver `LayerType::stacking_key`).
+    #[inline]
+    pub const fn stacking_key(&self) -> u64 {
+        self.layer.stacking_key(self.z)
+    }
+}
+
+/// Lista de superfícies ordenada de trás para frente (sem alocação).
+///
+/// Entradas com a mesma chave mantêm a ordem de inserção.
+#[derive(Clone, Copy, Debug)]
+pub struct PaintList {
+    /// Entradas ordenadas por `stacking_key`.
+    entries: [PaintEntry; MAX_PAINT_ENTRIES],
+    /// Número de entradas válidas.
+    count: usize,
+}
+
+impl Default for PaintList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaintList {
+    /// Cria lista vazia.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: [PaintEntry {
+                layer: LayerType::Background,
+                z: 0,
+                surface: SurfaceId::INVALID,
+                rect: Rect::ZERO,
+            }; MAX_PAINT_ENTRIES],
+            count: 0,
+        }
+    }
+
+    /// Número de entradas.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazia.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Remove todas as entradas.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Entradas de trás para frente como slice.
+    #[inline]
+    pub fn entries(&self) -> &[PaintEntry] {
+        &self.entries[..self.count]
+    }
+
+    /// Insere uma entrada na posição de pintura. Retorna `false` se cheia.
+    ///
+    /// A entrada vai depois de todas com chave menor ou igual, o que mantém
+    /// a ordenação estável.
+    pub fn push(&mut self, layer: LayerType, z: u32, surface: SurfaceId, rect: Rect) -> bool {
+        if self.count >= MAX_PAINT_ENTRIES {
+            return false;
+        }
+        let entry = PaintEntry {
+            layer,
+            z,
+            surface,
+            rect,
+        };
+        let key = entry.stacking_key();
+        let pos = self.entries[..self.count].partition_point(|e| e.stacking_key() <= key);
+        self.entries.copy_within(pos..self.count, pos + 1);
+        self.entries[pos] = entry;
+        self.count += 1;
+        true
+    }
+
+    /// Itera na ordem de pintura (fundo primeiro).
+    #[inline]
+    pub fn iter_back_to_front(&self) -> impl Iterator<Item = &PaintEntry> {
+        self.entries().iter()
+    }
+
+    /// Itera na ordem de hit-test (topo primeiro).
+    #[inline]
+    pub fn iter_front_to_back(&self) -> impl Iterator<Item = &PaintEntry> {
+        self.entries().iter().rev()
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! Estados e tipos de janela.
 
+use super::WindowFlags;
+use crate::geometry::Rect;
+
 // =============================================================================
 // WINDOW STATE
 // =============================================================================
@@ -81,6 +84,40 @@ impl WindowState {
     }
 }
 
+// =============================================================================
+// WINDOW GEOMETRY
+// =============================================================================
+
+/// Calcula o retângulo real de uma janela a partir do seu estado.
+///
+/// `Maximized` e os tiles usam `work_area` (output menos painéis/struts);
+/// `Fullscreen` cobre o `output` inteiro. `Normal`, `Minimized` e `Hidden`
+/// retornam o retângulo salvo `normal`.
+///
+/// Flags têm precedência: `FULLSCREEN` força tela cheia, e `NO_MAXIMIZE` /
+/// `NO_RESIZE` mantêm o retângulo normal em vez de maximizar ou tilar.
+pub fn resolve_window_rect(
+    state: WindowState,
+    flags: WindowFlags,
+    normal: Rect,
+    work_area: Rect,
+    output: Rect,
+) -> Rect {
+    if flags.has(WindowFlags::FULLSCREEN) && state.is_visible() {
+        return output;
+    }
+    let locked = flags.has(WindowFlags::NO_RESIZE);
+    match state {
+        WindowState::Fullscreen => output,
+        WindowState::Maximized if !locked && !flags.has(WindowFlags::NO_MAXIMIZE) => work_area,
+        WindowState::TiledLeft if !locked => work_area.split_at_fraction(0.5, true).0,
+        WindowState::TiledRight if !locked => work_area.split_at_fraction(0.5, true).1,
+        WindowState::TiledTop if !locked => work_area.split_at_fraction(0.5, false).0,
+        WindowState::TiledBottom if !locked => work_area.split_at_fraction(0.5, false).1,
+        _ => normal,
+    }
+}
+
 // =============================================================================
 // WINDOW TYPE
 // =============================================================================
@@ -2,7 +2,11 @@
 //!
 //! Superfícies e buffers de janela.
 
-use crate::buffer::BufferHandle;
+use core::fmt;
+
+use crate::buffer::{BufferDescriptor, BufferHandle};
+use crate::color::Color;
+use crate::display::{DisplayInfo, OutputInfo};
 use crate::geometry::Size;
 
 /// ID de superfície.
@@ -25,6 +29,28 @@ impl SurfaceId {
     pub const fn is_valid(&self) -> bool {
         self.0 != 0
     }
+
+    /// Verifica se é o valor sentinela reservado (`INVALID`).
+    #[inline]
+    pub const fn is_reserved(&self) -> bool {
+        self.0 == Self::INVALID.0
+    }
+
+    /// Cor estável para tingir a superfície em modo de depuração.
+    #[inline]
+    pub fn debug_color(&self) -> Color {
+        Color::from_debug_id(self.0 as u64)
+    }
+}
+
+impl fmt::Display for SurfaceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_reserved() {
+            f.write_str("Surface(INVALID)")
+        } else {
+            write!(f, "Surface(#{})", self.0)
+        }
+    }
 }
 
 /// Tipo de superfície.
@@ -145,6 +171,21 @@ impl SurfaceConfig {
         self.buffer_mode = mode;
         self
     }
+
+    /// Escala de buffer preferida dado os displays que a superfície cobre.
+    ///
+    /// Cada display entra com seu output (como em
+    /// [`DisplayRecord::decode`](crate::display::DisplayRecord::decode)); a
+    /// escala vem da densidade física ([`OutputInfo::buffer_scale`]), não da
+    /// resolução. Usa a maior, para que o conteúdo fique nítido no display de
+    /// maior densidade. Sem displays, retorna 1.
+    pub fn preferred_buffer_scale(&self, displays: &[(DisplayInfo, OutputInfo)]) -> u32 {
+        displays
+            .iter()
+            .map(|(display, output)| output.buffer_scale(display.width, display.height))
+            .max()
+            .unwrap_or(1)
+    }
 }
 
 /// Estado de commit de superfície.
@@ -187,4 +228,20 @@ impl SurfaceCommit {
         self.offset_y = y;
         self
     }
+
+    /// Verifica se `buffer` tem exatamente `logical * scale` pixels.
+    ///
+    /// Escala 0 ou overflow são inválidos.
+    pub fn validate_scale(&self, buffer: &BufferDescriptor, logical: Size) -> bool {
+        if self.scale == 0 {
+            return false;
+        }
+        match (
+            logical.width.checked_mul(self.scale),
+            logical.height.checked_mul(self.scale),
+        ) {
+            (Some(w), Some(h)) => buffer.width == w && buffer.height == h,
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,122 @@
+//! # Surface Tree
+//!
+//! Hierarquia de superfícies (toplevel, popups, subsuperfícies).
+
+use super::SurfaceId;
+use crate::geometry::Rect;
+
+/// Número máximo de nós em uma SurfaceTree.
+pub const MAX_TREE_NODES: usize = 32;
+
+/// Nó da árvore de superfícies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SurfaceNode {
+    /// ID da superfície.
+    pub id: SurfaceId,
+    /// Superfície pai (`SurfaceId::INVALID` para raízes).
+    pub parent: SurfaceId,
+    /// Retângulo em coordenadas globais.
+    pub rect: Rect,
+}
+
+/// Árvore de superfícies com capacidade fixa (sem alocação).
+#[derive(Clone, Copy, Debug)]
+pub struct SurfaceTree {
+    /// Nós armazenados.
+    nodes: [SurfaceNode; MAX_TREE_NODES],
+    /// Número de nós válidos.
+    count: usize,
+}
+
+impl Default for SurfaceTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SurfaceTree {
+    /// Cria árvore vazia.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            nodes: [SurfaceNode {
+                id: SurfaceId::INVALID,
+                parent: SurfaceId::INVALID,
+                rect: Rect::ZERO,
+            }; MAX_TREE_NODES],
+            count: 0,
+        }
+    }
+
+    /// Número de nós.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Verifica se está vazia.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Nós válidos como slice.
+    #[inline]
+    pub fn nodes(&self) -> &[SurfaceNode] {
+        &self.nodes[..self.count]
+    }
+
+    /// Adiciona uma superfície. Retorna `false` se cheia ou se o ID já existe.
+    pub fn insert(&mut self, id: SurfaceId, parent: SurfaceId, rect: Rect) -> bool {
+        if self.count >= MAX_TREE_NODES || !id.is_valid() || self.get(id).is_some() {
+            return false;
+        }
+        self.nodes[self.count] = SurfaceNode { id, parent, rect };
+        self.count += 1;
+        true
+    }
+
+    /// Obtém um nó por ID.
+    #[inline]
+    pub fn get(&self, id: SurfaceId) -> Option<&SurfaceNode> {
+        self.nodes().iter().find(|node| node.id == id)
+    }
+
+    /// União do retângulo de uma superfície com o de todos os descendentes.
+    pub fn subtree_bounds(&self, root: SurfaceId) -> Option<Rect> {
+        let root_index = self.nodes().iter().position(|node| node.id == root)?;
+
+        let mut in_subtree = [false; MAX_TREE_NODES];
+        in_subtree[root_index] = true;
+        let mut bounds = self.nodes[root_index].rect;
+
+        // Propaga até estabilizar (profundidade arbitrária, imune a ciclos)
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 0..self.count {
+                if in_subtree[i] {
+                    continue;
+                }
+                let parent = self.nodes[i].parent;
+                let parent_in_subtree = self
+                    .nodes()
+                    .iter()
+                    .enumerate()
+                    .any(|(j, node)| in_subtree[j] && node.id == parent);
+                if parent_in_subtree {
+                    in_subtree[i] = true;
+                    bounds = bounds.union(&self.nodes[i].rect);
+                    changed = true;
+                }
+            }
+        }
+        Some(bounds)
+    }
+
+    /// Limpa a árvore.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
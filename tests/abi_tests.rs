@@ -0,0 +1,47 @@
+//! # Testes de ABI
+//!
+//! Testes para o layout binário dos tipos compartilhados.
+
+use core::mem::size_of;
+
+use gfx_types::abi::*;
+use gfx_types::buffer::BufferDescriptor;
+use gfx_types::color::Color;
+use gfx_types::geometry::{Point, Rect, Transform2D};
+
+fn lookup(name: &str) -> Option<(usize, usize)> {
+    layout_info()
+        .iter()
+        .find(|(n, _, _)| *n == name)
+        .map(|&(_, size, align)| (size, align))
+}
+
+// =============================================================================
+// LAYOUT TESTS
+// =============================================================================
+
+#[test]
+fn test_abi_known_sizes() {
+    assert_eq!(size_of::<Rect>(), 16);
+    assert_eq!(size_of::<Point>(), 8);
+    assert_eq!(size_of::<Color>(), 4);
+    assert_eq!(size_of::<BufferDescriptor>(), 16);
+    assert_eq!(size_of::<Transform2D>(), 24);
+}
+
+#[test]
+fn test_abi_layout_info_matches() {
+    assert_eq!(lookup("Rect"), Some((16, 4)));
+    assert_eq!(lookup("Color"), Some((4, 4)));
+    assert_eq!(lookup("BufferDescriptor"), Some((16, 4)));
+    assert_eq!(lookup("BufferHandle"), Some((8, 8)));
+    assert_eq!(lookup("NotAType"), None);
+}
+
+#[test]
+fn test_abi_layout_info_unique_names() {
+    let table = layout_info();
+    for (i, (a, _, _)) in table.iter().enumerate() {
+        assert!(table[i + 1..].iter().all(|(b, _, _)| a != b));
+    }
+}
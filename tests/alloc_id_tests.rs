@@ -0,0 +1,92 @@
+//! # Testes de Alloc ID
+//!
+//! Testes para o alocador de IDs.
+
+use gfx_types::alloc_id::*;
+
+// =============================================================================
+// ID ALLOCATOR TESTS
+// =============================================================================
+
+#[test]
+fn test_id_allocator_never_invalid() {
+    let mut ids = IdAllocator::starting_at(0);
+    for _ in 0..100 {
+        assert!(ids.alloc_surface().unwrap().is_valid());
+        assert!(ids.alloc_buffer().unwrap().is_valid());
+    }
+}
+
+#[test]
+fn test_id_allocator_recycles() {
+    let mut ids = IdAllocator::new();
+    let a = ids.alloc_surface().unwrap();
+    let b = ids.alloc_surface().unwrap();
+    assert_ne!(a, b);
+    assert!(ids.free_surface(a));
+    assert_eq!(ids.alloc_surface(), Some(a));
+    assert_eq!(ids.free_count(), 0);
+}
+
+#[test]
+fn test_id_allocator_rejects_double_and_unissued_free() {
+    let mut ids = IdAllocator::starting_at(10);
+    let a = ids.alloc().unwrap();
+    let b = ids.alloc().unwrap();
+    assert!(ids.free(a));
+    // Double free
+    assert!(!ids.free(a));
+    // Nunca emitidos (abaixo do início e além do contador)
+    assert!(!ids.free(5));
+    assert!(!ids.free(b + 1));
+    assert_eq!(ids.free_count(), 1);
+
+    assert_eq!(ids.alloc(), Some(a));
+    assert_ne!(ids.alloc(), Some(a));
+}
+
+#[test]
+fn test_id_allocator_buffer_generation_bumps() {
+    let mut ids = IdAllocator::new();
+    let old = ids.alloc_buffer().unwrap();
+    assert_eq!(old.generation(), 0);
+    assert!(ids.free_buffer(old));
+    let new = ids.alloc_buffer().unwrap();
+    assert_eq!(new.id(), old.id());
+    assert_eq!(new.generation(), 1);
+    // Handle antigo não é igual ao novo (sem ABA)
+    assert_ne!(new, old);
+
+    assert!(ids.free_buffer(new));
+    assert_eq!(ids.alloc_buffer().unwrap().generation(), 2);
+}
+
+#[test]
+fn test_id_allocator_exhaustion_never_reissues_live() {
+    let mut ids = IdAllocator::starting_at(u32::MAX - 1);
+    let a = ids.alloc().unwrap();
+    let b = ids.alloc().unwrap();
+    assert_eq!((a, b), (u32::MAX - 1, u32::MAX));
+    assert!(ids.is_exhausted());
+    // Sem volta: nada novo enquanto `a` e `b` estão vivos
+    assert_eq!(ids.alloc(), None);
+
+    // Só o ID liberado volta; o vivo (`b`) nunca é reemitido
+    assert!(ids.free(a));
+    assert_eq!(ids.alloc(), Some(a));
+    assert_eq!(ids.alloc(), None);
+    assert!(!ids.free(1));
+}
+
+#[test]
+fn test_id_allocator_free_limits() {
+    let mut ids = IdAllocator::new();
+    assert!(!ids.free(0));
+    let live: Vec<u32> = (0..=MAX_FREE_IDS).map(|_| ids.alloc().unwrap()).collect();
+    for &id in &live[..MAX_FREE_IDS] {
+        assert!(ids.free(id));
+    }
+    // Lista cheia: o ID é descartado
+    assert!(!ids.free(live[MAX_FREE_IDS]));
+    assert_eq!(ids.free_count(), MAX_FREE_IDS);
+}
@@ -0,0 +1,113 @@
+//! # Testes de Anim
+//!
+//! Testes para curvas de easing.
+
+use gfx_types::anim::*;
+
+// =============================================================================
+// EASING TESTS
+// =============================================================================
+
+#[test]
+fn test_easing_endpoints() {
+    for easing in Easing::ALL {
+        assert!(easing.apply(0.0).abs() < 1e-4, "{:?} em 0", easing);
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-4, "{:?} em 1", easing);
+    }
+}
+
+#[test]
+fn test_easing_clamps_input() {
+    for easing in Easing::ALL {
+        assert_eq!(easing.apply(-1.0), easing.apply(0.0));
+        assert_eq!(easing.apply(2.0), easing.apply(1.0));
+    }
+}
+
+#[test]
+fn test_easing_out_reflects_in() {
+    let pairs = [
+        (Easing::QuadIn, Easing::QuadOut),
+        (Easing::CubicIn, Easing::CubicOut),
+        (Easing::QuartIn, Easing::QuartOut),
+        (Easing::SineIn, Easing::SineOut),
+        (Easing::ExpoIn, Easing::ExpoOut),
+        (Easing::BackIn, Easing::BackOut),
+        (Easing::BounceIn, Easing::BounceOut),
+    ];
+    for (ease_in, ease_out) in pairs {
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            let reflected = 1.0 - ease_in.apply(1.0 - t);
+            assert!((ease_out.apply(t) - reflected).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_easing_in_out_midpoint() {
+    for easing in [
+        Easing::QuadInOut,
+        Easing::CubicInOut,
+        Easing::SineInOut,
+        Easing::ExpoInOut,
+        Easing::BounceInOut,
+    ] {
+        assert!((easing.apply(0.5) - 0.5).abs() < 1e-3, "{:?}", easing);
+    }
+}
+
+#[test]
+fn test_easing_back_overshoots() {
+    assert!(Easing::BackIn.apply(0.2) < 0.0);
+    assert!(Easing::BackOut.apply(0.8) > 1.0);
+}
+
+#[test]
+fn test_easing_from_u8() {
+    assert_eq!(Easing::from_u8(0), Some(Easing::Linear));
+    assert_eq!(Easing::from_u8(21), Some(Easing::BounceInOut));
+    assert_eq!(Easing::from_u8(22), None);
+}
+
+// =============================================================================
+// CUBIC BEZIER TESTS
+// =============================================================================
+
+#[test]
+fn test_cubic_bezier_ease() {
+    let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+    assert!((ease.solve(0.5) - 0.8024).abs() < 0.01);
+    assert_eq!(ease, CubicBezier::EASE);
+}
+
+#[test]
+fn test_cubic_bezier_linear_identity() {
+    let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+    for i in 0..=20 {
+        let t = i as f32 / 20.0;
+        assert!((linear.solve(t) - t).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_cubic_bezier_endpoints_and_clamp() {
+    let curve = CubicBezier::new(0.68, -0.6, 0.32, 1.6);
+    assert_eq!(curve.solve(-1.0), 0.0);
+    assert_eq!(curve.solve(0.0), 0.0);
+    assert_eq!(curve.solve(1.0), 1.0);
+    assert_eq!(curve.solve(2.0), 1.0);
+    // Pontos de controle fora de 0-1 em y produzem overshoot
+    assert!(curve.solve(0.9) > 1.0);
+}
+
+#[test]
+fn test_cubic_bezier_monotonic_ease_in_out() {
+    let curve = CubicBezier::EASE_IN_OUT;
+    let mut prev = 0.0;
+    for i in 1..=50 {
+        let y = curve.solve(i as f32 / 50.0);
+        assert!(y >= prev);
+        prev = y;
+    }
+}
@@ -0,0 +1,111 @@
+//! # Testes de Atlas
+//!
+//! Testes para os empacotadores de atlas.
+
+use gfx_types::atlas::*;
+use gfx_types::geometry::{Rect, Size};
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Tamanhos variados (pseudo-aleatórios, determinísticos).
+fn mixed_sizes() -> Vec<Size> {
+    let mut seed = 0x2545_F491u32;
+    (0..200)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            Size::new(4 + seed % 40, 4 + (seed >> 8) % 40)
+        })
+        .collect()
+}
+
+fn assert_disjoint_and_inside(rects: &[Rect], atlas: Size) {
+    let bounds = Rect::from_size(atlas);
+    for (i, a) in rects.iter().enumerate() {
+        assert!(bounds.contains_rect(a), "{:?} fora do atlas", a);
+        for b in &rects[i + 1..] {
+            assert!(!a.intersects(b), "{:?} intersecta {:?}", a, b);
+        }
+    }
+}
+
+// =============================================================================
+// SHELF PACKER TESTS
+// =============================================================================
+
+#[test]
+fn test_shelf_packer_basic() {
+    let mut packer = ShelfPacker::new(Size::new(100, 100));
+    assert_eq!(
+        packer.insert(Size::new(60, 20)),
+        Some(Rect::new(0, 0, 60, 20))
+    );
+    assert_eq!(
+        packer.insert(Size::new(40, 10)),
+        Some(Rect::new(60, 0, 40, 10))
+    );
+    assert_eq!(
+        packer.insert(Size::new(50, 30)),
+        Some(Rect::new(0, 20, 50, 30))
+    );
+    assert_eq!(packer.insert(Size::new(101, 1)), None);
+    assert_eq!(packer.insert(Size::new(0, 10)), None);
+    assert!((packer.occupancy() - 0.31).abs() < 1e-4);
+    packer.clear();
+    assert_eq!(packer.occupancy(), 0.0);
+}
+
+// =============================================================================
+// MAX RECTS PACKER TESTS
+// =============================================================================
+
+#[test]
+fn test_max_rects_fills_exactly() {
+    let mut packer = MaxRectsPacker::new(Size::new(64, 64));
+    for _ in 0..16 {
+        assert!(packer.insert(Size::new(16, 16)).is_some());
+    }
+    assert_eq!(packer.insert(Size::new(1, 1)), None);
+    assert!((packer.occupancy() - 1.0).abs() < 1e-6);
+    assert_eq!(packer.free_count(), 0);
+
+    packer.clear();
+    assert_eq!(
+        packer.insert(Size::new(64, 64)),
+        Some(Rect::new(0, 0, 64, 64))
+    );
+}
+
+#[test]
+fn test_max_rects_mixed_sizes_disjoint() {
+    let atlas = Size::new(256, 256);
+    let mut packer = MaxRectsPacker::new(atlas);
+    let placed: Vec<Rect> = mixed_sizes()
+        .into_iter()
+        .filter_map(|s| packer.insert(s))
+        .collect();
+    assert!(placed.len() > 50);
+    assert_disjoint_and_inside(&placed, atlas);
+}
+
+#[test]
+fn test_max_rects_beats_shelf() {
+    let atlas = Size::new(256, 256);
+    let mut shelf = ShelfPacker::new(atlas);
+    let mut max_rects = MaxRectsPacker::new(atlas);
+    let mut shelf_rects = Vec::new();
+    for size in mixed_sizes() {
+        shelf_rects.extend(shelf.insert(size));
+        max_rects.insert(size);
+    }
+    assert_disjoint_and_inside(&shelf_rects, atlas);
+    assert!(
+        max_rects.occupancy() > shelf.occupancy(),
+        "max_rects {} <= shelf {}",
+        max_rects.occupancy(),
+        shelf.occupancy()
+    );
+}
@@ -3,7 +3,10 @@
 //! Testes para os tipos de buffer.
 
 use gfx_types::buffer::*;
-use gfx_types::color::PixelFormat;
+use gfx_types::color::{BlendMode, Channel, Color, ColorLut, ColorRamp, Gradient, PixelFormat};
+use gfx_types::damage::DamageBuffer;
+use gfx_types::geometry::{FillRule, Point, PointF, Rect, RoundedRect, Size, StaticPolygon};
+use gfx_types::render::FillParams;
 
 // =============================================================================
 // BUFFER DESCRIPTOR TESTS
@@ -46,6 +49,79 @@ fn test_buffer_descriptor_row_offset() {
     assert_eq!(desc.row_offset(10), 4000);
 }
 
+#[test]
+fn test_buffer_descriptor_aligned() {
+    let desc = BufferDescriptor::builder(10, 4)
+        .format(PixelFormat::RGB888)
+        .alignment(64)
+        .build()
+        .unwrap();
+    assert_eq!(desc.stride, 64);
+    assert_eq!(desc.row_padding(), 34);
+}
+
+#[test]
+fn test_buffer_descriptor_builder_default() {
+    let desc = BufferDescriptor::builder(800, 600).build().unwrap();
+    assert_eq!(desc, BufferDescriptor::new(800, 600, PixelFormat::ARGB8888));
+}
+
+#[test]
+fn test_buffer_descriptor_builder_aligned() {
+    let desc = BufferDescriptorBuilder::new(10, 10)
+        .format(PixelFormat::RGB888)
+        .alignment(16)
+        .build()
+        .unwrap();
+    assert_eq!(desc.stride, 32);
+    assert_eq!(desc.format, PixelFormat::RGB888);
+
+    let explicit = BufferDescriptorBuilder::new(10, 10)
+        .stride(128)
+        .alignment(64)
+        .build()
+        .unwrap();
+    assert_eq!(explicit.stride, 128);
+}
+
+#[test]
+fn test_buffer_descriptor_builder_invalid() {
+    let builder = BufferDescriptorBuilder::new(100, 10);
+    assert_eq!(
+        builder.stride(399).build(),
+        Err(BufferError::StrideTooSmall)
+    );
+    assert_eq!(
+        builder.alignment(12).build(),
+        Err(BufferError::InvalidAlignment)
+    );
+    assert_eq!(
+        builder.stride(400).alignment(256).build(),
+        Err(BufferError::StrideMisaligned)
+    );
+    assert_eq!(
+        BufferDescriptorBuilder::new(0, 10).build(),
+        Err(BufferError::EmptyDimensions)
+    );
+}
+
+#[test]
+fn test_buffer_descriptor_builder_overflow() {
+    // width * 4 estoura u32
+    assert_eq!(
+        BufferDescriptor::builder(0x4000_0000, 1).build(),
+        Err(BufferError::Overflow)
+    );
+    // Stride mínimo cabe, mas arredondar para o alinhamento estoura
+    assert_eq!(
+        BufferDescriptor::builder(u32::MAX / 4, 1)
+            .alignment(256)
+            .build(),
+        Err(BufferError::Overflow)
+    );
+    assert_eq!(BufferError::Overflow.as_str(), "buffer size overflows");
+}
+
 // =============================================================================
 // BUFFER HANDLE TESTS
 // =============================================================================
@@ -64,6 +140,23 @@ fn test_buffer_handle_new() {
     assert_eq!(handle.generation(), 1);
 }
 
+#[test]
+fn test_buffer_handle_debug_color() {
+    let h = BufferHandle::new(7, 1);
+    assert_eq!(h.debug_color(), BufferHandle::new(7, 1).debug_color());
+    assert!(h.debug_color().is_opaque());
+    assert_ne!(h.debug_color(), BufferHandle::new(8, 1).debug_color());
+}
+
+#[test]
+fn test_buffer_handle_display() {
+    let h = BufferHandle::new(0x2A, 1);
+    assert_eq!(format!("{}", h), "Handle(0x000000010000002a)");
+    assert!(!h.is_reserved());
+    assert_eq!(format!("{}", BufferHandle::INVALID), "Handle(INVALID)");
+    assert!(BufferHandle::INVALID.is_reserved());
+}
+
 // =============================================================================
 // BUFFER REGION TESTS
 // =============================================================================
@@ -89,3 +182,1232 @@ fn test_buffer_region_contains() {
     assert!(region.contains(50, 50));
     assert!(!region.contains(5, 5));
 }
+
+// =============================================================================
+// CHANNEL TESTS
+// =============================================================================
+
+fn argb_pattern(data: &mut [u8], desc: BufferDescriptor) {
+    let mut view = BufferViewMut::new(data, desc).unwrap();
+    for y in 0..desc.height {
+        for x in 0..desc.width {
+            let a = (x * 40 + y * 10) as u8;
+            view.set_pixel(x, y, Color::argb(a, x as u8, y as u8, 0x55));
+        }
+    }
+}
+
+#[test]
+fn test_extract_alpha_channel() {
+    let desc = BufferDescriptor::new(4, 3, PixelFormat::ARGB8888);
+    let mut data = [0u8; 48];
+    argb_pattern(&mut data, desc);
+
+    let gray_desc = BufferDescriptor::new(4, 3, PixelFormat::Gray8);
+    let mut gray = [0u8; 12];
+    let mut out = BufferViewMut::new(&mut gray, gray_desc).unwrap();
+    let src = BufferView::new(&data, desc).unwrap();
+    src.extract_channel(Channel::Alpha, &mut out).unwrap();
+
+    assert_eq!(gray[0], 0);
+    assert_eq!(gray[1], 40);
+    assert_eq!(gray[4 + 2], 90);
+}
+
+#[test]
+fn test_channel_roundtrip() {
+    let desc = BufferDescriptor::new(4, 3, PixelFormat::ARGB8888);
+    let mut data = [0u8; 48];
+    argb_pattern(&mut data, desc);
+    let original = data;
+
+    let gray_desc = BufferDescriptor::new(4, 3, PixelFormat::Gray8);
+    let mut gray = [0u8; 12];
+    BufferView::new(&data, desc)
+        .unwrap()
+        .extract_channel(
+            Channel::Alpha,
+            &mut BufferViewMut::new(&mut gray, gray_desc).unwrap(),
+        )
+        .unwrap();
+
+    let mask = BufferView::new(&gray, gray_desc).unwrap();
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.set_channel(Channel::Alpha, &mask).unwrap();
+    assert_eq!(data, original);
+}
+
+#[test]
+fn test_channel_errors() {
+    let desc = BufferDescriptor::new(4, 3, PixelFormat::XRGB8888);
+    let mut data = [0u8; 48];
+    let gray = [0u8; 12];
+    let mask = BufferView::new(&gray, BufferDescriptor::new(4, 3, PixelFormat::Gray8)).unwrap();
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    assert_eq!(
+        view.set_channel(Channel::Alpha, &mask),
+        Err(ConvertError::UnsupportedFormat)
+    );
+
+    let small = BufferView::new(&gray, BufferDescriptor::new(2, 2, PixelFormat::Gray8)).unwrap();
+    assert_eq!(
+        view.set_channel(Channel::Red, &small),
+        Err(ConvertError::SizeMismatch)
+    );
+}
+
+// =============================================================================
+// HISTOGRAM TESTS
+// =============================================================================
+
+#[test]
+fn test_histogram_two_colors() {
+    let desc = BufferDescriptor::new(4, 2, PixelFormat::ARGB8888);
+    let mut data = [0u8; 32];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    for y in 0..2 {
+        for x in 0..4 {
+            let color = if x < 3 { Color::RED } else { Color::BLUE };
+            view.set_pixel(x, y, color);
+        }
+    }
+
+    let hist = view.as_view().histogram(Rect::new(0, 0, 4, 2));
+    assert_eq!(hist.total(), 8);
+    assert_eq!(hist.r[255], 6);
+    assert_eq!(hist.r[0], 2);
+    assert_eq!(hist.b[255], 2);
+    assert_eq!(hist.g[0], 8);
+}
+
+#[test]
+fn test_histogram_clipped_region() {
+    let desc = BufferDescriptor::new(4, 4, PixelFormat::Gray8);
+    let data = [200u8; 16];
+    let view = BufferView::new(&data, desc).unwrap();
+
+    let hist = view.histogram(Rect::new(2, 2, 10, 10));
+    assert_eq!(hist.total(), 4);
+    assert_eq!(hist.luma[200], 4);
+    assert_eq!(hist.r[200], 0);
+
+    let outside = view.histogram(Rect::new(10, 10, 5, 5));
+    assert_eq!(outside.total(), 0);
+}
+
+// =============================================================================
+// LEVELS TESTS
+// =============================================================================
+
+#[test]
+fn test_adjust_levels_identity() {
+    let desc = BufferDescriptor::new(4, 1, PixelFormat::ARGB8888);
+    let mut data = [0u8; 16];
+    for (i, b) in data.iter_mut().enumerate() {
+        *b = (i * 17) as u8;
+    }
+    let original = data;
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.adjust_levels(0, 255, 1.0);
+    assert_eq!(data, original);
+}
+
+#[test]
+fn test_auto_levels_stretches_gradient() {
+    let desc = BufferDescriptor::new(100, 1, PixelFormat::Gray8);
+    let mut data = [0u8; 100];
+    for (i, b) in data.iter_mut().enumerate() {
+        *b = 100 + (i as u8) / 2; // 100..=149
+    }
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.auto_levels();
+
+    let min = *data.iter().min().unwrap();
+    let max = *data.iter().max().unwrap();
+    assert!(min < 10, "min = {}", min);
+    assert!(max > 245, "max = {}", max);
+}
+
+#[test]
+fn test_apply_lut() {
+    let desc = BufferDescriptor::new(4, 1, PixelFormat::ARGB8888);
+    let mut data = [0u8; 16];
+    for (i, b) in data.iter_mut().enumerate() {
+        *b = (i * 13) as u8;
+    }
+    let original = data;
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.apply_lut(&ColorLut::identity());
+    assert_eq!(data, original);
+
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let mut lut = ColorLut::identity();
+    lut.r = [255; 256];
+    view.apply_lut(&lut);
+    assert_eq!(view.get_pixel(2, 0).unwrap().red(), 255);
+    assert_eq!(view.get_pixel(2, 0).unwrap().green(), original[10]);
+}
+
+#[test]
+fn test_histogram_percentile() {
+    let desc = BufferDescriptor::new(10, 1, PixelFormat::Gray8);
+    let data = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
+    let hist = BufferView::new(&data, desc)
+        .unwrap()
+        .histogram(Rect::new(0, 0, 10, 1));
+    assert_eq!(hist.luma_percentile(0.0), 0);
+    assert_eq!(hist.luma_percentile(0.5), 50);
+    assert_eq!(hist.luma_percentile(1.0), 90);
+}
+
+// =============================================================================
+// SUB VIEW / TILES TESTS
+// =============================================================================
+
+#[test]
+fn test_buffer_sub_view() {
+    let desc = BufferDescriptor::new(4, 4, PixelFormat::Gray8);
+    let data: [u8; 16] = core::array::from_fn(|i| i as u8);
+    let view = BufferView::new(&data, desc).unwrap();
+
+    let sub = view.sub_view(Rect::new(2, 2, 2, 2)).unwrap();
+    assert_eq!(sub.width(), 2);
+    assert_eq!(sub.row(1).unwrap(), &[14, 15]);
+    assert!(view.sub_view(Rect::new(3, 3, 2, 2)).is_none());
+}
+
+#[test]
+fn test_buffer_sub_view_data_invariant() {
+    // Stride com padding: a sub-view do canto inferior direito termina
+    // antes do padding da última linha
+    let desc = BufferDescriptor::with_stride(4, 3, 6, PixelFormat::Gray8);
+    let data: [u8; 18] = core::array::from_fn(|i| i as u8);
+    let view = BufferView::new(&data, desc).unwrap();
+
+    let sub = view.sub_view(Rect::new(2, 1, 2, 2)).unwrap();
+    let sub_desc = *sub.descriptor();
+    assert_eq!(sub_desc.min_data_len(), 8);
+    assert_eq!(sub.data().len(), sub_desc.min_data_len());
+    assert!(sub.data().len() < sub_desc.size_bytes());
+    // Reconstruir a view a partir dos dados da sub-view continua válido
+    let again = BufferView::new(sub.data(), sub_desc).unwrap();
+    assert_eq!(again.row(1).unwrap(), &[14, 15]);
+
+    // Faltando bytes da última linha: rejeitado
+    assert!(BufferView::new(&data[..15], desc).is_none());
+    assert!(BufferView::new(&data[..16], desc).is_some());
+}
+
+#[test]
+fn test_buffer_tiles_cover_buffer() {
+    let desc = BufferDescriptor::new(10, 7, PixelFormat::ARGB8888);
+    let data = [0u8; 10 * 7 * 4];
+    let view = BufferView::new(&data, desc).unwrap();
+
+    let mut area = 0u64;
+    let mut bounds = Rect::ZERO;
+    let mut count = 0;
+    for (rect, sub) in view.tiles(Size::new(4, 4)) {
+        assert_eq!(sub.width(), rect.width);
+        assert_eq!(sub.height(), rect.height);
+        area += rect.area();
+        bounds = bounds.union(&rect);
+        count += 1;
+    }
+    assert_eq!(count, 6);
+    assert_eq!(area, 70);
+    assert_eq!(bounds, desc.rect());
+}
+
+#[test]
+fn test_buffer_tiles_edge_trimmed() {
+    let desc = BufferDescriptor::new(10, 7, PixelFormat::Gray8);
+    let data = [0u8; 70];
+    let view = BufferView::new(&data, desc).unwrap();
+
+    let last = view.tiles(Size::new(4, 4)).last().unwrap().0;
+    assert_eq!(last, Rect::new(8, 4, 2, 3));
+}
+
+// =============================================================================
+// RASTER TESTS
+// =============================================================================
+
+#[test]
+fn test_rasterize_rounded_rect() {
+    let desc = BufferDescriptor::new(20, 20, PixelFormat::ARGB8888);
+    let mut data = [0u8; 20 * 20 * 4];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let bg = Color::BLACK;
+    for y in 0..20 {
+        for x in 0..20 {
+            view.set_pixel(x, y, bg);
+        }
+    }
+
+    let rr = RoundedRect::from_coords(2.0, 2.0, 16.0, 16.0, 6.0);
+    view.rasterize_rounded_rect(rr, Color::WHITE);
+
+    // Interior: cor cheia
+    assert_eq!(view.get_pixel(10, 10), Some(Color::WHITE));
+    assert_eq!(view.get_pixel(2, 10), Some(Color::WHITE));
+    // Exterior: intocado
+    assert_eq!(view.get_pixel(0, 0), Some(bg));
+    assert_eq!(view.get_pixel(19, 10), Some(bg));
+    // Canto (fora do arco)
+    assert_eq!(view.get_pixel(2, 2), Some(bg));
+    // Pixel na borda do arco: valor intermediário
+    let edge = view.get_pixel(3, 3).unwrap();
+    assert!(edge.red() > 0 && edge.red() < 255, "edge = {:?}", edge);
+}
+
+// =============================================================================
+// SWIZZLE TESTS
+// =============================================================================
+
+#[test]
+fn test_swap_rb_inplace() {
+    for format in [
+        PixelFormat::ARGB8888,
+        PixelFormat::BGRA8888,
+        PixelFormat::RGBA8888,
+    ] {
+        let desc = BufferDescriptor::new(2, 2, format);
+        let mut data = [0u8; 16];
+        let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+        view.set_pixel(0, 0, Color::argb(0x80, 0x11, 0x22, 0x33));
+        view.set_pixel(1, 1, Color::RED);
+
+        view.swap_rb_inplace();
+        assert_eq!(
+            view.get_pixel(0, 0),
+            Some(Color::argb(0x80, 0x33, 0x22, 0x11))
+        );
+        assert_eq!(view.get_pixel(1, 1), Some(Color::BLUE));
+
+        view.swap_rb_inplace();
+        assert_eq!(
+            view.get_pixel(0, 0),
+            Some(Color::argb(0x80, 0x11, 0x22, 0x33))
+        );
+    }
+}
+
+#[test]
+fn test_swap_rb_noop_rgb565() {
+    let desc = BufferDescriptor::new(2, 2, PixelFormat::RGB565);
+    let mut data = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    let original = data;
+    BufferViewMut::new(&mut data, desc)
+        .unwrap()
+        .swap_rb_inplace();
+    assert_eq!(data, original);
+}
+
+// =============================================================================
+// DIFF TESTS
+// =============================================================================
+
+#[test]
+fn test_buffer_diff_single_pixel() {
+    let desc = BufferDescriptor::new(32, 32, PixelFormat::Gray8);
+    let prev = [0u8; 32 * 32];
+    let mut next = prev;
+    next[20 * 32 + 5] = 0xFF; // pixel (5, 20)
+
+    let mut damage = DamageBuffer::new();
+    let a = BufferView::new(&next, desc).unwrap();
+    let b = BufferView::new(&prev, desc).unwrap();
+    a.diff(&b, &mut damage, Size::new(8, 8));
+    assert_eq!(damage.len(), 1);
+    assert_eq!(damage.get(0).unwrap().rect, Rect::new(0, 16, 8, 8));
+}
+
+#[test]
+fn test_buffer_diff_identical_and_coalesced() {
+    let desc = BufferDescriptor::new(32, 32, PixelFormat::Gray8);
+    let prev = [0u8; 32 * 32];
+    let mut damage = DamageBuffer::new();
+    let view = BufferView::new(&prev, desc).unwrap();
+    view.diff(&view, &mut damage, Size::new(8, 8));
+    assert!(damage.is_empty());
+
+    // Bloco 2x2 de tiles alterado vira uma única região
+    let mut next = prev;
+    for y in 8..24 {
+        for x in 8..24 {
+            next[y * 32 + x] = 1;
+        }
+    }
+    BufferView::new(&next, desc)
+        .unwrap()
+        .diff(&view, &mut damage, Size::new(8, 8));
+    assert_eq!(damage.len(), 1);
+    assert_eq!(damage.get(0).unwrap().rect, Rect::new(8, 8, 16, 16));
+}
+
+#[test]
+fn test_buffer_diff_mismatched() {
+    let a = [0u8; 16];
+    let b = [1u8; 16];
+    let va = BufferView::new(&a, BufferDescriptor::new(4, 4, PixelFormat::Gray8)).unwrap();
+    let vb = BufferView::new(&b, BufferDescriptor::new(2, 8, PixelFormat::Gray8)).unwrap();
+    let mut damage = DamageBuffer::new();
+    va.diff(&vb, &mut damage, Size::new(2, 2));
+    assert!(damage.is_empty());
+}
+
+// =============================================================================
+// REPACK TESTS
+// =============================================================================
+
+#[test]
+fn test_descriptor_tightly_packed() {
+    let tight = BufferDescriptor::new(10, 4, PixelFormat::RGB888);
+    assert!(tight.is_tightly_packed());
+
+    let padded = BufferDescriptor::with_stride(10, 4, 32, PixelFormat::RGB888);
+    assert!(!padded.is_tightly_packed());
+    assert_eq!(padded.normalize_stride(), tight);
+}
+
+#[test]
+fn test_repack_tight() {
+    let padded = BufferDescriptor::with_stride(3, 2, 8, PixelFormat::Gray8);
+    let src = [
+        1, 2, 3, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 4, 5, 6, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE,
+    ];
+    let src = BufferView::new(&src, padded).unwrap();
+
+    let mut out = [0u8; 6];
+    let mut dst = BufferViewMut::new(&mut out, padded.normalize_stride()).unwrap();
+    dst.repack_tight(&src).unwrap();
+    assert_eq!(out, [1, 2, 3, 4, 5, 6]);
+
+    let mut bad = [0u8; 16];
+    let mut dst = BufferViewMut::new(&mut bad, padded).unwrap();
+    assert_eq!(dst.repack_tight(&src), Err(ConvertError::SizeMismatch));
+}
+
+// =============================================================================
+// CLEAR TESTS
+// =============================================================================
+
+#[test]
+fn test_clear_color_respects_padding() {
+    // 3 pixels ARGB (12 bytes) + 4 bytes de padding por linha
+    let desc = BufferDescriptor::with_stride(3, 2, 16, PixelFormat::ARGB8888);
+    let mut data = [0xAAu8; 32];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.clear_color(Color::RED);
+    for y in 0..2 {
+        for x in 0..3 {
+            assert_eq!(view.get_pixel(x, y), Some(Color::RED));
+        }
+    }
+    assert_eq!(&data[12..16], &[0xAA; 4]);
+    assert_eq!(&data[28..32], &[0xAA; 4]);
+}
+
+#[test]
+fn test_clear_color_general_path() {
+    let desc = BufferDescriptor::with_stride(3, 2, 10, PixelFormat::RGB888);
+    let mut data = [0xAAu8; 20];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.clear_color(Color::RED);
+    for y in 0..2 {
+        for x in 0..3 {
+            assert_eq!(view.get_pixel(x, y), Some(Color::RED));
+        }
+    }
+    assert_eq!(data[9], 0xAA);
+    assert_eq!(data[19], 0xAA);
+
+    let desc = BufferDescriptor::new(4, 1, PixelFormat::RGB565);
+    let mut data = [0u8; 8];
+    BufferViewMut::new(&mut data, desc)
+        .unwrap()
+        .clear_color(Color::RED);
+    assert_eq!(data, [0x00, 0xF8, 0x00, 0xF8, 0x00, 0xF8, 0x00, 0xF8]);
+}
+
+#[test]
+fn test_clear_color_aligned_and_unaligned() {
+    // Mesmo resultado pelo caminho de palavras (alinhado) e pelo fallback
+    let desc = BufferDescriptor::new(4, 2, PixelFormat::ARGB8888);
+    let mut storage = Aligned([0u8; 36]);
+
+    BufferViewMut::new(&mut storage.0[..32], desc)
+        .unwrap()
+        .clear_color(Color::GREEN);
+    let aligned = storage.0;
+
+    storage.0 = [0; 36];
+    BufferViewMut::new(&mut storage.0[1..33], desc)
+        .unwrap()
+        .clear_color(Color::GREEN);
+    assert_eq!(&storage.0[1..33], &aligned[..32]);
+    assert_eq!(storage.0[0], 0);
+    assert_eq!(
+        BufferView::new(&aligned, desc).unwrap().get_pixel(3, 1),
+        Some(Color::GREEN)
+    );
+}
+
+// =============================================================================
+// CROP TESTS
+// =============================================================================
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_crop_to_vec() {
+    // Stride com padding para garantir que o recorte sai compacto
+    let desc = BufferDescriptor::with_stride(6, 4, 32, PixelFormat::ARGB8888);
+    let mut data = [0u8; 128];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    for y in 0..4 {
+        for x in 0..6 {
+            view.set_pixel(x, y, Color::rgb(x as u8 * 10, y as u8 * 10, 7));
+        }
+    }
+
+    let (pixels, crop) = view.as_view().crop_to_vec(Rect::new(2, 1, 3, 2)).unwrap();
+    assert_eq!((crop.width, crop.height), (3, 2));
+    assert_eq!(crop.stride, 12);
+    assert!(crop.is_tightly_packed());
+    assert_eq!(pixels.len(), 24);
+
+    let cropped = BufferView::new(&pixels, crop).unwrap();
+    for y in 0..2 {
+        for x in 0..3 {
+            assert_eq!(
+                cropped.get_pixel(x, y),
+                view.get_pixel(x + 2, y + 1),
+                "({}, {})",
+                x,
+                y
+            );
+        }
+    }
+
+    // Fora dos limites ou vazio
+    assert!(view.as_view().crop_to_vec(Rect::new(4, 0, 3, 1)).is_none());
+    assert!(view.as_view().crop_to_vec(Rect::new(0, 0, 0, 1)).is_none());
+}
+
+// =============================================================================
+// RLE TESTS
+// =============================================================================
+
+#[test]
+fn test_rle_solid_row() {
+    let row = [0x11u8, 0x22, 0x33, 0xFF].repeat(300);
+    let mut packed = [0u8; 64];
+    let len = encode_row_rle(&row, 4, &mut packed).unwrap();
+    // 300 pixels = blocos de 255 + 45
+    assert_eq!(len, 10);
+    assert!(row.len() / len > 100);
+
+    let mut unpacked = vec![0u8; row.len()];
+    assert_eq!(
+        decode_row_rle(&packed[..len], 4, &mut unpacked),
+        Some(row.len())
+    );
+    assert_eq!(unpacked, row);
+}
+
+#[test]
+fn test_rle_noisy_row_expands() {
+    let row: Vec<u8> = (0..64u8).collect();
+    // Pior caso: um byte de contagem por pixel
+    let mut small = [0u8; 64];
+    assert_eq!(encode_row_rle(&row, 2, &mut small), None);
+
+    let mut packed = [0u8; 96];
+    let len = encode_row_rle(&row, 2, &mut packed).unwrap();
+    assert_eq!(len, 96);
+
+    let mut unpacked = [0u8; 64];
+    assert_eq!(decode_row_rle(&packed[..len], 2, &mut unpacked), Some(64));
+    assert_eq!(&unpacked[..], &row[..]);
+}
+
+#[test]
+fn test_rle_invalid_input() {
+    let mut out = [0u8; 16];
+    assert_eq!(encode_row_rle(&[1, 2, 3], 2, &mut out), None);
+    assert_eq!(encode_row_rle(&[1, 2], 0, &mut out), None);
+    assert_eq!(encode_row_rle(&[], 4, &mut out), Some(0));
+    // Contagem zero e bloco truncado
+    assert_eq!(decode_row_rle(&[0, 7], 1, &mut out), None);
+    assert_eq!(decode_row_rle(&[3, 7, 1, 8], 1, &mut out), Some(4));
+    assert_eq!(decode_row_rle(&[3, 7, 8], 1, &mut out), None);
+    // Saída pequena demais
+    assert_eq!(decode_row_rle(&[200, 1], 1, &mut out), None);
+}
+
+// =============================================================================
+// WORD VIEW TESTS
+// =============================================================================
+
+#[repr(align(4))]
+struct Aligned<const N: usize>([u8; N]);
+
+#[test]
+fn test_as_u32_slice_tight_argb() {
+    let desc = BufferDescriptor::new(4, 2, PixelFormat::ARGB8888);
+    let mut storage = Aligned([0u8; 32]);
+    {
+        let mut view = BufferViewMut::new(&mut storage.0, desc).unwrap();
+        view.set_pixel(1, 0, Color::RED);
+        let words = view.as_u32_slice_mut().unwrap();
+        assert_eq!(words.len(), 8);
+        words[7] = u32::from_le_bytes([0xFF, 0x00, 0x00, 0xFF]);
+    }
+    let view = BufferView::new(&storage.0, desc).unwrap();
+    let words = view.as_u32_slice().unwrap();
+    assert_eq!(words.len(), 8);
+    assert_eq!(words[1], u32::from_le_bytes([0xFF, 0xFF, 0x00, 0x00]));
+    assert_eq!(view.get_pixel(3, 1), Some(Color::BLUE));
+}
+
+#[test]
+fn test_as_u32_slice_rejects_unsuitable() {
+    let mut storage = Aligned([0u8; 64]);
+
+    let padded = BufferDescriptor::with_stride(3, 2, 16, PixelFormat::ARGB8888);
+    assert!(BufferView::new(&storage.0, padded)
+        .unwrap()
+        .as_u32_slice()
+        .is_none());
+
+    let rgb565 = BufferDescriptor::new(4, 2, PixelFormat::RGB565);
+    assert!(BufferViewMut::new(&mut storage.0, rgb565)
+        .unwrap()
+        .as_u32_slice_mut()
+        .is_none());
+
+    // Dados desalinhados
+    let tight = BufferDescriptor::new(2, 2, PixelFormat::XRGB8888);
+    assert!(BufferView::new(&storage.0[1..], tight)
+        .unwrap()
+        .as_u32_slice()
+        .is_none());
+}
+
+// =============================================================================
+// DESATURATE TESTS
+// =============================================================================
+
+#[test]
+fn test_desaturate_linear_vs_gamma() {
+    let desc = BufferDescriptor::new(2, 1, PixelFormat::ARGB8888);
+    let mut gamma = [0u8; 8];
+    let mut view = BufferViewMut::new(&mut gamma, desc).unwrap();
+    view.set_pixel(0, 0, Color::rgb(0, 255, 0));
+    view.set_pixel(1, 0, Color::argb(0x80, 90, 90, 90));
+    let mut linear = gamma;
+
+    BufferViewMut::new(&mut gamma, desc)
+        .unwrap()
+        .desaturate(false);
+    BufferViewMut::new(&mut linear, desc)
+        .unwrap()
+        .desaturate(true);
+
+    let gamma = BufferView::new(&gamma, desc).unwrap();
+    let linear = BufferView::new(&linear, desc).unwrap();
+    assert_eq!(
+        gamma.get_pixel(0, 0),
+        Some(Color::rgb(0, 255, 0).to_grayscale())
+    );
+    assert_eq!(linear.get_pixel(0, 0), Some(Color::gray(220)));
+    // Cinzas (com alpha) são preservados nos dois modos
+    assert_eq!(gamma.get_pixel(1, 0), Some(Color::argb(0x80, 90, 90, 90)));
+    assert_eq!(linear.get_pixel(1, 0), Some(Color::argb(0x80, 90, 90, 90)));
+}
+
+// =============================================================================
+// DOWNSCALE TESTS
+// =============================================================================
+
+#[test]
+fn test_downscale_box_two_colors() {
+    let src_desc = BufferDescriptor::new(4, 4, PixelFormat::ARGB8888);
+    let mut src = [0u8; 64];
+    let mut view = BufferViewMut::new(&mut src, src_desc).unwrap();
+    for y in 0..4 {
+        for x in 0..4 {
+            // Metade superior: xadrez preto/branco; inferior: vermelho
+            let c = if y >= 2 {
+                Color::RED
+            } else if (x + y) % 2 == 0 {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            };
+            view.set_pixel(x, y, c);
+        }
+    }
+
+    let dst_desc = BufferDescriptor::new(2, 2, PixelFormat::ARGB8888);
+    let mut dst = [0u8; 16];
+    let mut out = BufferViewMut::new(&mut dst, dst_desc).unwrap();
+    out.downscale_box(&BufferView::new(&src, src_desc).unwrap())
+        .unwrap();
+
+    // Média de 50% em luz linear é ~188 em sRGB (não 128)
+    assert_eq!(out.get_pixel(0, 0), Some(Color::gray(188)));
+    assert_eq!(out.get_pixel(1, 0), Some(Color::gray(188)));
+    assert_eq!(out.get_pixel(0, 1), Some(Color::RED));
+    assert_eq!(out.get_pixel(1, 1), Some(Color::RED));
+}
+
+#[test]
+fn test_downscale_box_alpha_edge() {
+    let src_desc = BufferDescriptor::new(2, 1, PixelFormat::ARGB8888);
+    let mut src = [0u8; 8];
+    let mut view = BufferViewMut::new(&mut src, src_desc).unwrap();
+    view.set_pixel(0, 0, Color::RED);
+    view.set_pixel(1, 0, Color::TRANSPARENT);
+
+    let dst_desc = BufferDescriptor::new(1, 1, PixelFormat::ARGB8888);
+    let mut dst = [0u8; 4];
+    let mut out = BufferViewMut::new(&mut dst, dst_desc).unwrap();
+    out.downscale_box(&BufferView::new(&src, src_desc).unwrap())
+        .unwrap();
+    // Cor preservada, só a cobertura cai pela metade
+    assert_eq!(out.get_pixel(0, 0), Some(Color::argb(128, 255, 0, 0)));
+}
+
+#[test]
+fn test_downscale_box_fractional_and_empty() {
+    // 3 -> 2: o pixel do meio contribui para os dois destinos
+    let src_desc = BufferDescriptor::new(3, 1, PixelFormat::Gray8);
+    let src = [0u8, 255, 0];
+    let dst_desc = BufferDescriptor::new(2, 1, PixelFormat::Gray8);
+    let mut dst = [0u8; 2];
+    let mut out = BufferViewMut::new(&mut dst, dst_desc).unwrap();
+    out.downscale_box(&BufferView::new(&src, src_desc).unwrap())
+        .unwrap();
+    assert_eq!(dst[0], dst[1]);
+    assert!(dst[0] > 0 && dst[0] < 255);
+
+    let empty = BufferDescriptor::new(0, 0, PixelFormat::Gray8);
+    let mut out = BufferViewMut::new(&mut dst, dst_desc).unwrap();
+    assert_eq!(
+        out.downscale_box(&BufferView::new(&[], empty).unwrap()),
+        Err(ConvertError::SizeMismatch)
+    );
+}
+
+// =============================================================================
+// ROW CHECKSUM TESTS
+// =============================================================================
+
+#[test]
+fn test_row_checksums_one_row_changed() {
+    // Stride com padding: o lixo no padding não deve afetar o checksum
+    let desc = BufferDescriptor::with_stride(4, 4, 20, PixelFormat::ARGB8888);
+    let mut data = [0u8; 80];
+    let mut prev = [0u32; 4];
+    assert_eq!(
+        BufferView::new(&data, desc)
+            .unwrap()
+            .row_checksums(&mut prev),
+        4
+    );
+
+    data[2 * 20 + 5] = 0xAB;
+    data[20 + 17] = 0xFF; // padding da linha 1
+    let mut cur = [0u32; 4];
+    BufferView::new(&data, desc)
+        .unwrap()
+        .row_checksums(&mut cur);
+
+    let flipped = prev.iter().zip(&cur).filter(|(a, b)| a != b).count();
+    assert_eq!(flipped, 1);
+
+    let mut out = [0u32; 4];
+    assert_eq!(changed_rows(&prev, &cur, &mut out), 1);
+    assert_eq!(out[0], 2);
+}
+
+#[test]
+fn test_changed_rows_length_mismatch() {
+    let mut out = [0u32; 4];
+    assert_eq!(changed_rows(&[1, 2], &[1, 2, 3], &mut out), 1);
+    assert_eq!(out[0], 2);
+    assert_eq!(changed_rows(&[1, 2], &[1, 2], &mut out), 0);
+}
+
+// =============================================================================
+// CLEAR REGIONS TESTS
+// =============================================================================
+
+#[test]
+fn test_clear_regions_only_damage() {
+    let desc = BufferDescriptor::new(8, 8, PixelFormat::RGB565);
+    let mut data = [0u8; 128];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.clear_color(Color::BLACK);
+
+    let mut damage = DamageBuffer::new();
+    damage.push(Rect::new(1, 1, 3, 3));
+    // Sobreposta à primeira e parcialmente fora do buffer
+    damage.push(Rect::new(2, 2, 3, 3));
+    damage.push(Rect::new(6, 6, 10, 10));
+    clear_regions(&damage, &mut view, Color::WHITE);
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let inside = damage
+                .iter()
+                .any(|r| r.rect.contains_point(Point::new(x as i32, y as i32)));
+            let expected = if inside { Color::WHITE } else { Color::BLACK };
+            assert_eq!(view.get_pixel(x, y), Some(expected), "({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_fill_rect_clipped() {
+    let desc = BufferDescriptor::new(4, 2, PixelFormat::ARGB8888);
+    let mut data = [0u8; 32];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.fill_rect(Rect::new(-2, -2, 4, 3), Color::RED);
+    assert_eq!(view.get_pixel(0, 0), Some(Color::RED));
+    assert_eq!(view.get_pixel(1, 0), Some(Color::RED));
+    assert_eq!(view.get_pixel(2, 0), Some(Color::TRANSPARENT));
+    assert_eq!(view.get_pixel(0, 1), Some(Color::TRANSPARENT));
+    // Totalmente fora: nada muda
+    view.fill_rect(Rect::new(10, 10, 4, 4), Color::RED);
+    assert_eq!(view.get_pixel(3, 1), Some(Color::TRANSPARENT));
+}
+
+#[test]
+fn test_fill_rect_word_path_and_odd_stride() {
+    // Stride 14: a linha 0 é alinhada (caminho de palavras), a linha 1 não
+    let desc = BufferDescriptor::with_stride(3, 2, 14, PixelFormat::ARGB8888);
+    let mut storage = Aligned([0xAAu8; 28]);
+    let mut view = BufferViewMut::new(&mut storage.0, desc).unwrap();
+    view.fill_rect(Rect::new(1, 0, 2, 2), Color::RED);
+    for y in 0..2 {
+        assert_eq!(
+            view.get_pixel(0, y),
+            Some(Color::from_argb_bytes([0xAA; 4]))
+        );
+        assert_eq!(view.get_pixel(1, y), Some(Color::RED));
+        assert_eq!(view.get_pixel(2, y), Some(Color::RED));
+    }
+    // Padding intocado
+    assert_eq!(&storage.0[12..14], &[0xAA; 2]);
+    assert_eq!(&storage.0[26..28], &[0xAA; 2]);
+}
+
+#[test]
+fn test_fill_rect_blended_source_over() {
+    let desc = BufferDescriptor::new(4, 4, PixelFormat::ARGB8888);
+    let mut data = [0u8; 64];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.clear_color(Color::BLUE);
+
+    let red = Color::argb(128, 255, 0, 0);
+    let params = FillParams::new(Rect::new(1, 1, 2, 2), red).with_blend(BlendMode::SourceOver);
+    view.fill_rect_blended(&params);
+
+    let purple = view.get_pixel(1, 1).unwrap();
+    assert_eq!(purple.alpha(), 255);
+    assert!(purple.red().abs_diff(128) <= 1);
+    assert_eq!(purple.green(), 0);
+    assert!(purple.blue().abs_diff(127) <= 1);
+    assert_eq!(view.get_pixel(2, 2), Some(purple));
+    // Fora do retângulo: inalterado
+    assert_eq!(view.get_pixel(0, 0), Some(Color::BLUE));
+    assert_eq!(view.get_pixel(3, 3), Some(Color::BLUE));
+}
+
+#[test]
+fn test_fill_rect_blended_clear() {
+    let desc = BufferDescriptor::new(4, 4, PixelFormat::ARGB8888);
+    let mut data = [0u8; 64];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.clear_color(Color::BLUE);
+
+    let params = FillParams::new(Rect::new(0, 0, 2, 4), Color::RED).with_blend(BlendMode::Clear);
+    view.fill_rect_blended(&params);
+    assert_eq!(view.get_pixel(1, 3).unwrap().alpha(), 0);
+    assert_eq!(view.get_pixel(2, 0), Some(Color::BLUE));
+}
+
+// =============================================================================
+// MASK TESTS
+// =============================================================================
+
+#[test]
+fn test_apply_mask_radial_gradient() {
+    const N: u32 = 9;
+    let desc = BufferDescriptor::new(N, N, PixelFormat::ARGB8888);
+    let mut data = [0u8; (N * N * 4) as usize];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let fill = Color::argb(200, 10, 120, 240);
+    view.clear_color(fill);
+
+    // Gradiente circular: 255 no centro, 0 a partir do raio 4
+    let mask_desc = BufferDescriptor::new(N, N, PixelFormat::Gray8);
+    let mut mask = [0u8; (N * N) as usize];
+    for y in 0..N {
+        for x in 0..N {
+            let dx = x as f32 - 4.0;
+            let dy = y as f32 - 4.0;
+            let d = (dx * dx + dy * dy).sqrt() / 4.0;
+            mask[(y * N + x) as usize] = ((1.0 - d).max(0.0) * 255.0) as u8;
+        }
+    }
+    view.apply_mask(&BufferView::new(&mask, mask_desc).unwrap())
+        .unwrap();
+
+    for y in 0..N {
+        for x in 0..N {
+            let m = mask[(y * N + x) as usize] as u32;
+            let px = view.get_pixel(x, y).unwrap();
+            assert_eq!(px.alpha() as u32, (200 * m + 127) / 255);
+            assert_eq!(px.to_rgb(), fill.to_rgb());
+        }
+    }
+    assert_eq!(view.get_pixel(4, 4).unwrap().alpha(), 200);
+    assert_eq!(view.get_pixel(0, 0).unwrap().alpha(), 0);
+    assert!(view.get_pixel(4, 2).unwrap().alpha() > view.get_pixel(4, 1).unwrap().alpha());
+}
+
+#[test]
+fn test_apply_mask_errors() {
+    let mask_desc = BufferDescriptor::new(2, 2, PixelFormat::Alpha8);
+    let mask = [255u8; 4];
+    let mask = BufferView::new(&mask, mask_desc).unwrap();
+
+    let mut data = [0u8; 16];
+    let desc = BufferDescriptor::new(2, 2, PixelFormat::XRGB8888);
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    assert_eq!(view.apply_mask(&mask), Err(ConvertError::UnsupportedFormat));
+
+    let desc = BufferDescriptor::new(4, 1, PixelFormat::ARGB8888);
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    assert_eq!(view.apply_mask(&mask), Err(ConvertError::SizeMismatch));
+
+    let desc = BufferDescriptor::new(2, 2, PixelFormat::ARGB8888);
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let rgb_mask = BufferView::new(&[0u8; 16], desc).unwrap();
+    assert_eq!(
+        view.apply_mask(&rgb_mask),
+        Err(ConvertError::UnsupportedFormat)
+    );
+}
+
+// =============================================================================
+// PLANAR TESTS
+// =============================================================================
+
+#[test]
+fn test_deinterleave_round_trip() {
+    let desc = BufferDescriptor::new(3, 2, PixelFormat::ARGB8888);
+    let mut data = [0u8; 24];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    for y in 0..2 {
+        for x in 0..3 {
+            let i = (y * 3 + x) as u8;
+            view.set_pixel(x, y, Color::argb(0x80 + i, i, 0x10 + i, 0x20 + i));
+        }
+    }
+    let original = data;
+
+    let (mut r, mut g, mut b, mut a) = ([0u8; 6], [0u8; 6], [0u8; 6], [0u8; 6]);
+    BufferView::new(&data, desc)
+        .unwrap()
+        .deinterleave(&mut [&mut r, &mut g, &mut b, &mut a])
+        .unwrap();
+    assert_eq!(r, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(g[5], 0x15);
+    assert_eq!(b[0], 0x20);
+    assert_eq!(a, [0x80, 0x81, 0x82, 0x83, 0x84, 0x85]);
+
+    let mut rebuilt = [0u8; 24];
+    BufferViewMut::new(&mut rebuilt, desc)
+        .unwrap()
+        .interleave_from(&[&r, &g, &b, &a])
+        .unwrap();
+    assert_eq!(rebuilt, original);
+}
+
+#[test]
+fn test_deinterleave_validation() {
+    let desc = BufferDescriptor::new(2, 2, PixelFormat::ARGB8888);
+    let data = [0u8; 16];
+    let view = BufferView::new(&data, desc).unwrap();
+    let mut small = [0u8; 3];
+    assert_eq!(
+        view.deinterleave(&mut [&mut small]),
+        Err(ConvertError::SizeMismatch)
+    );
+    assert_eq!(view.deinterleave(&mut []), Err(ConvertError::SizeMismatch));
+
+    let gray = BufferDescriptor::new(2, 2, PixelFormat::Gray8);
+    let mut plane = [0u8; 4];
+    assert_eq!(
+        BufferView::new(&data, gray)
+            .unwrap()
+            .deinterleave(&mut [&mut plane]),
+        Err(ConvertError::UnsupportedFormat)
+    );
+
+    let mut out = [0u8; 16];
+    let p = [0u8; 4];
+    assert_eq!(
+        BufferViewMut::new(&mut out, desc)
+            .unwrap()
+            .interleave_from(&[&p, &p, &p, &p, &p]),
+        Err(ConvertError::SizeMismatch)
+    );
+}
+
+// =============================================================================
+// POLYGON FILL TESTS
+// =============================================================================
+
+fn diamond() -> StaticPolygon {
+    StaticPolygon::quad(
+        PointF::new(8.0, 2.0),
+        PointF::new(14.0, 8.0),
+        PointF::new(8.0, 14.0),
+        PointF::new(2.0, 8.0),
+    )
+}
+
+fn alphas(data: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    data.chunks_exact(4).map(|px| px[0])
+}
+
+#[test]
+fn test_fill_polygon_aa_vs_hard() {
+    let desc = BufferDescriptor::new(16, 16, PixelFormat::ARGB8888);
+
+    let mut hard = [0u8; 1024];
+    BufferViewMut::new(&mut hard, desc).unwrap().fill_polygon(
+        &diamond(),
+        Color::WHITE,
+        FillRule::NonZero,
+    );
+    assert!(alphas(&hard).all(|a| a == 0 || a == 255));
+
+    let mut aa = [0u8; 1024];
+    let mut view = BufferViewMut::new(&mut aa, desc).unwrap();
+    view.fill_polygon_aa(&diamond(), Color::WHITE, FillRule::NonZero);
+    // Interior totalmente coberto, fora intocado
+    assert_eq!(view.get_pixel(8, 8), Some(Color::WHITE));
+    assert_eq!(view.get_pixel(0, 0), Some(Color::TRANSPARENT));
+    // Pixels na borda diagonal têm alpha intermediário
+    let edge = view.get_pixel(4, 5).unwrap().alpha();
+    assert!(edge > 0 && edge < 255, "{}", edge);
+    assert!(alphas(&aa).filter(|&a| a > 0 && a < 255).count() > 8);
+
+    // Área total próxima da analítica (2 * 6^2 = 72 pixels)
+    let area: f32 = alphas(&aa).map(|a| a as f32 / 255.0).sum();
+    assert!((area - 72.0).abs() < 1.0, "{}", area);
+}
+
+#[test]
+fn test_fill_polygon_rules() {
+    // Pentagrama: o pentágono central tem winding 2
+    let mut star = StaticPolygon::new();
+    for i in 0..5 {
+        let angle = -core::f32::consts::FRAC_PI_2 + i as f32 * 4.0 * core::f32::consts::PI / 5.0;
+        star.push(PointF::new(
+            8.0 + 7.0 * angle.cos(),
+            8.0 + 7.0 * angle.sin(),
+        ));
+    }
+    let desc = BufferDescriptor::new(16, 16, PixelFormat::ARGB8888);
+
+    let mut data = [0u8; 1024];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.fill_polygon(&star, Color::RED, FillRule::NonZero);
+    assert_eq!(view.get_pixel(8, 8), Some(Color::RED));
+
+    let mut data = [0u8; 1024];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    view.fill_polygon_aa(&star, Color::RED, FillRule::EvenOdd);
+    assert_eq!(view.get_pixel(8, 8), Some(Color::TRANSPARENT));
+    // Pontas continuam preenchidas
+    assert_eq!(view.get_pixel(8, 4).unwrap().alpha(), 255);
+}
+
+// =============================================================================
+// GRADIENT FILL TESTS
+// =============================================================================
+
+#[test]
+fn test_fill_gradient_rect_linear() {
+    let desc = BufferDescriptor::new(10, 2, PixelFormat::ARGB8888);
+    let mut data = [0u8; 80];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let ramp = ColorRamp::from_stops(&[(0.0, Color::RED), (1.0, Color::BLUE)]).unwrap();
+    // Do centro da primeira coluna ao centro da última
+    let gradient = Gradient::linear(PointF::new(0.5, 0.0), PointF::new(9.5, 0.0), ramp);
+    view.fill_gradient_rect(Rect::new(-5, 0, 100, 100), &gradient);
+
+    for y in 0..2 {
+        assert_eq!(view.get_pixel(0, y), Some(Color::RED));
+        assert_eq!(view.get_pixel(9, y), Some(Color::BLUE));
+    }
+    // Meio interpolado em luz linear
+    let mid = view.get_pixel(5, 0).unwrap();
+    assert!(mid.red() > 128 && mid.blue() > 128, "{:?}", mid);
+}
+
+#[test]
+fn test_fill_gradient_rect_radial() {
+    let desc = BufferDescriptor::new(11, 11, PixelFormat::Gray8);
+    let mut data = [0u8; 121];
+    let mut view = BufferViewMut::new(&mut data, desc).unwrap();
+    let ramp = ColorRamp::from_stops(&[(0.0, Color::WHITE), (1.0, Color::BLACK)]).unwrap();
+    let gradient = Gradient::radial(PointF::new(5.5, 5.5), 5.0, ramp);
+    view.fill_gradient_rect(Rect::new(0, 0, 11, 11), &gradient);
+
+    let brightest = data.iter().copied().max().unwrap();
+    assert_eq!(data[5 * 11 + 5], brightest);
+    assert_eq!(brightest, 255);
+    assert!(data[5 * 11 + 3] > data[5 * 11 + 1]);
+    assert_eq!(data[0], 0);
+
+    // Geometria degenerada usa a primeira parada
+    let flat = Gradient::radial(PointF::new(0.0, 0.0), 0.0, ramp);
+    assert_eq!(flat.sample(PointF::new(3.0, 3.0)), Color::WHITE);
+}
+
+// =============================================================================
+// BUFFER AGE TESTS
+// =============================================================================
+
+#[test]
+fn test_buffer_age_triple_buffering() {
+    let buffers = [
+        BufferHandle::new(1, 0),
+        BufferHandle::new(2, 0),
+        BufferHandle::new(3, 0),
+    ];
+    let mut ages = BufferAge::new();
+    // Buffers novos: conteúdo indefinido
+    assert_eq!(ages.age_of(buffers[0]), None);
+
+    for frame in 0..9 {
+        let next = buffers[frame % 3];
+        if frame >= 3 {
+            // O buffer reutilizado foi apresentado 3 commits atrás
+            assert_eq!(ages.age_of(next), Some(2));
+        }
+        ages.commit(next);
+        assert_eq!(ages.age_of(next), Some(0));
+        if frame >= 2 {
+            assert_eq!(ages.age_of(buffers[(frame + 2) % 3]), Some(1));
+            assert_eq!(ages.age_of(buffers[(frame + 1) % 3]), Some(2));
+        }
+    }
+}
+
+#[test]
+fn test_buffer_age_history_limit() {
+    let mut ages = BufferAge::new();
+    for i in 1..=(MAX_BUFFER_AGE as u32 + 2) {
+        ages.commit(BufferHandle::new(i, 0));
+    }
+    assert_eq!(ages.len(), MAX_BUFFER_AGE);
+    assert_eq!(ages.age_of(BufferHandle::new(1, 0)), None);
+    assert_eq!(
+        ages.age_of(BufferHandle::new(3, 0)),
+        Some(MAX_BUFFER_AGE as u32 - 1)
+    );
+
+    ages.commit(BufferHandle::INVALID);
+    assert_eq!(ages.age_of(BufferHandle::INVALID), None);
+    ages.clear();
+    assert!(ages.is_empty());
+}
+
+// =============================================================================
+// FORMAT CONVERSION TESTS
+// =============================================================================
+
+/// Cores exatamente representáveis nos dois formatos.
+fn conversion_samples(from: PixelFormat, to: PixelFormat) -> [Color; 3] {
+    if from == PixelFormat::Alpha8 || to == PixelFormat::Alpha8 {
+        [Color::TRANSPARENT, Color::argb(0x80, 0, 0, 0), Color::BLACK]
+    } else {
+        [Color::BLACK, Color::WHITE, Color::BLACK]
+    }
+}
+
+#[test]
+fn test_convert_from_supported_pairs_round_trip() {
+    for from in PixelFormat::ALL {
+        for to in PixelFormat::ALL {
+            if !PixelFormat::can_convert(from, to) {
+                continue;
+            }
+            assert!(PixelFormat::can_convert(to, from));
+
+            let src_desc = BufferDescriptor::new(3, 1, from);
+            let mut src = [0u8; 12];
+            let mut view = BufferViewMut::new(&mut src, src_desc).unwrap();
+            for (x, c) in conversion_samples(from, to).into_iter().enumerate() {
+                view.set_pixel(x as u32, 0, c);
+            }
+
+            let mid_desc = BufferDescriptor::new(3, 1, to);
+            let mut mid = [0u8; 12];
+            BufferViewMut::new(&mut mid, mid_desc)
+                .unwrap()
+                .convert_from(&BufferView::new(&src, src_desc).unwrap())
+                .unwrap();
+
+            let mut back = [0u8; 12];
+            BufferViewMut::new(&mut back, src_desc)
+                .unwrap()
+                .convert_from(&BufferView::new(&mid, mid_desc).unwrap())
+                .unwrap();
+            assert_eq!(back, src, "{} -> {}", from.name(), to.name());
+        }
+    }
+}
+
+#[test]
+fn test_convert_from_unsupported_pairs() {
+    let mut rejected = 0;
+    for from in PixelFormat::ALL {
+        for to in PixelFormat::ALL {
+            if PixelFormat::can_convert(from, to) {
+                continue;
+            }
+            rejected += 1;
+            let src = [0u8; 4];
+            let mut dst = [0u8; 4];
+            let result = BufferViewMut::new(&mut dst, BufferDescriptor::new(1, 1, to))
+                .unwrap()
+                .convert_from(&BufferView::new(&src, BufferDescriptor::new(1, 1, from)).unwrap());
+            assert_eq!(result, Err(ConvertError::Unsupported(from, to)));
+        }
+    }
+    // Alpha8 com os 6 formatos sem alpha, nos dois sentidos
+    assert_eq!(rejected, 12);
+}
+
+#[test]
+fn test_convert_from_size_mismatch() {
+    let src = [0u8; 16];
+    let mut dst = [0u8; 8];
+    let result = BufferViewMut::new(&mut dst, BufferDescriptor::new(2, 2, PixelFormat::Gray16))
+        .unwrap()
+        .convert_from(
+            &BufferView::new(&src, BufferDescriptor::new(4, 1, PixelFormat::ARGB8888)).unwrap(),
+        );
+    assert_eq!(result, Err(ConvertError::SizeMismatch));
+}
@@ -2,6 +2,7 @@
 //!
 //! Testes para os tipos de cores.
 
+use gfx_types::buffer::BufferDescriptor;
 use gfx_types::color::*;
 
 // =============================================================================
@@ -68,6 +69,199 @@ fn test_color_grayscale() {
     assert!(gray.red() > 90 && gray.red() < 110);
 }
 
+#[test]
+fn test_color_force_opaque() {
+    let c = Color::argb(0x10, 0x20, 0x30, 0x40);
+    assert_eq!(c.force_opaque(), Color::rgb(0x20, 0x30, 0x40));
+    assert!(Color::TRANSPARENT.force_opaque().is_opaque());
+}
+
+#[test]
+fn test_color_over() {
+    assert_eq!(Color::RED.over(Color::BLUE), Color::RED);
+    assert_eq!(Color::TRANSPARENT.over(Color::BLUE), Color::BLUE);
+    let half = Color::argb(128, 255, 255, 255).over(Color::BLACK);
+    assert_eq!(half.alpha(), 255);
+    assert_eq!(half.red(), 128);
+}
+
+#[test]
+fn test_color_lerp_premultiplied_fade() {
+    let red = Color::RED;
+    let clear = Color::TRANSPARENT;
+
+    let straight = red.lerp(&clear, 0.5);
+    let premul = red.lerp_premultiplied(&clear, 0.5);
+
+    // Lerp direto escurece o vermelho; pré-multiplicado mantém o matiz
+    assert!(straight.red() < 200);
+    assert_eq!(premul.red(), 255);
+    assert_eq!(premul.green(), 0);
+    assert_eq!(premul.alpha(), 128);
+
+    assert_eq!(red.lerp_premultiplied(&clear, 0.0), red);
+    assert_eq!(red.lerp_premultiplied(&clear, 1.0), clear);
+}
+
+#[test]
+fn test_color_from_hsl() {
+    assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+    assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::GREEN);
+    assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::BLUE);
+    assert_eq!(Color::from_hsl(480.0, 1.0, 0.5), Color::GREEN);
+    assert_eq!(Color::from_hsl(0.0, 0.0, 1.0), Color::WHITE);
+}
+
+#[test]
+fn test_color_hsl_roundtrip() {
+    let c = Color::rgb(0x34, 0x80, 0xC2);
+    let (h, s, l) = c.to_hsl();
+    assert_eq!(Color::from_hsl(h, s, l), c);
+}
+
+#[test]
+fn test_color_debug_id() {
+    let a = Color::from_debug_id(1);
+    assert_eq!(a, Color::from_debug_id(1));
+    assert!(a.is_opaque());
+    let distinct = (0..16u64)
+        .filter(|&id| Color::from_debug_id(id) != Color::from_debug_id(id + 1))
+        .count();
+    assert_eq!(distinct, 16);
+}
+
+#[test]
+fn test_color_ansi256_known_indices() {
+    assert_eq!(Color::RED.to_ansi256(), 196);
+    assert_eq!(Color::BLACK.to_ansi256(), 16);
+    assert_eq!(Color::WHITE.to_ansi256(), 231);
+    assert_eq!(Color::gray(0x80).to_ansi256(), 244);
+    assert_eq!(Color::from_ansi256(196), Color::rgb(255, 0, 0));
+    assert_eq!(Color::from_ansi256(232), Color::gray(8));
+    assert_eq!(Color::from_ansi256(255), Color::gray(238));
+    assert_eq!(Color::from_ansi256(9), Color::rgb(255, 0, 0));
+}
+
+#[test]
+fn test_color_ansi256_cube_round_trip() {
+    for index in 16..=231u8 {
+        assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+    }
+    for index in 232..=255u8 {
+        assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+    }
+}
+
+#[test]
+fn test_color_quantization_error() {
+    let c = Color::rgb(100, 150, 200);
+    assert_eq!(c.quantization_error(PixelFormat::ARGB8888), 0);
+    assert_eq!(
+        Color::argb(0x40, 1, 2, 3).quantization_error(PixelFormat::ARGB8888),
+        0
+    );
+    // 565: r 100 -> 99, g 150 -> 150, b 200 -> 206
+    assert_eq!(c.quantization_error(PixelFormat::RGB565), 1 + 36);
+    // Cor na grade 565 não perde nada
+    assert_eq!(Color::WHITE.quantization_error(PixelFormat::RGB565), 0);
+}
+
+#[test]
+fn test_color_mix_oklab_avoids_gray() {
+    let blue = Color::rgb(0, 0, 255);
+    let yellow = Color::rgb(255, 255, 0);
+
+    // Meio em RGB linear: cinza neutro
+    let linear = ColorF::rgb(0.5, 0.5, 0.5);
+    let (_, a, b) = linear.to_oklab();
+    assert!(a.abs() < 1e-3 && b.abs() < 1e-3);
+
+    // Meio em OKLab mantém croma
+    let mid = blue.mix_oklab(&yellow, 0.5);
+    let (_, a, b) = mid.to_float().to_oklab();
+    assert!((a * a + b * b).sqrt() > 0.05);
+    assert_ne!(mid.red(), mid.blue());
+
+    assert_eq!(blue.mix_oklab(&yellow, 0.0), blue);
+    assert_eq!(blue.mix_oklab(&yellow, 1.0), yellow);
+}
+
+#[test]
+fn test_color_oklab_round_trip() {
+    for c in [
+        Color::rgb(255, 0, 0),
+        Color::rgb(12, 200, 77),
+        Color::rgb(128, 128, 128),
+        Color::WHITE,
+        Color::BLACK,
+    ] {
+        let (l, a, b) = c.to_float().to_oklab();
+        let back = ColorF::from_oklab(l, a, b);
+        let f = c.to_float();
+        assert!((back.r - f.r).abs() < 1e-3, "{:?}", c);
+        assert!((back.g - f.g).abs() < 1e-3, "{:?}", c);
+        assert!((back.b - f.b).abs() < 1e-3, "{:?}", c);
+        assert_eq!(c.mix_oklab(&c, 0.5), c);
+    }
+    // Branco tem L = 1 e croma nulo
+    let (l, a, b) = ColorF::WHITE.to_oklab();
+    assert!((l - 1.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+}
+
+#[test]
+fn test_color_byte_order_fixed() {
+    let c = Color::argb(0x11, 0x22, 0x33, 0x44);
+    assert_eq!(c.to_argb_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(c.to_bgra_bytes(), [0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(Color::from_argb_bytes([0x11, 0x22, 0x33, 0x44]), c);
+    assert_eq!(Color::from_bgra_bytes([0x44, 0x33, 0x22, 0x11]), c);
+
+    // Ordem nativa: apenas a representação do u32 interno
+    assert_eq!(c.to_native_bytes(), 0x1122_3344u32.to_ne_bytes());
+    assert_eq!(Color::from_native_bytes(c.to_native_bytes()), c);
+}
+
+#[test]
+fn test_argb8888_memory_order() {
+    // `ARGB8888` é `A, R, G, B` na memória, independente do host
+    let c = Color::argb(0x11, 0x22, 0x33, 0x44);
+    let mut px = [0u8; 4];
+    PixelFormat::ARGB8888.write_color(c, &mut px);
+    assert_eq!(px, [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(px, c.to_argb_bytes());
+    PixelFormat::BGRA8888.write_color(c, &mut px);
+    assert_eq!(px, [0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(
+        PixelFormat::ARGB8888.read_color(&[0x11, 0x22, 0x33, 0x44]),
+        c
+    );
+    assert_eq!(
+        PixelFormat::BGRA8888.read_color(&[0x44, 0x33, 0x22, 0x11]),
+        c
+    );
+}
+
+#[test]
+fn test_color_grayscale_linear_saturated() {
+    // Verde puro: Rec.601 em gamma vs Rec.709 em luz linear
+    let green = Color::rgb(0, 255, 0);
+    assert_ne!(green.to_grayscale_linear(), green.to_grayscale());
+    assert_eq!(green.to_grayscale_linear(), Color::gray(220));
+    let blue = Color::argb(0x40, 0, 0, 255);
+    let gray = blue.to_grayscale_linear();
+    assert_eq!(gray.alpha(), 0x40);
+    assert_ne!(gray, blue.to_grayscale());
+}
+
+#[test]
+fn test_color_grayscale_linear_pure_grays() {
+    for level in [0u8, 1, 17, 64, 128, 200, 254, 255] {
+        let gray = Color::gray(level);
+        assert_eq!(gray.to_grayscale_linear(), gray);
+        assert_eq!(gray.to_grayscale_linear(), gray.to_grayscale());
+    }
+}
+
 // =============================================================================
 // COLORF TESTS
 // =============================================================================
@@ -136,6 +330,77 @@ fn test_pixel_format_buffer_size() {
     assert_eq!(size, 800 * 600 * 4);
 }
 
+#[test]
+fn test_pixel_format_classification() {
+    use PixelFormat::*;
+    let table = [
+        (ARGB8888, true, false, 4),
+        (XRGB8888, true, false, 3),
+        (RGB565, false, false, 3),
+        (BGRA8888, true, false, 4),
+        (RGBA8888, true, false, 4),
+        (RGB888, false, true, 3),
+        (BGR888, false, true, 3),
+        (Gray8, false, false, 1),
+        (Gray16, false, false, 1),
+        (Alpha8, false, false, 1),
+    ];
+    for (format, is32, is24, components) in table {
+        assert_eq!(format.is_32bit(), is32, "{}", format.name());
+        assert_eq!(format.is_24bit(), is24, "{}", format.name());
+        assert_eq!(format.component_count(), components, "{}", format.name());
+    }
+}
+
+#[test]
+fn test_pixel_format_fast_convert() {
+    use PixelFormat::*;
+    assert!(ARGB8888.can_fast_convert(BGRA8888));
+    assert!(BGRA8888.can_fast_convert(RGBA8888));
+    assert!(RGB888.can_fast_convert(XRGB8888));
+    assert!(Gray8.can_fast_convert(Gray8));
+    assert!(!ARGB8888.can_fast_convert(RGB565));
+    assert!(!RGB565.can_fast_convert(ARGB8888));
+    assert!(!Gray8.can_fast_convert(ARGB8888));
+}
+
+#[test]
+fn test_pixel_format_recommended_alignment() {
+    use PixelFormat::*;
+    let table = [
+        (ARGB8888, 4, Some(1)),
+        (XRGB8888, 4, Some(1)),
+        (BGRA8888, 4, Some(1)),
+        (RGBA8888, 4, Some(1)),
+        (RGB888, 8, None),
+        (BGR888, 8, None),
+        (RGB565, 8, Some(2)),
+        (Gray16, 8, Some(2)),
+        (Gray8, 4, Some(4)),
+        (Alpha8, 4, Some(4)),
+    ];
+    for (format, alignment, per_word) in table {
+        assert_eq!(
+            format.recommended_alignment(),
+            alignment,
+            "{}",
+            format.name()
+        );
+        assert_eq!(format.pixels_per_word(), per_word, "{}", format.name());
+
+        // Larguras ímpares ainda geram stride alinhado a palavra
+        for width in [1, 3, 17, 641] {
+            let desc = BufferDescriptor::builder(width, 2)
+                .format(format)
+                .alignment(format.recommended_alignment())
+                .build()
+                .unwrap();
+            assert_eq!(desc.stride % 4, 0, "{} x{}", format.name(), width);
+            assert!(desc.stride >= format.min_stride(width));
+        }
+    }
+}
+
 // =============================================================================
 // BLEND MODE TESTS
 // =============================================================================
@@ -153,3 +418,418 @@ fn test_blend_mode_porter_duff() {
     assert!(BlendMode::DestOver.is_porter_duff());
     assert!(!BlendMode::Multiply.is_porter_duff());
 }
+
+#[test]
+fn test_blend_mode_inverse() {
+    assert_eq!(BlendMode::Add.inverse(), Some(BlendMode::Subtract));
+    assert_eq!(BlendMode::Subtract.inverse(), Some(BlendMode::Add));
+    assert!(BlendMode::Add.is_invertible());
+    assert!(!BlendMode::Multiply.is_invertible());
+    assert_eq!(BlendMode::Multiply.inverse(), None);
+    assert!(!BlendMode::SourceOver.is_invertible());
+
+    // Aplicar e desfazer com a mesma fonte restaura o destino
+    let dst = ColorF::rgb(0.2, 0.3, 0.4);
+    let src = ColorF::rgb(0.1, 0.2, 0.3);
+    let added = BlendMode::Add.blend(src, dst);
+    let undone = BlendMode::Add.inverse().unwrap().blend(src, added);
+    assert!((undone.r - dst.r).abs() < 1e-5);
+    assert!((undone.g - dst.g).abs() < 1e-5);
+    assert!((undone.b - dst.b).abs() < 1e-5);
+}
+
+fn blend_samples() -> [Color; 8] {
+    [
+        Color::TRANSPARENT,
+        Color::BLACK,
+        Color::WHITE,
+        Color::argb(0x80, 0xFF, 0x40, 0x00),
+        Color::argb(0x33, 0x10, 0x90, 0xF0),
+        Color::argb(0xFF, 0x7F, 0x80, 0x81),
+        Color::argb(0xC8, 0x20, 0xE0, 0x60),
+        Color::argb(0x01, 0xFF, 0xFF, 0xFF),
+    ]
+}
+
+fn float_to_color(c: ColorF) -> Color {
+    let to_u8 = |v: f32| (v * 255.0).round() as u8;
+    Color::argb(to_u8(c.a), to_u8(c.r), to_u8(c.g), to_u8(c.b))
+}
+
+fn assert_close(a: Color, b: Color, tolerance: u8) {
+    let (aa, ar, ag, ab) = a.to_argb();
+    let (ba, br, bg, bb) = b.to_argb();
+    assert!(aa.abs_diff(ba) <= tolerance, "{:08X} vs {:08X}", a.0, b.0);
+    if aa == 0 && ba == 0 {
+        return;
+    }
+    assert!(ar.abs_diff(br) <= tolerance, "{:08X} vs {:08X}", a.0, b.0);
+    assert!(ag.abs_diff(bg) <= tolerance, "{:08X} vs {:08X}", a.0, b.0);
+    assert!(ab.abs_diff(bb) <= tolerance, "{:08X} vs {:08X}", a.0, b.0);
+}
+
+#[test]
+fn test_blend_u8_source_over_matches_float() {
+    for src in blend_samples() {
+        for dst in blend_samples() {
+            let int = BlendMode::SourceOver.blend_u8(src, dst);
+            let float = BlendMode::SourceOver.blend(src.to_float(), dst.to_float());
+            assert_close(int, float_to_color(float), 1);
+        }
+    }
+}
+
+#[test]
+fn test_blend_u8_common_modes_match_float() {
+    for mode in [
+        BlendMode::SourceIn,
+        BlendMode::SourceOut,
+        BlendMode::SourceAtop,
+        BlendMode::DestOver,
+        BlendMode::DestIn,
+        BlendMode::DestOut,
+        BlendMode::DestAtop,
+        BlendMode::Xor,
+        BlendMode::Clear,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::Darken,
+        BlendMode::Lighten,
+        BlendMode::ColorDodge,
+        BlendMode::ColorBurn,
+        BlendMode::HardLight,
+        BlendMode::SoftLight,
+        BlendMode::Difference,
+        BlendMode::Exclusion,
+        BlendMode::Add,
+        BlendMode::Subtract,
+    ] {
+        for src in blend_samples() {
+            for dst in blend_samples() {
+                let int = mode.blend_u8(src, dst);
+                let float = mode.blend(src.to_float(), dst.to_float());
+                assert_close(int, float_to_color(float), 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_blend_u8_special_cases() {
+    let src = Color::argb(0x80, 0xFF, 0x00, 0x00);
+    assert_eq!(BlendMode::Normal.blend_u8(src, Color::WHITE), src);
+    assert_eq!(
+        BlendMode::Clear.blend_u8(src, Color::WHITE),
+        Color::TRANSPARENT
+    );
+    assert_eq!(
+        BlendMode::DestOver.blend_u8(src, Color::WHITE),
+        Color::WHITE
+    );
+    assert_eq!(
+        BlendMode::Multiply.blend_u8(Color::WHITE, Color::rgb(10, 20, 30)),
+        Color::rgb(10, 20, 30)
+    );
+}
+
+#[test]
+fn test_blend_table_matches_direct() {
+    let src = Color::argb(0x99, 0x20, 0x80, 0xE0);
+    let table = BlendTable::new(src);
+    assert_eq!(table.source(), src);
+    let sa = src.alpha() as u32;
+    let div = |v: u32| ((v + 127) / 255) as u8;
+    for v in 0..=255u32 {
+        assert_eq!(table.factor(v as u8), div(v * (255 - sa)));
+    }
+    for dst in [Color::BLACK, Color::WHITE, Color::rgb(0x12, 0x34, 0x56)] {
+        let out = table.blend(dst);
+        let direct = |s: u8, d: u8| div(s as u32 * sa) + div(d as u32 * (255 - sa));
+        assert_eq!(out.alpha(), 255);
+        assert_eq!(out.red(), direct(src.red(), dst.red()));
+        assert_eq!(out.green(), direct(src.green(), dst.green()));
+        assert_eq!(out.blue(), direct(src.blue(), dst.blue()));
+        assert_close(out, src.over(dst), 1);
+    }
+    // Destino translúcido delega para Color::over
+    let dst = Color::argb(0x40, 0x10, 0x10, 0x10);
+    assert_eq!(table.blend(dst), src.over(dst));
+}
+
+// =============================================================================
+// COMPOSITE TESTS
+// =============================================================================
+
+fn assert_colorf_close(a: ColorF, b: ColorF) {
+    assert!((a.r - b.r).abs() < 1e-4, "{:?} vs {:?}", a, b);
+    assert!((a.g - b.g).abs() < 1e-4, "{:?} vs {:?}", a, b);
+    assert!((a.b - b.b).abs() < 1e-4, "{:?} vs {:?}", a, b);
+    assert!((a.a - b.a).abs() < 1e-4, "{:?} vs {:?}", a, b);
+}
+
+#[test]
+fn test_composite_matches_blend_mode() {
+    let src = ColorF::new(0.9, 0.3, 0.1, 0.6);
+    let dst = ColorF::new(0.2, 0.4, 0.8, 0.7);
+    assert_colorf_close(
+        composite(src, dst, BlendMode::Normal, CompositeOp::SourceOver),
+        BlendMode::SourceOver.blend(src, dst),
+    );
+    assert_colorf_close(
+        composite(src, dst, BlendMode::Multiply, CompositeOp::SourceOver),
+        BlendMode::Multiply.blend(src, dst),
+    );
+    assert_colorf_close(
+        composite(src, dst, BlendMode::Normal, CompositeOp::SourceAtop),
+        BlendMode::SourceAtop.blend(src, dst),
+    );
+}
+
+#[test]
+fn test_composite_multiply_over_vs_atop() {
+    let src = ColorF::new(1.0, 0.5, 0.5, 0.6);
+    let backdrop = ColorF::new(0.5, 0.5, 0.5, 0.5);
+    let over = Compositor::new(BlendMode::Multiply, CompositeOp::SourceOver).apply(src, backdrop);
+    let atop = Compositor::new(BlendMode::Multiply, CompositeOp::SourceAtop).apply(src, backdrop);
+    assert!((over.a - 0.8).abs() < 1e-4);
+    // Atop preserva a cobertura do fundo
+    assert!((atop.a - 0.5).abs() < 1e-4);
+    assert!((over.r - atop.r).abs() > 0.01);
+}
+
+#[test]
+fn test_composite_op_edges() {
+    let src = ColorF::new(1.0, 0.0, 0.0, 1.0);
+    let dst = ColorF::new(0.0, 0.0, 1.0, 1.0);
+    assert_eq!(
+        composite(src, dst, BlendMode::Screen, CompositeOp::Clear),
+        ColorF::TRANSPARENT
+    );
+    assert_colorf_close(
+        composite(src, dst, BlendMode::Normal, CompositeOp::Dest),
+        dst,
+    );
+    assert_eq!(CompositeOp::from_u8(5), Some(CompositeOp::SourceAtop));
+    assert_eq!(CompositeOp::from_u8(12), None);
+    assert_eq!(CompositeOp::default().name(), "SourceOver");
+}
+
+// =============================================================================
+// PIXEL CODEC TESTS
+// =============================================================================
+
+#[test]
+fn test_write_color_32bit() {
+    let c = Color::argb(0x11, 0x22, 0x33, 0x44);
+    let mut out = [0u8; 4];
+
+    PixelFormat::ARGB8888.write_color(c, &mut out);
+    assert_eq!(out, [0x11, 0x22, 0x33, 0x44]);
+    PixelFormat::XRGB8888.write_color(c, &mut out);
+    assert_eq!(out, [0xFF, 0x22, 0x33, 0x44]);
+    PixelFormat::BGRA8888.write_color(c, &mut out);
+    assert_eq!(out, [0x44, 0x33, 0x22, 0x11]);
+    PixelFormat::RGBA8888.write_color(c, &mut out);
+    assert_eq!(out, [0x22, 0x33, 0x44, 0x11]);
+}
+
+#[test]
+fn test_write_color_24bit() {
+    let c = Color::rgb(0x22, 0x33, 0x44);
+    let mut out = [0u8; 3];
+
+    PixelFormat::RGB888.write_color(c, &mut out);
+    assert_eq!(out, [0x22, 0x33, 0x44]);
+    PixelFormat::BGR888.write_color(c, &mut out);
+    assert_eq!(out, [0x44, 0x33, 0x22]);
+}
+
+#[test]
+fn test_write_color_16bit_and_gray() {
+    let mut out = [0u8; 2];
+    PixelFormat::RGB565.write_color(Color::RED, &mut out);
+    assert_eq!(out, [0x00, 0xF8]);
+    PixelFormat::RGB565.write_color(Color::BLUE, &mut out);
+    assert_eq!(out, [0x1F, 0x00]);
+
+    PixelFormat::Gray16.write_color(Color::WHITE, &mut out);
+    assert_eq!(out, [0xFF, 0xFF]);
+
+    let mut one = [0u8; 1];
+    PixelFormat::Gray8.write_color(Color::gray(0x80), &mut one);
+    assert_eq!(one, [0x80]);
+    PixelFormat::Alpha8.write_color(Color::argb(0x40, 1, 2, 3), &mut one);
+    assert_eq!(one, [0x40]);
+}
+
+#[test]
+fn test_xrgb_alpha_ignored() {
+    for high in [0x00u8, 0x7F, 0xFF] {
+        let bytes = [high, 0x22, 0x33, 0x44];
+        let c = PixelFormat::XRGB8888.read_color(&bytes);
+        assert_eq!(c.alpha(), 255);
+        assert_eq!(c, Color::rgb(0x22, 0x33, 0x44));
+    }
+
+    // Copiar ARGB translúcido para XRGB não vaza transparência
+    let mut out = [0u8; 4];
+    PixelFormat::XRGB8888.write_color(Color::argb(0, 1, 2, 3), &mut out);
+    assert_eq!(out[0], 0xFF);
+}
+
+#[test]
+fn test_read_write_roundtrip() {
+    let c = Color::argb(0x80, 0x12, 0x34, 0x56);
+    let opaque = c.with_alpha(0xFF);
+    let mut out = [0u8; 4];
+
+    for format in [
+        PixelFormat::ARGB8888,
+        PixelFormat::BGRA8888,
+        PixelFormat::RGBA8888,
+    ] {
+        format.write_color(c, &mut out);
+        assert_eq!(format.read_color(&out), c);
+    }
+    for format in [
+        PixelFormat::XRGB8888,
+        PixelFormat::RGB888,
+        PixelFormat::BGR888,
+    ] {
+        format.write_color(c, &mut out);
+        assert_eq!(format.read_color(&out), opaque);
+    }
+
+    // Formatos com perda
+    PixelFormat::RGB565.write_color(Color::WHITE, &mut out);
+    assert_eq!(PixelFormat::RGB565.read_color(&out), Color::WHITE);
+    PixelFormat::RGB565.write_color(Color::rgb(0x10, 0x20, 0x30), &mut out);
+    assert_eq!(
+        PixelFormat::RGB565.read_color(&out),
+        Color::rgb(0x10, 0x20, 0x31)
+    );
+    PixelFormat::Gray8.write_color(Color::WHITE, &mut out);
+    assert_eq!(PixelFormat::Gray8.read_color(&out), Color::WHITE);
+    PixelFormat::Gray16.write_color(Color::BLACK, &mut out);
+    assert_eq!(PixelFormat::Gray16.read_color(&out), Color::BLACK);
+    PixelFormat::Alpha8.write_color(c, &mut out);
+    assert_eq!(
+        PixelFormat::Alpha8.read_color(&out),
+        Color::argb(0x80, 0, 0, 0)
+    );
+}
+
+// =============================================================================
+// COLOR RAMP TESTS
+// =============================================================================
+
+#[test]
+fn test_color_ramp_uneven_stops() {
+    let ramp =
+        ColorRamp::from_stops(&[(1.0, Color::WHITE), (0.0, Color::BLACK), (0.2, Color::RED)])
+            .unwrap();
+    assert_eq!(ramp.len(), 3);
+    assert_eq!(ramp.stops()[1].0, 0.2);
+
+    assert_eq!(ramp.sample(0.2), Color::RED);
+    // Entre vermelho (0.2) e branco (1.0): vermelho mantido, G/B crescem
+    let mid = ramp.sample(0.6);
+    assert_eq!(mid.red(), 255);
+    assert_eq!(mid.green(), mid.blue());
+    // Interpolação em espaço linear: meio caminho fica acima de 128 em sRGB
+    assert!(mid.green() > 150, "green = {}", mid.green());
+}
+
+#[test]
+fn test_color_ramp_clamping() {
+    let ramp = ColorRamp::from_stops(&[(0.25, Color::RED), (0.75, Color::BLUE)]).unwrap();
+    assert_eq!(ramp.sample(-1.0), Color::RED);
+    assert_eq!(ramp.sample(0.1), Color::RED);
+    assert_eq!(ramp.sample(0.9), Color::BLUE);
+    assert_eq!(ramp.sample(5.0), Color::BLUE);
+    assert_eq!(ColorRamp::new().sample(0.5), Color::TRANSPARENT);
+}
+
+#[test]
+fn test_color_ramp_invalid_position() {
+    assert!(ColorRamp::from_stops(&[(1.5, Color::RED)]).is_none());
+    assert!(ColorRamp::from_stops(&[(-0.1, Color::RED)]).is_none());
+}
+
+#[test]
+fn test_color_ramp_premultiplied() {
+    let ramp = ColorRamp::from_stops(&[(0.0, Color::RED), (1.0, Color::TRANSPARENT)]).unwrap();
+    let straight = ramp.sample(0.5);
+    let premul = ramp.sample_premultiplied(0.5);
+    assert_eq!(premul.alpha(), straight.alpha());
+    assert_eq!(premul.red(), 255);
+    assert!(straight.red() < 255);
+}
+
+// =============================================================================
+// COLOR LUT TESTS
+// =============================================================================
+
+#[test]
+fn test_color_lut_identity() {
+    let lut = ColorLut::identity();
+    for c in [
+        Color::RED,
+        Color::rgb(1, 2, 3),
+        Color::argb(0x40, 0x80, 0xC0, 0xFF),
+    ] {
+        assert_eq!(lut.apply(c), c);
+    }
+}
+
+#[test]
+fn test_color_lut_gamma() {
+    let lut = ColorLut::gamma(2.2);
+    for i in [0usize, 32, 64, 128, 200, 255] {
+        let expected = (remove_gamma(i as f32 / 255.0, 2.2) * 255.0).round() as u8;
+        assert_eq!(lut.r[i], expected);
+        assert_eq!(lut.b[i], expected);
+    }
+    let c = lut.apply(Color::argb(0x10, 128, 128, 128));
+    assert_eq!(c.alpha(), 0x10);
+    assert!(c.red() > 128);
+}
+
+// =============================================================================
+// TONE MAPPING TESTS
+// =============================================================================
+
+#[test]
+fn test_tonemap_reinhard_low_values() {
+    // Valores baixos praticamente não mudam (c / (1 + c) ~ c)
+    for v in [0.0f32, 0.005, 0.01, 0.02] {
+        let out = ColorF::new(v, v, v, 0.5).tonemap(ToneMap::Reinhard);
+        assert!((out.r - v).abs() <= v * 0.03 + 1e-6, "{} -> {}", v, out.r);
+        assert_eq!(out.a, 0.5);
+    }
+    // Com ponto branco 1.0, toda a faixa [0, 1] é preservada
+    for v in [0.1f32, 0.5, 0.9, 1.0] {
+        let out = ColorF::new(v, v, v, 1.0).tonemap(ToneMap::ReinhardExtended(1.0));
+        assert!((out.r - v).abs() < 1e-5, "{} -> {}", v, out.r);
+    }
+}
+
+#[test]
+fn test_tonemap_compresses_hdr() {
+    let hdr = ColorF::new(4.0, 1.0, 0.0, 1.0);
+    for op in [
+        ToneMap::Reinhard,
+        ToneMap::ReinhardExtended(8.0),
+        ToneMap::AcesApprox,
+    ] {
+        let out = hdr.tonemap(op);
+        assert!(out.r < 1.0 && out.r > 0.75, "{:?}: {}", op, out.r);
+        // Monotônico: mais brilho nunca escurece
+        assert!(out.r > out.g, "{:?}", op);
+        assert!(ColorF::new(1000.0, 0.0, 0.0, 1.0).tonemap(op).r <= 1.0);
+    }
+    // Ponto branco satura exatamente em 1.0
+    let white = ColorF::new(8.0, 8.0, 8.0, 1.0).tonemap(ToneMap::ReinhardExtended(8.0));
+    assert!((white.r - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,226 @@
+//! # Testes de Damage
+//!
+//! Testes para damage tracking.
+
+use gfx_types::damage::*;
+use gfx_types::geometry::{Rect, Transform2D};
+
+// =============================================================================
+// DAMAGE BUFFER TESTS
+// =============================================================================
+
+#[test]
+fn test_damage_buffer_push() {
+    let mut buf = DamageBuffer::new();
+    buf.push(Rect::new(0, 0, 10, 10));
+    buf.push(Rect::ZERO); // ignorado
+    buf.push(Rect::new(20, 20, 5, 5));
+    assert_eq!(buf.len(), 2);
+    assert_eq!(buf.bounds(), Rect::new(0, 0, 25, 25));
+}
+
+#[test]
+fn test_damage_buffer_overflow_collapses() {
+    let mut buf = DamageBuffer::new();
+    for i in 0..(MAX_DAMAGE_REGIONS as i32 + 1) {
+        buf.push(Rect::new(i * 10, 0, 5, 5));
+    }
+    assert_eq!(buf.len(), 1);
+    assert_eq!(
+        buf.get(0).unwrap().rect,
+        Rect::new(0, 0, MAX_DAMAGE_REGIONS as u32 * 10 + 5, 5)
+    );
+}
+
+#[test]
+fn test_damage_optimize_merges_adjacent() {
+    let mut buf = DamageBuffer::new();
+    buf.push(Rect::new(0, 0, 10, 10));
+    buf.push(Rect::new(10, 0, 10, 10));
+    buf.push(Rect::new(500, 500, 10, 10));
+    buf.optimize(0.1);
+    assert_eq!(buf.len(), 2);
+    assert!(buf.iter().any(|r| r.rect == Rect::new(0, 0, 20, 10)));
+    assert!(buf.iter().any(|r| r.rect == Rect::new(500, 500, 10, 10)));
+}
+
+#[test]
+fn test_damage_optimize_keeps_far_apart() {
+    let mut buf = DamageBuffer::new();
+    buf.push(Rect::new(0, 0, 10, 10));
+    buf.push(Rect::new(100, 100, 10, 10));
+    buf.optimize(0.5);
+    assert_eq!(buf.len(), 2);
+
+    // Com threshold alto, tudo vira uma região
+    buf.optimize(f32::MAX);
+    assert_eq!(buf.len(), 1);
+    assert_eq!(buf.bounds(), Rect::new(0, 0, 110, 110));
+}
+
+#[test]
+fn test_damage_transform_translate_exact() {
+    let region = DamageRegion::from_coords(10, 20, 30, 40);
+    let moved = region.transform(&Transform2D::translate(100.0, -5.0));
+    assert_eq!(moved.rect, Rect::new(110, 15, 30, 40));
+}
+
+#[test]
+fn test_damage_transform_scale_doubles() {
+    let region = DamageRegion::from_coords(10, 20, 30, 40);
+    let scaled = region.transform(&Transform2D::scale(2.0));
+    assert_eq!(scaled.rect, Rect::new(20, 40, 60, 80));
+}
+
+#[test]
+fn test_damage_transform_rotation_inflates() {
+    let region = DamageRegion::from_coords(0, 0, 100, 100);
+    let rotated = region.transform(&Transform2D::rotate_degrees(45.0));
+    // AABB de um quadrado girado 45° tem lado 100 * sqrt(2)
+    assert!(rotated.rect.width >= 141 && rotated.rect.width <= 143);
+    assert!(rotated.rect.height >= 141 && rotated.rect.height <= 143);
+    assert!(rotated.area() > region.area());
+}
+
+#[test]
+fn test_damage_buffer_transform_all() {
+    let mut buffer = DamageBuffer::new();
+    buffer.push(Rect::new(0, 0, 10, 10));
+    buffer.push(Rect::new(50, 50, 5, 5));
+    buffer.transform_all(&Transform2D::scale(2.0));
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.get(0).unwrap().rect, Rect::new(0, 0, 20, 20));
+    assert_eq!(buffer.get(1).unwrap().rect, Rect::new(100, 100, 10, 10));
+
+    // Escala zero esvazia todas as regiões
+    buffer.transform_all(&Transform2D::scale(0.0));
+    assert!(buffer.is_empty());
+}
+
+fn scattered_rects() -> [Rect; 10] {
+    [
+        // Três grupos distantes
+        Rect::new(0, 0, 4, 4),
+        Rect::new(6, 2, 4, 4),
+        Rect::new(2, 7, 3, 3),
+        Rect::new(200, 0, 5, 5),
+        Rect::new(208, 4, 4, 4),
+        Rect::new(203, 9, 2, 2),
+        Rect::new(100, 300, 6, 6),
+        Rect::new(110, 302, 3, 3),
+        Rect::new(104, 310, 4, 2),
+        Rect::new(101, 306, 2, 2),
+    ]
+}
+
+fn covers_all(buffer: &DamageBuffer, rects: &[Rect]) -> bool {
+    rects
+        .iter()
+        .all(|r| buffer.iter().any(|region| region.rect.contains_rect(r)))
+}
+
+#[test]
+fn test_damage_buffer_simplify_to() {
+    let rects = scattered_rects();
+    let mut buffer = DamageBuffer::new();
+    for r in rects {
+        buffer.push(r);
+    }
+    buffer.simplify_to(3);
+    assert_eq!(buffer.len(), 3);
+    assert!(covers_all(&buffer, &rects));
+    // As fusões mais baratas agrupam cada cluster separadamente
+    assert!(buffer.iter().any(|r| r.rect == Rect::new(0, 0, 10, 10)));
+    assert!(buffer.iter().any(|r| r.rect == Rect::new(200, 0, 12, 11)));
+    assert!(buffer.iter().any(|r| r.rect == Rect::new(100, 300, 13, 12)));
+
+    // Já dentro do limite: nada muda
+    buffer.simplify_to(5);
+    assert_eq!(buffer.len(), 3);
+}
+
+#[test]
+fn test_damage_buffer_simplify_keeps_coverage() {
+    let rects = scattered_rects();
+    for max in (0..=10).rev() {
+        let mut buffer = DamageBuffer::new();
+        for r in rects {
+            buffer.push(r);
+        }
+        buffer.simplify_to(max);
+        assert_eq!(buffer.len(), max.max(1));
+        assert!(covers_all(&buffer, &rects), "max_rects = {}", max);
+    }
+}
+
+// =============================================================================
+// OCCLUSION TESTS
+// =============================================================================
+
+#[test]
+fn test_compute_visible_full_occlusion() {
+    let screen = Rect::new(0, 0, 1000, 1000);
+    let front = Rect::new(0, 0, 500, 500);
+    let back = Rect::new(100, 100, 200, 200);
+
+    let mut out = DamageBuffer::new();
+    compute_visible(&[front, back], screen, &mut out);
+
+    // Apenas a janela da frente contribui
+    assert_eq!(out.len(), 1);
+    assert_eq!(out.get(0).unwrap().rect, front);
+}
+
+#[test]
+fn test_compute_visible_partial_overlap() {
+    let screen = Rect::new(0, 0, 1000, 1000);
+    let front = Rect::new(50, 50, 100, 100);
+    let back = Rect::new(0, 0, 100, 100);
+
+    let mut out = DamageBuffer::new();
+    compute_visible(&[front, back], screen, &mut out);
+
+    // Frente inteira + L da janela de trás
+    assert_eq!(out.total_area(), 100 * 100 + (100 * 100 - 50 * 50));
+    for region in out.iter().skip(1) {
+        assert!(!region.rect.intersects(&front));
+        assert!(back.contains_rect(&region.rect));
+    }
+}
+
+#[test]
+fn test_compute_visible_clips_to_screen() {
+    let screen = Rect::new(0, 0, 100, 100);
+    let mut out = DamageBuffer::new();
+    compute_visible(&[Rect::new(50, 50, 100, 100)], screen, &mut out);
+    assert_eq!(out.get(0).unwrap().rect, Rect::new(50, 50, 50, 50));
+}
+
+#[test]
+fn test_frame_damage_window_move() {
+    let prev = [Rect::new(100, 100, 200, 150)];
+    let cur = [Rect::new(110, 100, 200, 150)];
+
+    let mut out = DamageBuffer::new();
+    frame_damage(&prev, &cur, &mut out);
+
+    let rects: Vec<Rect> = out.iter().map(|r| r.rect).collect();
+    // Faixa recém-coberta à direita, faixa recém-exposta à esquerda
+    assert_eq!(
+        rects,
+        [Rect::new(300, 100, 10, 150), Rect::new(100, 100, 10, 150)]
+    );
+}
+
+#[test]
+fn test_frame_damage_unchanged_and_multiple() {
+    let windows = [Rect::new(0, 0, 50, 50), Rect::new(100, 0, 50, 50)];
+    let mut out = DamageBuffer::new();
+    frame_damage(&windows, &windows, &mut out);
+    assert!(out.is_empty());
+
+    // Uma janela fecha: toda a área dela fica exposta
+    frame_damage(&windows, &windows[..1], &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out.get(0).unwrap().rect, windows[1]);
+}
@@ -0,0 +1,198 @@
+//! # Testes de Display
+//!
+//! Testes para os tipos de display.
+
+use gfx_types::color::PixelFormat;
+use gfx_types::display::*;
+use gfx_types::geometry::{PointF, RectF, Size};
+
+// =============================================================================
+// REFRESH PHASE TESTS
+// =============================================================================
+
+fn display_60hz() -> DisplayInfo {
+    DisplayInfo::new(1, 1920, 1080, 60000, PixelFormat::XRGB8888, 7680)
+}
+
+#[test]
+fn test_display_phase_at_linear_and_wraps() {
+    let display = display_60hz();
+    // Intervalo de ~16666.67us
+    assert_eq!(display.phase_at(0), 0.0);
+    assert!((display.phase_at(4167) - 0.25).abs() < 1e-3);
+    assert!((display.phase_at(8333) - 0.5).abs() < 1e-3);
+    assert!((display.phase_at(12500) - 0.75).abs() < 1e-3);
+    // Passou de um intervalo: volta para perto de 0
+    assert!(display.phase_at(16667) < 0.01);
+    assert!((display.phase_at(20834) - 0.25).abs() < 1e-3);
+    assert!(display.phase_at(16666) < 1.0);
+}
+
+#[test]
+fn test_display_frames_between() {
+    let display = display_60hz();
+    assert_eq!(display.frames_between(0, 100_000), 6);
+    assert_eq!(display.frames_between(1_000_000, 1_100_000), 6);
+    assert_eq!(display.frames_between(0, 1_000_000), 60);
+    assert_eq!(display.frames_between(500, 100), 0);
+}
+
+#[test]
+fn test_display_phase_zero_refresh() {
+    let display = DisplayInfo::new(1, 1920, 1080, 0, PixelFormat::XRGB8888, 7680);
+    assert_eq!(display.phase_at(12345), 0.0);
+    assert_eq!(display.frames_between(0, 1_000_000), 0);
+}
+
+// =============================================================================
+// ROTATION TESTS
+// =============================================================================
+
+#[test]
+fn test_rotation_swaps_dimensions() {
+    let display = DisplayInfo::new(1, 1920, 1080, 60000, PixelFormat::XRGB8888, 7680);
+    assert!(Rotation::Rotate90.swaps_dimensions());
+    assert!(Rotation::Rotate270.swaps_dimensions());
+    assert!(!Rotation::FlipH.swaps_dimensions());
+    assert_eq!(
+        display.logical_size(Rotation::Rotate90),
+        Size::new(1080, 1920)
+    );
+
+    let t = display.rotation_transform(Rotation::Rotate90);
+    let logical = RectF::new(0.0, 0.0, 1080.0, 1920.0);
+    assert_eq!(
+        t.transform_rect(logical),
+        RectF::new(0.0, 0.0, 1920.0, 1080.0)
+    );
+    // Canto superior esquerdo lógico vai para o canto superior direito do painel
+    assert_eq!(
+        t.transform_point(PointF::new(0.0, 0.0)),
+        PointF::new(1920.0, 0.0)
+    );
+}
+
+#[test]
+fn test_rotation_180_twice_is_identity() {
+    let display = DisplayInfo::new(1, 1920, 1080, 60000, PixelFormat::XRGB8888, 7680);
+    let half = display.rotation_transform(Rotation::Rotate180);
+    assert!(half.then(&half).is_identity());
+
+    let quarter = display.rotation_transform(Rotation::Rotate90);
+    let back = display.rotation_transform(Rotation::Rotate270);
+    let p = PointF::new(100.0, 200.0);
+    let q = quarter.transform_point(p);
+    assert_eq!(quarter.inverse().unwrap().transform_point(q), p);
+    assert_eq!(
+        back.transform_point(PointF::new(0.0, 0.0)),
+        PointF::new(0.0, 1080.0)
+    );
+    assert_eq!(Rotation::from_u8(5), Some(Rotation::FlipV));
+    assert_eq!(Rotation::from_u8(6), None);
+}
+
+// =============================================================================
+// DISPLAY RECORD TESTS
+// =============================================================================
+
+fn sample_display() -> (DisplayInfo, OutputInfo) {
+    let display = DisplayInfo::new(3, 2560, 1440, 144_000, PixelFormat::XRGB8888, 2560 * 4);
+    let output = OutputInfo {
+        id: 7,
+        connector: ConnectorType::DisplayPort,
+        connected: true,
+        width_mm: 597,
+        height_mm: 336,
+    };
+    (display, output)
+}
+
+#[test]
+fn test_display_record_roundtrip() {
+    let (display, output) = sample_display();
+    let record = display.to_record(&output);
+    assert_eq!(record.version, DISPLAY_RECORD_VERSION);
+    assert_eq!(record.size as usize, DisplayRecord::SIZE);
+
+    let (d, o) = DisplayInfo::from_record(&record).unwrap();
+    assert_eq!(d.id, 3);
+    assert_eq!(d.size(), display.size());
+    assert_eq!(d.refresh_rate_mhz, 144_000);
+    assert_eq!(d.format, PixelFormat::XRGB8888);
+    assert_eq!(d.stride, display.stride);
+    assert_eq!(o.id, 7);
+    assert_eq!(o.connector, ConnectorType::DisplayPort);
+    assert!(o.connected);
+    assert_eq!((o.width_mm, o.height_mm), (597, 336));
+}
+
+#[test]
+fn test_display_record_rejects_unknown() {
+    let (display, output) = sample_display();
+    let mut record = display.to_record(&output);
+    record.version = DISPLAY_RECORD_VERSION + 1;
+    assert!(DisplayInfo::from_record(&record).is_none());
+
+    let mut record = display.to_record(&output);
+    record.format = 999;
+    assert!(DisplayInfo::from_record(&record).is_none());
+}
+
+// =============================================================================
+// MODE FILTER TESTS
+// =============================================================================
+
+fn sample_modes() -> [DisplayMode; 4] {
+    [
+        DisplayMode::new(3840, 2160, 60_000),
+        DisplayMode::new(2560, 1440, 144_000),
+        DisplayMode::new(1920, 1080, 60_000),
+        DisplayMode::new(1024, 768, 60_000),
+    ]
+}
+
+#[test]
+fn test_filter_modes_vga_drops_4k() {
+    let modes = sample_modes();
+    let mut out = [DisplayMode::default(); 4];
+    let n = filter_modes_for_connector(&modes, ConnectorType::VGA, PixelFormat::XRGB8888, &mut out);
+    assert_eq!(n, 2);
+    assert_eq!(out[0], modes[2]);
+    assert_eq!(out[1], modes[3]);
+}
+
+#[test]
+fn test_filter_modes_displayport_keeps_4k() {
+    let modes = sample_modes();
+    let mut out = [DisplayMode::default(); 4];
+    let n = filter_modes_for_connector(
+        &modes,
+        ConnectorType::DisplayPort,
+        PixelFormat::ARGB8888,
+        &mut out,
+    );
+    assert_eq!(n, 4);
+    assert_eq!(out, modes);
+}
+
+#[test]
+fn test_filter_modes_bandwidth_and_capacity() {
+    let modes = sample_modes();
+    // DVI dual-link não comporta 4K a 60 Hz nem 1440p a 144 Hz
+    let mut out = [DisplayMode::default(); 4];
+    let n =
+        filter_modes_for_connector(&modes, ConnectorType::DVID, PixelFormat::XRGB8888, &mut out);
+    assert_eq!(&out[..n], &[modes[2], modes[3]]);
+
+    // Saída menor que o número de modos aceitos
+    let mut small = [DisplayMode::default(); 1];
+    let n = filter_modes_for_connector(
+        &modes,
+        ConnectorType::Virtual,
+        PixelFormat::XRGB8888,
+        &mut small,
+    );
+    assert_eq!(n, 1);
+    assert_eq!(small[0], modes[0]);
+    assert!(ConnectorType::DisplayPort.max_bandwidth() > ConnectorType::HDMIA.max_bandwidth());
+}
@@ -3,6 +3,7 @@
 //! Testes para os tipos geométricos.
 
 use gfx_types::geometry::*;
+use gfx_types::window::ResizeEdge;
 
 // =============================================================================
 // POINT TESTS
@@ -64,6 +65,36 @@ fn test_point_midpoint() {
     assert_eq!(mid.y, 10);
 }
 
+#[test]
+fn test_point_snap_to_guides() {
+    let p = Point::new(52, 203);
+    let guides = [0, 50, 100, 200];
+    assert_eq!(p.snap_to_guides(&guides, Axis::X, 5), 50);
+    assert_eq!(p.snap_to_guides(&guides, Axis::Y, 5), 200);
+    // Fora do threshold: mantém
+    assert_eq!(p.snap_to_guides(&guides, Axis::X, 1), 52);
+}
+
+#[test]
+fn test_point_snap_to_nearest_guide() {
+    let p = Point::new(48, 0);
+    // 45 está a 3px, 50 está a 2px
+    assert_eq!(p.snap_to_guides(&[45, 50], Axis::X, 5), 50);
+}
+
+#[test]
+fn test_point_clamp_to_rect() {
+    let r = Rect::new(10, 20, 100, 50);
+    assert_eq!(Point::new(-5, 300).clamp_to_rect(r), Point::new(10, 69));
+    assert_eq!(Point::new(500, 0).clamp_to_rect(r), Point::new(109, 20));
+    assert_eq!(Point::new(50, 30).clamp_to_rect(r), Point::new(50, 30));
+    assert!(r.contains_point(Point::new(1000, 1000).clamp_to_rect(r)));
+    assert_eq!(
+        Point::new(5, 5).clamp_to_rect(Rect::new(3, 4, 0, 0)),
+        Point::new(3, 4)
+    );
+}
+
 // =============================================================================
 // SIZE TESTS
 // =============================================================================
@@ -88,6 +119,30 @@ fn test_size_is_empty() {
     assert!(!Size::new(10, 10).is_empty());
 }
 
+#[test]
+fn test_size_overflow_add() {
+    let big = Size::new(u32::MAX - 1, 10);
+    let one = Size::new(1, 1);
+    assert_eq!(big.checked_add(one), Some(Size::new(u32::MAX, 11)));
+    assert_eq!(big.checked_add(Size::new(2, 0)), None);
+    assert_eq!(big.saturating_add(Size::new(5, 5)), Size::new(u32::MAX, 15));
+    assert_eq!(big + Size::new(5, 5), Size::new(u32::MAX, 15));
+}
+
+#[test]
+fn test_size_overflow_mul() {
+    let big = Size::new(u32::MAX / 2 + 1, 3);
+    assert_eq!(big.checked_mul(2), None);
+    assert_eq!(
+        Size::new(u32::MAX / 2, 3).checked_mul(2),
+        Some(Size::new(u32::MAX - 1, 6))
+    );
+    assert_eq!(big.saturating_mul(2), Size::new(u32::MAX, 6));
+    assert_eq!(big * 2, Size::new(u32::MAX, 6));
+    // Altura também estoura
+    assert_eq!(Size::new(1, u32::MAX).checked_mul(3), None);
+}
+
 // =============================================================================
 // RECT TESTS
 // =============================================================================
@@ -110,6 +165,50 @@ fn test_rect_from_points() {
     assert_eq!(r.height, 50);
 }
 
+#[test]
+fn test_rect_from_points_extreme() {
+    let r = Rect::from_points(Point::new(i32::MIN, i32::MIN), Point::new(i32::MAX, 0));
+    assert_eq!(r.x, i32::MIN);
+    assert_eq!(r.y, i32::MIN);
+    // Saturado em i32::MAX: bordas representáveis, aquém dos pontos
+    assert_eq!(r.width, i32::MAX as u32);
+    assert_eq!(r.height, i32::MAX as u32);
+    assert_eq!(r.right(), -1);
+    assert_eq!(r.bottom(), -1);
+    assert!(r.right() >= r.x && r.bottom() >= r.y);
+
+    // Dentro do limite, idêntico à versão checada
+    let (a, b) = (Point::new(-1, i32::MIN), Point::new(i32::MAX - 1, -1));
+    assert_eq!(
+        Some(Rect::from_points(a, b)),
+        Rect::from_points_checked(a, b)
+    );
+}
+
+#[test]
+fn test_rect_from_points_checked() {
+    // Largura u32::MAX: `right()` não conseguiria representar i32::MAX
+    assert_eq!(
+        Rect::from_points_checked(Point::new(i32::MAX, 5), Point::new(i32::MIN, -5)),
+        None
+    );
+    assert_eq!(
+        Rect::from_points_checked(Point::new(0, i32::MIN), Point::new(1, 0)),
+        None
+    );
+
+    // No limite: bordas exatas
+    let r =
+        Rect::from_points_checked(Point::new(-1, i32::MIN), Point::new(i32::MAX - 1, -1)).unwrap();
+    assert_eq!(r.width, i32::MAX as u32);
+    assert_eq!(r.right(), i32::MAX - 1);
+    assert_eq!(r.bottom(), -1);
+    assert_eq!(
+        Rect::from_points_checked(Point::new(110, 70), Point::new(10, 20)),
+        Some(Rect::new(10, 20, 100, 50))
+    );
+}
+
 #[test]
 fn test_rect_edges() {
     let r = Rect::new(10, 20, 100, 50);
@@ -160,6 +259,672 @@ fn test_rect_union() {
     assert_eq!(union.height, 150);
 }
 
+#[test]
+fn test_rect_subtract() {
+    let r = Rect::new(0, 0, 100, 100);
+    let hole = Rect::new(25, 25, 50, 50);
+    let (parts, count) = r.subtract(&hole);
+    assert_eq!(count, 4);
+    let area: u64 = parts[..count].iter().map(|p| p.area()).sum();
+    assert_eq!(area, 100 * 100 - 50 * 50);
+    for p in &parts[..count] {
+        assert!(!p.intersects(&hole));
+    }
+
+    let (_, none) = r.subtract(&Rect::new(-10, -10, 200, 200));
+    assert_eq!(none, 0);
+
+    let (same, one) = r.subtract(&Rect::new(200, 200, 10, 10));
+    assert_eq!(one, 1);
+    assert_eq!(same[0], r);
+}
+
+#[test]
+fn test_rect_snap_to_neighbor() {
+    let other = Rect::new(0, 0, 100, 100);
+    // Borda esquerda a 3px da borda direita do vizinho
+    let win = Rect::new(103, 40, 50, 50);
+    let snapped = win.snap_to(&[other], 5);
+    assert_eq!(snapped, Rect::new(100, 40, 50, 50));
+}
+
+#[test]
+fn test_rect_snap_to_beyond_threshold() {
+    let other = Rect::new(0, 0, 100, 100);
+    let win = Rect::new(110, 40, 50, 50);
+    assert_eq!(win.snap_to(&[other], 5), win);
+}
+
+#[test]
+fn test_rect_snap_to_prefers_closest() {
+    let screen = Rect::new(0, 0, 1000, 800);
+    let other = Rect::new(0, 0, 100, 100);
+    // Topo a 2px do topo da tela, esquerda a 4px do vizinho
+    let win = Rect::new(104, 2, 50, 50);
+    let snapped = win.snap_to(&[screen, other], 5);
+    assert_eq!(snapped, Rect::new(100, 0, 50, 50));
+}
+
+#[test]
+fn test_rect_overlap_metrics() {
+    let a = Rect::new(0, 0, 100, 100);
+    assert_eq!(a.overlap_ratio(&a), 1.0);
+    assert_eq!(a.iou(&a), 1.0);
+
+    let far = Rect::new(200, 200, 10, 10);
+    assert_eq!(a.overlap_ratio(&far), 0.0);
+    assert_eq!(a.iou(&far), 0.0);
+
+    // Metade sobreposta: interseção 5000, união 15000
+    let half = Rect::new(50, 0, 100, 100);
+    assert_eq!(a.overlap_ratio(&half), 0.5);
+    assert!((a.iou(&half) - 1.0 / 3.0).abs() < 1e-6);
+
+    // Retângulo pequeno totalmente contido
+    let inner = Rect::new(10, 10, 10, 10);
+    assert_eq!(a.overlap_ratio(&inner), 1.0);
+    assert_eq!(a.overlap_ratio(&Rect::ZERO), 0.0);
+    assert_eq!(a.iou(&Rect::ZERO), 0.0);
+}
+
+#[test]
+fn test_rect_center_distance_sq() {
+    let a = Rect::new(0, 0, 10, 10);
+    let b = Rect::new(30, 40, 10, 10);
+    assert_eq!(a.center_distance_sq(&b), 30 * 30 + 40 * 40);
+    assert_eq!(a.center_distance_sq(&a), 0);
+}
+
+#[test]
+fn test_nearest_center_vs_edge() {
+    // Retângulo alongado: borda perto do ponto, centro longe
+    let long = Rect::new(0, 0, 1000, 10);
+    let small = Rect::new(120, 40, 20, 20);
+    let rects = [long, small];
+    let p = Point::new(100, 20);
+
+    assert_eq!(nearest_by_center(&rects, p), Some(1));
+    assert_eq!(nearest_by_edge(&rects, p), Some(0));
+    assert_eq!(nearest_by_center(&[], p), None);
+    assert_eq!(long.edge_distance_sq(Point::new(5, 5)), 0);
+}
+
+#[test]
+fn test_rect_dock() {
+    let r = Rect::new(0, 0, 400, 300);
+
+    let (strip, rest) = r.dock(ResizeEdge::Left, 50);
+    assert_eq!(strip, Rect::new(0, 0, 50, 300));
+    assert_eq!(rest, Rect::new(50, 0, 350, 300));
+    assert_eq!(strip.union(&rest), r);
+
+    let (strip, rest) = r.dock(ResizeEdge::Bottom, 50);
+    assert_eq!(strip, Rect::new(0, 250, 400, 50));
+    assert_eq!(rest, Rect::new(0, 0, 400, 250));
+    assert_eq!(strip.area() + rest.area(), r.area());
+
+    let (strip, rest) = r.dock(ResizeEdge::Right, 500);
+    assert_eq!(strip, r);
+    assert!(rest.is_empty());
+
+    let (strip, rest) = r.dock(ResizeEdge::TopLeft, 50);
+    assert!(strip.is_empty());
+    assert_eq!(rest, r);
+}
+
+#[test]
+fn test_rect_list_areas() {
+    let rects = [
+        Rect::new(0, 0, 10, 10),
+        Rect::new(5, 5, 10, 10),
+        Rect::new(100, 100, 1, 1),
+    ];
+    assert_eq!(bounding_box(&rects), Some(Rect::new(0, 0, 101, 101)));
+    assert_eq!(bounding_box(&[]), None);
+
+    assert_eq!(total_area(&rects), 201);
+    // Sobreposição de 5x5 contada uma vez
+    assert_eq!(covered_area(&rects), 176);
+    assert!(total_area(&rects) > covered_area(&rects));
+}
+
+#[test]
+fn test_covered_area_nested_and_empty() {
+    let rects = [
+        Rect::new(0, 0, 100, 100),
+        Rect::new(10, 10, 20, 20),
+        Rect::ZERO,
+    ];
+    assert_eq!(covered_area(&rects), 10_000);
+    assert_eq!(covered_area(&[]), 0);
+    // Cruz: duas faixas sobrepostas no centro
+    let cross = [Rect::new(0, 10, 30, 10), Rect::new(10, 0, 10, 30)];
+    assert_eq!(covered_area(&cross), 500);
+}
+
+#[test]
+fn test_snap_layout_shared_edge() {
+    let layout = [
+        RectF::new(0.3, 0.0, 100.2, 50.4),
+        RectF::new(100.5, 0.0, 99.5, 50.4),
+    ];
+    let mut out = [Rect::ZERO; 2];
+    assert_eq!(snap_layout(&layout, &mut out), 2);
+    // Sem gap nem sobreposição na borda compartilhada
+    assert_eq!(out[0].right(), out[1].x);
+    assert_eq!(out[1].x, 101);
+    assert_eq!(out[1].right(), 200);
+    assert_eq!(out[0].height, out[1].height);
+    assert_eq!(covered_area(&out), total_area(&out));
+}
+
+#[test]
+fn test_snap_layout_truncates_output() {
+    let layout = [RectF::new(0.0, 0.0, 10.0, 10.0); 3];
+    let mut out = [Rect::ZERO; 2];
+    assert_eq!(snap_layout(&layout, &mut out), 2);
+    assert_eq!(out[1], Rect::new(0, 0, 10, 10));
+}
+
+#[test]
+fn test_flow_layout_wraps_rows() {
+    // Container de 100px: cabem 3 ícones de 30px com gap de 5 (30+5+30+5+30)
+    let items = [Size::new(30, 20); 5];
+    let mut out = [Rect::ZERO; 8];
+    assert_eq!(flow_layout(&items, 100, 5, &mut out), 5);
+    assert_eq!(out[0], Rect::new(0, 0, 30, 20));
+    assert_eq!(out[2], Rect::new(70, 0, 30, 20));
+    // Segunda linha espaçada por `gap`
+    assert_eq!(out[3], Rect::new(0, 25, 30, 20));
+    assert_eq!(out[4], Rect::new(35, 25, 30, 20));
+    assert!(out[..5].iter().all(|r| r.right() <= 100));
+}
+
+#[test]
+fn test_flow_layout_no_overlap() {
+    let items = [
+        Size::new(40, 10),
+        Size::new(30, 25),
+        Size::new(50, 15),
+        Size::new(120, 8),
+        Size::new(10, 10),
+        Size::new(60, 30),
+    ];
+    let mut out = [Rect::ZERO; 6];
+    assert_eq!(flow_layout(&items, 100, 4, &mut out), 6);
+    for i in 0..6 {
+        for j in (i + 1)..6 {
+            assert!(!out[i].intersects(&out[j]), "{:?} x {:?}", out[i], out[j]);
+        }
+    }
+    // Linha seguinte começa abaixo do item mais alto da anterior
+    assert_eq!(out[2], Rect::new(0, 29, 50, 15));
+    // Item mais largo que o container fica sozinho na linha
+    assert_eq!(out[3], Rect::new(0, 48, 120, 8));
+    assert_eq!(out[4], Rect::new(0, 60, 10, 10));
+
+    // Saída limitada
+    let mut small = [Rect::ZERO; 2];
+    assert_eq!(flow_layout(&items, 100, 4, &mut small), 2);
+}
+
+#[test]
+fn test_flow_layout_stops_at_i32_limit() {
+    // Itens e espaços enormes: sem o limite as coordenadas dariam a volta
+    let big = Size::new(1 << 30, 1 << 30);
+    let items = [big; 6];
+    let mut out = [Rect::ZERO; 6];
+    // O segundo item terminaria em 2.5 * 2^30 (com espaço) ou 2^31
+    assert_eq!(flow_layout(&items, u32::MAX, 1 << 29, &mut out), 1);
+    assert_eq!(flow_layout(&items, u32::MAX, 0, &mut out), 1);
+    // Quebrando linha: o fundo do segundo item seria 2^31
+    assert_eq!(flow_layout(&items, 1 << 30, 0, &mut out), 1);
+
+    let items = [Size::new(1 << 29, 1 << 29); 6];
+    let n = flow_layout(&items, u32::MAX, 0, &mut out);
+    assert_eq!(n, 3);
+    for r in &out[..n] {
+        assert!(r.x >= 0 && r.y >= 0);
+        assert_eq!(r.right() as i64, r.x as i64 + r.width as i64);
+    }
+    for i in 0..n {
+        for j in (i + 1)..n {
+            assert!(!out[i].intersects(&out[j]), "{:?} x {:?}", out[i], out[j]);
+        }
+    }
+}
+
+#[test]
+fn test_constrain_popup_fits_preferred() {
+    let screen = Rect::new(0, 0, 1920, 1080);
+    let menu = Rect::new(0, 0, 200, 300);
+    let anchor = Rect::new(100, 100, 80, 24);
+    assert_eq!(
+        menu.constrain_popup(anchor, screen, PopupGravity::Bottom),
+        Rect::new(100, 124, 200, 300)
+    );
+    assert_eq!(
+        menu.constrain_popup(anchor, screen, PopupGravity::Right),
+        Rect::new(180, 100, 200, 300)
+    );
+}
+
+#[test]
+fn test_constrain_popup_flips_above() {
+    let screen = Rect::new(0, 0, 1920, 1080);
+    let menu = Rect::new(0, 0, 200, 300);
+    let anchor = Rect::new(100, 1000, 80, 24);
+    let placed = menu.constrain_popup(anchor, screen, PopupGravity::Bottom);
+    assert_eq!(placed, Rect::new(100, 700, 200, 300));
+    assert_eq!(placed.bottom(), anchor.y);
+}
+
+#[test]
+fn test_constrain_popup_slides_left() {
+    let screen = Rect::new(0, 0, 1920, 1080);
+    let menu = Rect::new(0, 0, 200, 300);
+    let anchor = Rect::new(1850, 10, 60, 24);
+    let placed = menu.constrain_popup(anchor, screen, PopupGravity::Bottom);
+    assert_eq!(placed, Rect::new(1720, 34, 200, 300));
+    assert!(screen.contains_rect(&placed));
+}
+
+#[test]
+fn test_constrain_popup_no_room_either_side() {
+    // Nem abaixo nem acima cabe: mantém o lado preferido e desliza
+    let screen = Rect::new(0, 0, 800, 400);
+    let menu = Rect::new(0, 0, 100, 300);
+    let anchor = Rect::new(10, 180, 50, 20);
+    let placed = menu.constrain_popup(anchor, screen, PopupGravity::Bottom);
+    assert_eq!(placed, Rect::new(10, 100, 100, 300));
+}
+
+#[test]
+fn test_rect_clamp_to() {
+    let screen = Rect::new(0, 0, 1920, 1080);
+    // Fora da borda direita: desliza para a esquerda
+    let win = Rect::new(1800, 100, 400, 300);
+    assert_eq!(win.clamp_to(screen), Rect::new(1520, 100, 400, 300));
+    // Fora de duas bordas: desliza nos dois eixos
+    let win = Rect::new(-50, 900, 400, 300);
+    assert_eq!(win.clamp_to(screen), Rect::new(0, 780, 400, 300));
+    // Maior que os limites: recortado
+    let win = Rect::new(100, -20, 2000, 500);
+    assert_eq!(win.clamp_to(screen), Rect::new(0, 0, 1920, 500));
+}
+
+#[test]
+fn test_rect_snap_center_to() {
+    let container = Rect::new(0, 0, 1000, 800);
+    // Centro em (504, 397), container em (500, 400)
+    let win = Rect::new(404, 297, 200, 200);
+    assert_eq!(
+        win.snap_center_to(container, 5),
+        Rect::new(400, 300, 200, 200)
+    );
+    // Fora do threshold em X, dentro em Y
+    let far = Rect::new(420, 297, 200, 200);
+    assert_eq!(
+        far.snap_center_to(container, 5),
+        Rect::new(420, 300, 200, 200)
+    );
+}
+
+#[test]
+fn test_rounded_rect_coverage() {
+    let rr = RoundedRect::from_coords(0.0, 0.0, 20.0, 20.0, 5.0);
+    assert_eq!(rr.coverage(PointF::new(10.0, 10.0)), 1.0);
+    assert_eq!(rr.coverage(PointF::new(30.0, 10.0)), 0.0);
+    // Canto fora do arco
+    assert_eq!(rr.coverage(PointF::new(0.5, 0.5)), 0.0);
+    // Exatamente na borda reta: meia cobertura
+    assert!((rr.coverage(PointF::new(0.0, 10.0)) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_rectf_round_out() {
+    let r = RectF::new(1.2, 2.7, 3.5, 1.1);
+    assert_eq!(r.round_out(), Rect::new(1, 2, 4, 2));
+    let neg = RectF::new(-0.5, -1.5, 1.0, 1.0);
+    assert_eq!(neg.round_out(), Rect::new(-1, -2, 2, 2));
+}
+
+fn polygon_bounds(poly: &StaticPolygon) -> (f32, f32, f32, f32) {
+    poly.iter().fold(
+        (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+        |(x0, y0, x1, y1), p| (x0.min(p.x), y0.min(p.y), x1.max(p.x), y1.max(p.y)),
+    )
+}
+
+#[test]
+fn test_rounded_rect_hit_region() {
+    let rr = RoundedRect::from_coords(0.0, 0.0, 100.0, 100.0, 20.0);
+    // Faixa de borda no canto, ao longo da diagonal do arco
+    assert_eq!(rr.hit_region(PointF::new(7.5, 7.5), 5.0), HitRegion::Border);
+    // Canto do retângulo base, mas fora do arco
+    assert_eq!(
+        rr.hit_region(PointF::new(2.0, 2.0), 5.0),
+        HitRegion::Outside
+    );
+    // Borda reta e interior
+    assert_eq!(
+        rr.hit_region(PointF::new(50.0, 2.0), 5.0),
+        HitRegion::Border
+    );
+    assert_eq!(
+        rr.hit_region(PointF::new(50.0, 6.0), 5.0),
+        HitRegion::Interior
+    );
+    assert_eq!(
+        rr.hit_region(PointF::new(50.0, 50.0), 5.0),
+        HitRegion::Interior
+    );
+    assert_eq!(
+        rr.hit_region(PointF::new(150.0, 50.0), 5.0),
+        HitRegion::Outside
+    );
+    assert!(HitRegion::Border.is_inside());
+    assert_eq!(HitRegion::from_u8(2), Some(HitRegion::Interior));
+}
+
+#[test]
+fn test_rounded_rect_border_ring() {
+    let rr = RoundedRect::from_coords(10.0, 20.0, 200.0, 100.0, 12.0);
+    let (outer, inner) = rr.border_ring(4.0);
+    assert_eq!(outer, rr);
+    assert_eq!(inner.rect, RectF::new(14.0, 24.0, 192.0, 92.0));
+    assert_eq!(inner.radius, 8.0);
+
+    // Borda mais grossa que o raio: canto interno reto
+    let (_, inner) = rr.border_ring(20.0);
+    assert_eq!(inner.radius, 0.0);
+    assert_eq!(inner.rect, RectF::new(30.0, 40.0, 160.0, 60.0));
+    // Um ponto no canto do buraco pertence ao interno
+    assert!(inner.signed_distance(PointF::new(30.5, 40.5)) < 0.0);
+}
+
+#[test]
+fn test_rounded_rect_to_polygon_bounds() {
+    let rr = RoundedRect::from_coords(10.0, 20.0, 100.0, 50.0, 8.0);
+    let poly = rr.to_polygon(2);
+    assert!(poly.is_closed());
+    let (x0, y0, x1, y1) = polygon_bounds(&poly);
+    assert!((x0 - 10.0).abs() < 0.01);
+    assert!((y0 - 20.0).abs() < 0.01);
+    assert!((x1 - 110.0).abs() < 0.01);
+    assert!((y1 - 70.0).abs() < 0.01);
+}
+
+#[test]
+fn test_rounded_rect_to_polygon_segments() {
+    let rr = RoundedRect::from_coords(0.0, 0.0, 100.0, 100.0, 10.0);
+    let one = rr.to_polygon(1).len();
+    let two = rr.to_polygon(2).len();
+    assert!(two > one);
+    // Limitado pela capacidade estática
+    assert!(rr.to_polygon(255).len() <= MAX_STATIC_POINTS);
+    // Sem raio: apenas os quatro cantos
+    let square = RoundedRect::from_coords(0.0, 0.0, 10.0, 10.0, 0.0);
+    assert_eq!(square.to_polygon(3).len(), 4);
+}
+
+#[test]
+fn test_rect_split_at_fraction_odd_width() {
+    let r = Rect::new(10, 20, 101, 50);
+    let (left, right) = r.split_at_fraction(0.5, true);
+    assert_eq!(left.width + right.width, 101);
+    assert_eq!(left.width.abs_diff(right.width), 1);
+    assert_eq!(left.right(), right.x);
+    assert_eq!(right.right(), r.right());
+    assert_eq!(left.height, 50);
+
+    let (top, bottom) = r.split_at_fraction(0.25, false);
+    assert_eq!(top.height + bottom.height, 50);
+    assert_eq!(top.bottom(), bottom.y);
+}
+
+#[test]
+fn test_rect_steps_to_endpoints_and_count() {
+    let from = Rect::new(0, 0, 10, 10);
+    let to = Rect::new(100, 50, 210, 110);
+    let steps: Vec<Rect> = from.steps_to(to, 5).collect();
+    assert_eq!(steps.len(), 5);
+    assert_eq!(steps[0], from);
+    assert_eq!(steps[4], to);
+    // Ponto médio cobre a interpolação exata
+    assert_eq!(steps[2], Rect::new(50, 25, 110, 60));
+    for pair in steps.windows(2) {
+        assert!(pair[1].x >= pair[0].x);
+        assert!(pair[1].width >= pair[0].width);
+    }
+}
+
+#[test]
+fn test_rect_steps_to_degenerate_counts() {
+    let from = Rect::new(0, 0, 10, 10);
+    let to = Rect::new(5, 5, 20, 20);
+    assert_eq!(from.steps_to(to, 0).count(), 0);
+    let single: Vec<Rect> = from.steps_to(to, 1).collect();
+    assert_eq!(single, [from]);
+    let pair: Vec<Rect> = from.steps_to(to, 2).collect();
+    assert_eq!(pair, [from, to]);
+}
+
+#[test]
+fn test_rect_ltrb_round_trip() {
+    let r = Rect::new(-10, 20, 30, 40);
+    assert_eq!(r.to_ltrb(), (-10, 20, 20, 60));
+    let (l, t, right, b) = r.to_ltrb();
+    assert_eq!(Rect::from_ltrb(l, t, right, b), r);
+
+    let f = RectF::new(1.5, 2.5, 10.0, 4.0);
+    assert_eq!(f.to_ltrb(), (1.5, 2.5, 11.5, 6.5));
+    let (l, t, right, b) = f.to_ltrb();
+    assert_eq!(RectF::from_ltrb(l, t, right, b), f);
+}
+
+#[test]
+fn test_rect_from_ltrb_negative_extent() {
+    let r = Rect::from_ltrb(50, 10, 20, 30);
+    assert_eq!(r.width, 0);
+    assert_eq!(r.height, 20);
+    assert!(r.is_empty());
+    assert_eq!(Rect::from_ltrb(i32::MIN, 0, i32::MAX, 1).width, u32::MAX);
+
+    let f = RectF::from_ltrb(5.0, 5.0, 1.0, 1.0);
+    assert_eq!((f.width, f.height), (0.0, 0.0));
+}
+
+#[test]
+fn test_rect_split_at_fraction_clamped() {
+    let r = Rect::new(0, 0, 80, 60);
+    let (a, b) = r.split_at_fraction(-1.0, true);
+    assert!(a.is_empty());
+    assert_eq!(b, r);
+    let (a, b) = r.split_at_fraction(2.0, false);
+    assert_eq!(a, r);
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_rect_quad_split_tiles_parent() {
+    for r in [
+        Rect::new(10, -4, 64, 32),
+        Rect::new(0, 0, 5, 7),
+        Rect::new(3, 3, 1, 1),
+    ] {
+        let quads = r.quad_split();
+        let area: u64 = quads.iter().map(|q| q.area()).sum();
+        assert_eq!(area, r.area());
+        let bounds = quads.iter().fold(quads[0], |acc, q| acc.union(q));
+        assert_eq!(bounds, r);
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                assert!(!quads[i].intersects(&quads[j]), "{:?}", r);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rect_quad_split_odd() {
+    let [nw, ne, sw, se] = Rect::new(0, 0, 5, 3).quad_split();
+    assert_eq!(nw, Rect::new(0, 0, 2, 1));
+    assert_eq!(ne, Rect::new(2, 0, 3, 1));
+    assert_eq!(sw, Rect::new(0, 1, 2, 2));
+    assert_eq!(se, Rect::new(2, 1, 3, 2));
+}
+
+#[test]
+fn test_rectf_fit_content_contain_and_cover() {
+    let area = RectF::new(0.0, 0.0, 1920.0, 1080.0);
+    let video = SizeF::new(1440.0, 1080.0);
+    // Contain: pillarbox centralizado
+    assert_eq!(
+        area.fit_content(video, ContentFit::Contain, Alignment::Center),
+        RectF::new(240.0, 0.0, 1440.0, 1080.0)
+    );
+    // Cover: preenche a largura e recorta em cima e embaixo
+    assert_eq!(
+        area.fit_content(video, ContentFit::Cover, Alignment::Center),
+        RectF::new(0.0, -180.0, 1920.0, 1440.0)
+    );
+    assert_eq!(
+        area.fit_content(video, ContentFit::Fill, Alignment::TopLeft),
+        area
+    );
+}
+
+#[test]
+fn test_rectf_fit_content_scale_down_and_align() {
+    let area = RectF::new(10.0, 10.0, 400.0, 300.0);
+    let icon = SizeF::new(100.0, 50.0);
+    // ScaleDown nunca aumenta
+    assert_eq!(
+        area.fit_content(icon, ContentFit::ScaleDown, Alignment::Center),
+        RectF::new(160.0, 135.0, 100.0, 50.0)
+    );
+    // Mas reduz quando não cabe
+    assert_eq!(
+        area.fit_content(
+            SizeF::new(800.0, 300.0),
+            ContentFit::ScaleDown,
+            Alignment::Center
+        ),
+        RectF::new(10.0, 85.0, 400.0, 150.0)
+    );
+    // Alinhamento no canto superior esquerdo
+    assert_eq!(
+        area.fit_content(icon, ContentFit::Contain, Alignment::TopLeft),
+        RectF::new(10.0, 10.0, 400.0, 200.0)
+    );
+    assert_eq!(
+        area.fit_content(icon, ContentFit::None, Alignment::BottomRight),
+        RectF::new(310.0, 260.0, 100.0, 50.0)
+    );
+}
+
+#[test]
+fn test_rect_adjacency_shared_edge() {
+    let left = Rect::new(0, 0, 100, 200);
+    let right = Rect::new(100, 50, 100, 100);
+    assert_eq!(left.gap_to(&right), (0, -100));
+    assert_eq!(left.adjacency(&right), Some(Adjacency::SharesRightEdge));
+    assert_eq!(right.adjacency(&left), Some(Adjacency::SharesLeftEdge));
+
+    let below = Rect::new(20, 200, 50, 10);
+    assert_eq!(left.adjacency(&below), Some(Adjacency::SharesBottomEdge));
+    assert_eq!(below.adjacency(&left), Some(Adjacency::SharesTopEdge));
+
+    let corner = Rect::new(100, 200, 10, 10);
+    assert_eq!(left.adjacency(&corner), Some(Adjacency::DiagonalTouch));
+    assert!(!Adjacency::DiagonalTouch.shares_edge());
+}
+
+#[test]
+fn test_rect_gap_and_overlap() {
+    let a = Rect::new(0, 0, 100, 100);
+    let b = Rect::new(130, 20, 50, 50);
+    assert_eq!(a.gap_to(&b), (30, -50));
+    assert_eq!(b.gap_to(&a), (30, -50));
+    assert_eq!(a.adjacency(&b), None);
+
+    // Sobreposição nos dois eixos
+    let c = Rect::new(90, 95, 50, 50);
+    assert_eq!(a.gap_to(&c), (-10, -5));
+    assert_eq!(a.adjacency(&c), None);
+}
+
+// =============================================================================
+// LINE TESTS
+// =============================================================================
+
+fn assert_dashes(got: &[LineF], expected: &[(f32, f32)]) {
+    assert_eq!(got.len(), expected.len(), "{:?}", got);
+    for (dash, &(a, b)) in got.iter().zip(expected) {
+        let want = LineF::from_coords(a, 0.0, b, 0.0);
+        assert!(dash.approx_eq(&want, 1e-4), "{:?} != {:?}", dash, want);
+    }
+}
+
+#[test]
+fn test_linef_dashed() {
+    let line = LineF::from_coords(0.0, 0.0, 12.0, 0.0);
+    let mut out = [LineF::default(); 8];
+
+    let n = line.dashed(&[4.0, 2.0], 0.0, &mut out);
+    assert_dashes(&out[..n], &[(0.0, 4.0), (6.0, 10.0)]);
+
+    // Fase desloca o padrão para trás ao longo da linha
+    let n = line.dashed(&[4.0, 2.0], 1.0, &mut out);
+    assert_dashes(&out[..n], &[(0.0, 3.0), (5.0, 9.0), (11.0, 12.0)]);
+    let n = line.dashed(&[4.0, 2.0], 4.0, &mut out);
+    assert_dashes(&out[..n], &[(2.0, 6.0), (8.0, 12.0)]);
+    // Fase negativa equivale a fase + período
+    let n = line.dashed(&[4.0, 2.0], -1.0, &mut out);
+    assert_dashes(&out[..n], &[(1.0, 5.0), (7.0, 11.0)]);
+}
+
+#[test]
+fn test_linef_dashed_edge_cases() {
+    let line = LineF::from_coords(0.0, 0.0, 12.0, 0.0);
+    let mut out = [LineF::default(); 8];
+
+    // Padrão ímpar é repetido: [3] vira traço 3, espaço 3
+    let n = line.dashed(&[3.0], 0.0, &mut out);
+    assert_dashes(&out[..n], &[(0.0, 3.0), (6.0, 9.0)]);
+
+    // Padrão inválido: linha sólida
+    assert_eq!(line.dashed(&[], 0.0, &mut out), 1);
+    assert_eq!(out[0], line);
+    assert_eq!(line.dashed(&[0.0, 0.0], 0.0, &mut out), 1);
+
+    // Saída limitada ao tamanho de `out`
+    let mut small = [LineF::default(); 1];
+    assert_eq!(line.dashed(&[1.0, 1.0], 0.0, &mut small), 1);
+
+    // Linha vertical
+    let vertical = LineF::from_coords(5.0, 0.0, 5.0, 6.0);
+    let n = vertical.dashed(&[2.0, 2.0], 0.0, &mut out);
+    assert_eq!(n, 2);
+    assert!(out[1].approx_eq(&LineF::from_coords(5.0, 4.0, 5.0, 6.0), 1e-4));
+}
+
+#[test]
+fn test_linef_dashed_tiny_pattern_terminates() {
+    // Passos abaixo de meio ULP de `pos` não avançariam: linha sólida
+    let long = LineF::from_coords(0.0, 0.0, 100_000.0, 0.0);
+    let mut out = [LineF::default(); 4];
+    assert_eq!(long.dashed(&[1e-3, 1e-3], 0.0, &mut out), 1);
+    assert_eq!(out[0], long);
+    // Também com uma entrada grande dominando o período da fase
+    let short = LineF::from_coords(0.0, 0.0, 10.0, 0.0);
+    assert_eq!(short.dashed(&[1e-6, 1e3], 500.0, &mut out), 1);
+    assert_eq!(out[0], short);
+    // Zeros continuam aceitos
+    assert_eq!(short.dashed(&[4.0, 0.0, 2.0, 2.0], 0.0, &mut out), 3);
+}
+
 // =============================================================================
 // TRANSFORM TESTS
 // =============================================================================
@@ -194,6 +959,72 @@ fn test_transform_scale() {
     assert!((transformed.y - 40.0).abs() < 0.0001);
 }
 
+#[test]
+fn test_transform_is_flipped() {
+    let mirror = Transform2D::scale_xy(-1.0, 1.0);
+    assert!(mirror.is_flipped());
+    assert!(!Transform2D::rotate_degrees(90.0).is_flipped());
+    assert!(!Transform2D::identity().is_flipped());
+}
+
+#[test]
+fn test_transform_has_skew() {
+    assert!(Transform2D::skew(0.5, 0.0).has_skew());
+    assert!(!Transform2D::rotate_degrees(30.0).has_skew());
+    assert!(!Transform2D::scale_xy(2.0, 3.0).has_skew());
+}
+
+#[test]
+fn test_transform_uniform_scale() {
+    let t = Transform2D::scale(2.0).then_translate(5.0, 5.0);
+    assert_eq!(t.uniform_scale(), Some(2.0));
+    assert_eq!(Transform2D::scale_xy(2.0, 3.0).uniform_scale(), None);
+    assert_eq!(Transform2D::rotate_degrees(45.0).uniform_scale(), None);
+}
+
+// =============================================================================
+// APPROX EQ TESTS
+// =============================================================================
+
+#[test]
+fn test_approx_eq_points_and_sizes() {
+    let p = PointF::new(1.0, 2.0);
+    assert!(p.approx_eq(&PointF::new(1.0005, 1.9995), 1e-3));
+    assert!(!p.approx_eq(&PointF::new(1.01, 2.0), 1e-3));
+    assert!(!p.approx_eq(&PointF::new(f32::NAN, 2.0), 1.0));
+
+    let s = SizeF::new(10.0, 20.0);
+    assert!(s.approx_eq(&SizeF::new(10.0001, 20.0), 1e-3));
+    assert!(!s.approx_eq(&SizeF::new(10.0, 20.1), 1e-3));
+}
+
+#[test]
+fn test_approx_eq_rects_and_shapes() {
+    let r = RectF::new(0.0, 0.0, 100.0, 50.0);
+    assert!(r.approx_eq(&RectF::new(0.0004, -0.0004, 100.0, 50.0), 1e-3));
+    assert!(!r.approx_eq(&RectF::new(0.0, 0.0, 100.0, 50.01), 1e-3));
+
+    let rr = RoundedRect::new(r, 8.0);
+    assert!(rr.approx_eq(&RoundedRect::new(r, 8.0005), 1e-3));
+    assert!(!rr.approx_eq(&RoundedRect::new(r, 8.1), 1e-3));
+
+    let c = Circle::new(PointF::new(1.0, 1.0), 5.0);
+    assert!(c.approx_eq(&Circle::new(PointF::new(1.0, 1.0005), 5.0), 1e-3));
+    assert!(!c.approx_eq(&Circle::new(PointF::new(1.0, 1.0), 5.5), 1e-3));
+}
+
+#[test]
+fn test_approx_eq_transform() {
+    // Rotação de 90° duas vezes ~ rotação de 180° (com erro de arredondamento)
+    let quarter = Transform2D::rotate(core::f32::consts::FRAC_PI_2);
+    let half = Transform2D::rotate(core::f32::consts::PI);
+    let composed = quarter.then(&quarter);
+    assert!(composed.approx_eq(&half, 1e-5));
+    assert!(!composed.approx_eq(&Transform2D::identity(), 1e-5));
+    assert!(Transform2D::identity().approx_eq(&Transform2D::translate(0.0001, 0.0), 1e-3));
+    assert!(!Transform2D::identity().approx_eq(&Transform2D::translate(0.1, 0.0), 1e-3));
+}
+
 // =============================================================================
 // INSETS TESTS
 // =============================================================================
@@ -215,3 +1046,117 @@ fn test_insets_symmetric() {
     assert_eq!(i.left, 20);
     assert_eq!(i.right, 20);
 }
+
+#[test]
+fn test_insets_between_centered() {
+    let outer = Rect::new(0, 0, 100, 80);
+    let inner = Rect::new(10, 10, 80, 60);
+    let insets = Insets::between(outer, inner);
+    assert_eq!(insets, Insets::uniform(10));
+    assert_eq!(outer.inset(insets), inner);
+}
+
+#[test]
+fn test_insets_between_asymmetric() {
+    // Moldura de janela: barra de título de 30px e bordas de 4px
+    let frame = Rect::new(100, 50, 808, 634);
+    let client = Rect::new(104, 80, 800, 600);
+    let insets = Insets::between(frame, client);
+    assert_eq!(insets, Insets::new(30, 4, 4, 4));
+    assert_eq!(frame.inset(insets), client);
+
+    // Interno ultrapassando o externo gera valores negativos
+    let overflow = Insets::between(Rect::new(0, 0, 10, 10), Rect::new(-5, 2, 20, 4));
+    assert_eq!(overflow, Insets::new(2, -5, 4, -5));
+    assert_eq!(
+        Rect::new(0, 0, 10, 10).inset(overflow),
+        Rect::new(-5, 2, 20, 4)
+    );
+}
+
+#[test]
+fn test_rect_inset_collapses() {
+    let r = Rect::new(0, 0, 10, 10);
+    assert!(r.inset(Insets::uniform(6)).is_empty());
+}
+
+fn assert_transform_eq(a: &Transform2D, b: &Transform2D) {
+    let pairs = [
+        (a.a, b.a),
+        (a.b, b.b),
+        (a.c, b.c),
+        (a.d, b.d),
+        (a.tx, b.tx),
+        (a.ty, b.ty),
+    ];
+    for (x, y) in pairs {
+        assert!((x - y).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn test_transform_decompose_roundtrip() {
+    let t = Transform2D::scale_xy(2.0, -3.0)
+        .then(&Transform2D::skew(0.3, 0.0))
+        .then_rotate(0.7)
+        .then_translate(10.0, -5.0);
+    let parts = t.decompose().unwrap();
+    assert_eq!(parts.translate_x, 10.0);
+    assert_transform_eq(&Transform2D::from_decomposed(&parts), &t);
+    assert!(Transform2D::scale(0.0).decompose().is_none());
+}
+
+#[test]
+fn test_transform_quad_identity() {
+    let r = RectF::new(10.0, 20.0, 30.0, 40.0);
+    let quad = Transform2D::identity().transform_quad(r);
+    assert_eq!(
+        quad,
+        [
+            PointF::new(10.0, 20.0),
+            PointF::new(40.0, 20.0),
+            PointF::new(40.0, 60.0),
+            PointF::new(10.0, 60.0),
+        ]
+    );
+}
+
+#[test]
+fn test_transform_quad_rotate_90() {
+    let r = RectF::new(0.0, 0.0, 10.0, 20.0);
+    let quad = Transform2D::rotate_degrees(90.0).transform_quad(r);
+    let expected = [
+        PointF::new(0.0, 0.0),
+        PointF::new(0.0, 10.0),
+        PointF::new(-20.0, 10.0),
+        PointF::new(-20.0, 0.0),
+    ];
+    for (p, e) in quad.iter().zip(expected.iter()) {
+        assert!(p.distance(e) < 1e-4, "{:?} != {:?}", p, e);
+    }
+    // A bounding box do quad coincide com transform_rect
+    let aabb = Transform2D::rotate_degrees(90.0).transform_rect(r);
+    assert!((aabb.x + 20.0).abs() < 1e-4 && (aabb.width - 20.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_transform_lerp_rotation() {
+    let start = Transform2D::identity();
+    let end = Transform2D::rotate_degrees(90.0);
+    let mid = start.lerp(&end, 0.5);
+    assert_transform_eq(&mid, &Transform2D::rotate_degrees(45.0));
+    assert!((mid.determinant() - 1.0).abs() < 1e-4);
+
+    // Menor arco: de 170° para -170° passa por 180°
+    let a = Transform2D::rotate_degrees(170.0);
+    let b = Transform2D::rotate_degrees(-170.0);
+    assert_transform_eq(&a.lerp(&b, 0.5), &Transform2D::rotate_degrees(180.0));
+}
+
+#[test]
+fn test_transform_lerp_degenerate() {
+    let start = Transform2D::scale(0.0);
+    let end = Transform2D::translate(10.0, 20.0);
+    let mid = start.lerp(&end, 0.5);
+    assert_transform_eq(&mid, &Transform2D::new(0.5, 0.0, 0.0, 0.5, 5.0, 10.0));
+}
@@ -0,0 +1,324 @@
+//! # Testes de Input
+//!
+//! Testes para os tipos de input.
+
+use gfx_types::buffer::{BufferDescriptor, BufferHandle};
+use gfx_types::color::PixelFormat;
+use gfx_types::geometry::{Point, PointF, Rect};
+use gfx_types::input::*;
+use gfx_types::window::ResizeEdge;
+
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+// =============================================================================
+// CURSOR TYPE TESTS
+// =============================================================================
+
+#[test]
+fn test_cursor_for_resize_angle() {
+    assert_eq!(CursorType::for_resize_angle(0.0), CursorType::ResizeE);
+    assert_eq!(CursorType::for_resize_angle(FRAC_PI_2), CursorType::ResizeS);
+    assert_eq!(CursorType::for_resize_angle(PI), CursorType::ResizeW);
+    assert_eq!(
+        CursorType::for_resize_angle(-FRAC_PI_2),
+        CursorType::ResizeN
+    );
+    assert_eq!(
+        CursorType::for_resize_angle(FRAC_PI_4),
+        CursorType::ResizeSE
+    );
+    assert_eq!(
+        CursorType::for_resize_angle(-FRAC_PI_4),
+        CursorType::ResizeNE
+    );
+    assert_eq!(
+        CursorType::for_resize_angle(3.0 * FRAC_PI_4),
+        CursorType::ResizeSW
+    );
+    assert_eq!(
+        CursorType::for_resize_angle(-3.0 * FRAC_PI_4),
+        CursorType::ResizeNW
+    );
+    // Perto do eixo arredonda para o cardinal
+    assert_eq!(CursorType::for_resize_angle(0.3), CursorType::ResizeE);
+    assert_eq!(CursorType::for_resize_angle(2.0 * PI), CursorType::ResizeE);
+}
+
+#[test]
+fn test_cursor_for_edge() {
+    assert_eq!(CursorType::for_edge(ResizeEdge::Top), CursorType::ResizeN);
+    assert_eq!(
+        CursorType::for_edge(ResizeEdge::BottomRight),
+        CursorType::ResizeSE
+    );
+    assert!(CursorType::for_edge(ResizeEdge::Left).is_resize());
+}
+
+// =============================================================================
+// CURSOR IMAGE TESTS
+// =============================================================================
+
+#[test]
+fn test_cursor_image_bounds() {
+    let desc = BufferDescriptor::new(32, 32, PixelFormat::ARGB8888);
+    let image = CursorImage::new(desc, CursorHotspot::new(4, 6), BufferHandle::new(1, 0));
+    assert!(image.is_valid());
+    assert_eq!(image.bounds(), Rect::new(-4, -6, 32, 32));
+    assert_eq!(
+        image.bounds_at(Point::new(100, 200)),
+        Rect::new(96, 194, 32, 32)
+    );
+}
+
+#[test]
+fn test_cursor_image_hotspot_validation() {
+    let desc = BufferDescriptor::new(16, 16, PixelFormat::ARGB8888);
+    let handle = BufferHandle::new(1, 0);
+    assert!(CursorImage::new(desc, CursorHotspot::new(15, 15), handle).is_valid());
+    assert!(!CursorImage::new(desc, CursorHotspot::new(16, 0), handle).is_valid());
+    assert!(!CursorImage::new(desc, CursorHotspot::new(0, -1), handle).is_valid());
+}
+
+// =============================================================================
+// ANIMATED CURSOR TESTS
+// =============================================================================
+
+fn spinner_frame(id: u32) -> CursorImage {
+    let desc = BufferDescriptor::new(16, 16, PixelFormat::ARGB8888);
+    CursorImage::new(desc, CursorHotspot::new(8, 8), BufferHandle::new(id, 0))
+}
+
+fn spinner() -> AnimatedCursor {
+    let mut cursor = AnimatedCursor::new(spinner_frame(1), 100);
+    assert!(cursor.push(spinner_frame(2), 50));
+    assert!(cursor.push(spinner_frame(3), 150));
+    cursor
+}
+
+#[test]
+fn test_animated_cursor_frame_sequence() {
+    let cursor = spinner();
+    assert_eq!(cursor.len(), 3);
+    assert_eq!(cursor.total_duration(), 300);
+
+    let (image, remaining) = cursor.frame_at(0);
+    assert_eq!(*image, spinner_frame(1));
+    assert_eq!(remaining, 100);
+
+    let (image, remaining) = cursor.frame_at(99);
+    assert_eq!(*image, spinner_frame(1));
+    assert_eq!(remaining, 1);
+
+    let (image, remaining) = cursor.frame_at(100);
+    assert_eq!(*image, spinner_frame(2));
+    assert_eq!(remaining, 50);
+
+    assert_eq!(*cursor.frame_at(160).0, spinner_frame(3));
+}
+
+#[test]
+fn test_animated_cursor_wraps() {
+    let cursor = spinner();
+    let (image, remaining) = cursor.frame_at(300);
+    assert_eq!(*image, spinner_frame(1));
+    assert_eq!(remaining, 100);
+    assert_eq!(*cursor.frame_at(3 * 300 + 120).0, spinner_frame(2));
+
+    // Todas as durações zero: estático no primeiro frame
+    let still = AnimatedCursor::new(spinner_frame(7), 0);
+    assert_eq!(still.frame_at(12345), (&spinner_frame(7), 0));
+
+    let mut full = AnimatedCursor::new(spinner_frame(1), 10);
+    for i in 1..MAX_CURSOR_FRAMES {
+        assert!(full.push(spinner_frame(i as u32 + 1), 10));
+    }
+    assert!(!full.push(spinner_frame(99), 10));
+}
+
+// =============================================================================
+// GESTURE RECOGNIZER TESTS
+// =============================================================================
+
+fn touch(id: u32, phase: TouchPhase, x: f32, y: f32) -> TouchPoint {
+    TouchPoint::new(TouchId::new(id), phase, PointF::new(x, y))
+}
+
+#[test]
+fn test_gesture_tap_and_double_tap() {
+    let mut rec = GestureRecognizer::new();
+    assert_eq!(
+        rec.update(&touch(1, TouchPhase::Begin, 100.0, 100.0), 0),
+        None
+    );
+    assert_eq!(
+        rec.update(&touch(1, TouchPhase::Move, 102.0, 101.0), 20_000),
+        None
+    );
+    let event = rec.update(&touch(1, TouchPhase::End, 102.0, 101.0), 80_000);
+    assert_eq!(
+        event,
+        Some(GestureEvent::Tap {
+            position: PointF::new(102.0, 101.0)
+        })
+    );
+    assert_eq!(rec.active_touches(), 0);
+
+    rec.update(&touch(2, TouchPhase::Begin, 104.0, 100.0), 200_000);
+    let event = rec.update(&touch(2, TouchPhase::End, 104.0, 100.0), 250_000);
+    assert_eq!(
+        event.map(|e| e.gesture_type()),
+        Some(GestureType::DoubleTap)
+    );
+}
+
+#[test]
+fn test_gesture_long_press() {
+    let mut rec = GestureRecognizer::new();
+    rec.update(&touch(1, TouchPhase::Begin, 50.0, 60.0), 1_000_000);
+    assert_eq!(rec.tick(1_200_000), None);
+    assert_eq!(
+        rec.tick(1_600_000),
+        Some(GestureEvent::LongPress {
+            position: PointF::new(50.0, 60.0)
+        })
+    );
+    // Soltar depois do long-press não gera tap
+    assert_eq!(
+        rec.update(&touch(1, TouchPhase::End, 50.0, 60.0), 1_700_000),
+        None
+    );
+}
+
+#[test]
+fn test_gesture_pan_and_swipe() {
+    let mut rec = GestureRecognizer::new();
+    rec.update(&touch(1, TouchPhase::Begin, 100.0, 300.0), 0);
+    let event = rec.update(&touch(1, TouchPhase::Move, 150.0, 302.0), 30_000);
+    assert_eq!(event.map(|e| e.gesture_type()), Some(GestureType::Pan));
+    let event = rec.update(&touch(1, TouchPhase::Move, 250.0, 304.0), 60_000);
+    assert_eq!(
+        event,
+        Some(GestureEvent::Pan {
+            delta: PointF::new(100.0, 2.0)
+        })
+    );
+    match rec.update(&touch(1, TouchPhase::End, 300.0, 305.0), 100_000) {
+        Some(GestureEvent::Swipe {
+            direction,
+            velocity,
+        }) => {
+            assert_eq!(direction, SwipeDirection::Right);
+            assert!(velocity > 1900.0);
+        }
+        other => panic!("esperava swipe, veio {:?}", other),
+    }
+}
+
+#[test]
+fn test_gesture_slow_pan_is_not_swipe() {
+    let mut rec = GestureRecognizer::new();
+    rec.update(&touch(1, TouchPhase::Begin, 0.0, 0.0), 0);
+    rec.update(&touch(1, TouchPhase::Move, 0.0, 50.0), 500_000);
+    assert_eq!(
+        rec.update(&touch(1, TouchPhase::End, 0.0, 60.0), 1_000_000),
+        None
+    );
+}
+
+#[test]
+fn test_gesture_two_finger_pinch() {
+    let mut rec = GestureRecognizer::new();
+    rec.update(&touch(1, TouchPhase::Begin, 100.0, 100.0), 0);
+    rec.update(&touch(2, TouchPhase::Begin, 200.0, 100.0), 10_000);
+    assert_eq!(rec.active_touches(), 2);
+
+    let event = rec.update(&touch(2, TouchPhase::Move, 300.0, 100.0), 50_000);
+    match event {
+        Some(GestureEvent::Pinch { scale }) => assert!((scale - 2.0).abs() < 1e-4),
+        other => panic!("esperava pinch, veio {:?}", other),
+    }
+    // Gesto travado em pinch mesmo com rotação
+    let event = rec.update(&touch(1, TouchPhase::Move, 100.0, 50.0), 60_000);
+    assert_eq!(event.map(|e| e.gesture_type()), Some(GestureType::Pinch));
+
+    // Soltar os dedos não gera tap
+    assert_eq!(
+        rec.update(&touch(1, TouchPhase::End, 100.0, 50.0), 70_000),
+        None
+    );
+    assert_eq!(
+        rec.update(&touch(2, TouchPhase::End, 300.0, 100.0), 80_000),
+        None
+    );
+    assert_eq!(rec.active_touches(), 0);
+}
+
+#[test]
+fn test_gesture_two_finger_rotate() {
+    let mut rec = GestureRecognizer::new();
+    rec.update(&touch(1, TouchPhase::Begin, 0.0, 0.0), 0);
+    rec.update(&touch(2, TouchPhase::Begin, 100.0, 0.0), 0);
+    // Mesma distância, 90 graus
+    match rec.update(&touch(2, TouchPhase::Move, 0.0, 100.0), 50_000) {
+        Some(GestureEvent::Rotate { angle }) => {
+            assert!((angle - core::f32::consts::FRAC_PI_2).abs() < 1e-3)
+        }
+        other => panic!("esperava rotate, veio {:?}", other),
+    }
+}
+
+#[test]
+fn test_gesture_cancel_and_unknown_touch() {
+    let mut rec = GestureRecognizer::new();
+    assert_eq!(rec.update(&touch(9, TouchPhase::Move, 0.0, 0.0), 0), None);
+    rec.update(&touch(1, TouchPhase::Begin, 0.0, 0.0), 0);
+    rec.update(&touch(1, TouchPhase::Cancel, 0.0, 0.0), 10_000);
+    assert_eq!(rec.active_touches(), 0);
+    assert_eq!(rec.tick(10_000_000), None);
+}
+
+// =============================================================================
+// POINTER MAPPING TESTS
+// =============================================================================
+
+#[test]
+fn test_map_pointer_touchpad_to_screen() {
+    let touchpad = Rect::new(0, 0, 4096, 4096);
+    let screen = Rect::new(0, 0, 1920, 1080);
+    assert_eq!(
+        map_pointer(PointF::new(2048.0, 1024.0), touchpad, screen),
+        PointF::new(960.0, 270.0)
+    );
+    // Segundo monitor à direita
+    let right = Rect::new(1920, 0, 2560, 1440);
+    assert_eq!(
+        map_pointer(PointF::new(4096.0, 0.0), touchpad, right),
+        PointF::new(4480.0, 0.0)
+    );
+    // Origem de `from` deslocada
+    assert_eq!(
+        map_pointer(
+            PointF::new(150.0, 150.0),
+            Rect::new(100, 100, 100, 100),
+            screen
+        ),
+        PointF::new(960.0, 540.0)
+    );
+    // Área de origem vazia
+    assert_eq!(
+        map_pointer(PointF::new(5.0, 5.0), Rect::ZERO, right),
+        PointF::new(1920.0, 0.0)
+    );
+}
+
+// =============================================================================
+// TOUCH ID TESTS
+// =============================================================================
+
+#[test]
+fn test_touch_id_display() {
+    assert_eq!(format!("{}", TouchId::new(0)), "Touch(#0)");
+    assert_eq!(format!("{}", TouchId::INVALID), "Touch(INVALID)");
+    assert!(TouchId::INVALID.is_reserved());
+    assert!(!TouchId::new(3).is_reserved());
+}
@@ -0,0 +1,66 @@
+//! # Testes de Render
+//!
+//! Testes para parâmetros de renderização.
+
+use gfx_types::buffer::BufferHandle;
+use gfx_types::color::{BlendMode, Color};
+use gfx_types::geometry::Rect;
+use gfx_types::render::*;
+
+// =============================================================================
+// BLIT PARAMS TESTS
+// =============================================================================
+
+#[test]
+fn test_blit_resolve_inside() {
+    let blit = BlitParams::new(BufferHandle::INVALID, Rect::new(0, 0, 50, 50), 10, 10);
+    let (src, dst) = blit.resolve(Rect::new(0, 0, 100, 100)).unwrap();
+    assert_eq!(src, Rect::new(0, 0, 50, 50));
+    assert_eq!(dst, Rect::new(10, 10, 50, 50));
+}
+
+#[test]
+fn test_blit_resolve_partly_off_left() {
+    let blit = BlitParams::new(BufferHandle::INVALID, Rect::new(5, 5, 50, 40), -20, -10);
+    let (src, dst) = blit.resolve(Rect::new(0, 0, 100, 100)).unwrap();
+    assert_eq!(dst, Rect::new(0, 0, 30, 30));
+    assert_eq!(src, Rect::new(25, 15, 30, 30));
+}
+
+#[test]
+fn test_blit_resolve_off_screen() {
+    let blit = BlitParams::new(BufferHandle::INVALID, Rect::new(0, 0, 50, 50), 200, 0);
+    assert!(blit.resolve(Rect::new(0, 0, 100, 100)).is_none());
+}
+
+// =============================================================================
+// PIPELINE STATE TESTS
+// =============================================================================
+
+#[test]
+fn test_pipeline_apply_pixel_global_alpha() {
+    let state = PipelineState::new()
+        .with_blend(BlendMode::SourceOver)
+        .with_alpha(128);
+    let out = state.apply_pixel(Color::RED, Color::BLUE);
+    assert_eq!(out.alpha(), 255);
+    assert!(out.red().abs_diff(128) <= 1);
+    assert_eq!(out.green(), 0);
+    assert!(out.blue().abs_diff(127) <= 1);
+
+    // Alpha global 255 não altera o source
+    let opaque = PipelineState::new().with_blend(BlendMode::SourceOver);
+    assert_eq!(opaque.apply_pixel(Color::RED, Color::BLUE), Color::RED);
+}
+
+#[test]
+fn test_pipeline_apply_pixel_xor() {
+    let state = PipelineState::new().with_raster_op(RasterOp::Xor);
+    assert_eq!(state.blend_mode, BlendMode::Normal);
+    let out = state.apply_pixel(Color::RED, Color::WHITE);
+    assert_eq!(out, Color::argb(255, 0, 255, 255));
+    // Aplicar de novo desfaz o XOR
+    assert_eq!(state.apply_pixel(Color::RED, out), Color::WHITE);
+
+    assert_eq!(RasterOp::Nor.apply(0xF0, 0x0F), 0xFFFF_FF00);
+}
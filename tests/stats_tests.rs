@@ -0,0 +1,54 @@
+//! # Testes de Stats
+//!
+//! Testes para estatísticas de frame.
+
+use gfx_types::display::DisplayMode;
+use gfx_types::stats::*;
+
+// =============================================================================
+// FRAME STATS TESTS
+// =============================================================================
+
+#[test]
+fn test_frame_stats_steady_60fps() {
+    let mut stats = FrameStats::new();
+    for _ in 0..200 {
+        stats.push(16_667);
+    }
+    assert_eq!(stats.len(), FRAME_STATS_CAPACITY);
+    assert!((stats.average_fps() - 60.0).abs() < 0.1);
+    assert_eq!(stats.p99_frame_time(), 16_667);
+    assert!(stats.jitter() < 1.0);
+    assert_eq!(
+        stats.dropped_frames(&DisplayMode::new(1920, 1080, 60_000)),
+        0
+    );
+}
+
+#[test]
+fn test_frame_stats_spikes() {
+    let mut stats = FrameStats::new();
+    for i in 0..100 {
+        // Dois picos: um frame de 2 períodos e outro de 3
+        let dt = match i {
+            10 => 33_333,
+            50 => 50_000,
+            _ => 16_667,
+        };
+        stats.push(dt);
+    }
+    assert_eq!(stats.p99_frame_time(), 33_333);
+    assert!(stats.jitter() > 1000.0);
+    assert_eq!(
+        stats.dropped_frames(&DisplayMode::new(1920, 1080, 60_000)),
+        3
+    );
+}
+
+#[test]
+fn test_frame_stats_empty() {
+    let stats = FrameStats::new();
+    assert!(stats.is_empty());
+    assert_eq!(stats.average_fps(), 0.0);
+    assert_eq!(stats.p99_frame_time(), 0);
+}
@@ -0,0 +1,62 @@
+//! # Testes de Text
+//!
+//! Testes para os tipos de tipografia.
+
+use gfx_types::geometry::{ApproxEq, RectF, Transform2D};
+use gfx_types::text::*;
+
+// =============================================================================
+// GLYPH TESTS
+// =============================================================================
+
+#[test]
+fn test_glyph_id_display() {
+    assert_eq!(format!("{}", GlyphId::new(65)), "Glyph(#65)");
+    assert_eq!(format!("{}", GlyphId::NOTDEF), "Glyph(NOTDEF)");
+    assert!(GlyphId::NOTDEF.is_reserved());
+    assert!(!GlyphId::new(65).is_reserved());
+}
+
+// =============================================================================
+// RUN BOUNDS TESTS
+// =============================================================================
+
+fn three_glyph_run() -> ([GlyphPosition; 3], [GlyphMetrics; 3]) {
+    let m = GlyphMetrics::monospace(8.0, 16.0);
+    let positions = [
+        GlyphPosition::new(GlyphId::new(1), 10.0, 30.0),
+        GlyphPosition::new(GlyphId::new(2), 18.0, 30.0),
+        GlyphPosition::new(GlyphId::new(3), 26.0, 30.0),
+    ];
+    (positions, [m; 3])
+}
+
+#[test]
+fn test_text_run_bounds_horizontal() {
+    let (positions, metrics) = three_glyph_run();
+    let bounds = text_run_bounds(&positions, &metrics, &Transform2D::identity());
+    // Baseline em y=30, glyphs de 16px acima dela
+    assert!(bounds.approx_eq(&RectF::new(10.0, 14.0, 24.0, 16.0), 1e-4));
+
+    // Glyph sem tinta (espaço) não amplia a caixa
+    let mut spaced = metrics;
+    spaced[2] = GlyphMetrics::new(0.0, 0.0, 8.0);
+    let bounds = text_run_bounds(&positions, &spaced, &Transform2D::identity());
+    assert!(bounds.approx_eq(&RectF::new(10.0, 14.0, 16.0, 16.0), 1e-4));
+
+    assert_eq!(
+        text_run_bounds(&[], &[], &Transform2D::identity()),
+        RectF::ZERO
+    );
+}
+
+#[test]
+fn test_text_run_bounds_rotated() {
+    let (positions, metrics) = three_glyph_run();
+    let t = Transform2D::rotate(core::f32::consts::FRAC_PI_2);
+    let bounds = text_run_bounds(&positions, &metrics, &t);
+    // 90°: (x, y) -> (-y, x)
+    assert!(bounds.approx_eq(&RectF::new(-30.0, 10.0, 16.0, 24.0), 1e-3));
+    assert!(bounds.width.approx_eq(&16.0, 1e-3));
+    assert!(bounds.height.approx_eq(&24.0, 1e-3));
+}
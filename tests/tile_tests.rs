@@ -0,0 +1,83 @@
+//! # Testes de Tiling
+//!
+//! Testes para os layouts de tiling.
+
+use gfx_types::geometry::Rect;
+use gfx_types::tile::*;
+
+/// Verifica que os retângulos cobrem o container exatamente, sem sobreposição.
+fn assert_exact_tiling(container: Rect, rects: &[Rect]) {
+    let area: u64 = rects.iter().map(|r| r.area()).sum();
+    assert_eq!(area, container.area());
+    for (i, a) in rects.iter().enumerate() {
+        assert!(container.contains_rect(a));
+        for b in &rects[i + 1..] {
+            assert!(!a.intersects(b));
+        }
+    }
+    let bounds = rects.iter().fold(Rect::ZERO, |acc, r| acc.union(r));
+    assert_eq!(bounds, container);
+}
+
+// =============================================================================
+// TILE TESTS
+// =============================================================================
+
+#[test]
+fn test_tile_columns_distributes_remainder() {
+    let container = Rect::new(10, 20, 101, 50);
+    let mut rects = [Rect::ZERO; 3];
+    for (slot, r) in rects.iter_mut().zip(tile_columns(container, 3)) {
+        *slot = r;
+    }
+    assert_eq!(rects[0].width, 34);
+    assert_eq!(rects[1].width, 34);
+    assert_eq!(rects[2].width, 33);
+    assert_exact_tiling(container, &rects);
+}
+
+#[test]
+fn test_tile_rows() {
+    let container = Rect::new(0, 0, 100, 99);
+    let mut rects = [Rect::ZERO; 4];
+    for (slot, r) in rects.iter_mut().zip(tile_rows(container, 4)) {
+        *slot = r;
+    }
+    assert_exact_tiling(container, &rects);
+}
+
+#[test]
+fn test_tile_grid() {
+    let container = Rect::new(0, 0, 1920, 1080);
+    for n in 1..=10 {
+        let mut rects = [Rect::ZERO; 10];
+        let mut count = 0;
+        for r in tile_grid(container, n) {
+            rects[count] = r;
+            count += 1;
+        }
+        assert_eq!(count, n);
+        assert_exact_tiling(container, &rects[..count]);
+    }
+}
+
+#[test]
+fn test_tile_master_stack() {
+    let container = Rect::new(0, 0, 1000, 600);
+    let mut rects = [Rect::ZERO; 4];
+    for (slot, r) in rects.iter_mut().zip(tile_master_stack(container, 4, 0.6)) {
+        *slot = r;
+    }
+    assert_eq!(rects[0], Rect::new(0, 0, 600, 600));
+    assert_eq!(rects[1].x, 600);
+    assert_eq!(rects[1].width, 400);
+    assert_exact_tiling(container, &rects);
+}
+
+#[test]
+fn test_tile_master_stack_single() {
+    let container = Rect::new(0, 0, 1000, 600);
+    let mut iter = tile_master_stack(container, 1, 0.6);
+    assert_eq!(iter.next(), Some(container));
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,392 @@
+//! # Testes de Window
+//!
+//! Testes para os tipos de janela.
+
+use gfx_types::buffer::{BufferDescriptor, BufferHandle};
+use gfx_types::color::PixelFormat;
+use gfx_types::display::{ConnectorType, DisplayInfo, OutputInfo};
+use gfx_types::geometry::{Point, Rect, Size};
+use gfx_types::input::SwipeDirection;
+use gfx_types::window::*;
+
+// =============================================================================
+// SURFACE TESTS
+// =============================================================================
+
+#[test]
+fn test_surface_debug_color() {
+    let id = SurfaceId::new(42);
+    assert_eq!(id.debug_color(), SurfaceId::new(42).debug_color());
+    assert!(id.debug_color().is_opaque());
+    assert_ne!(id.debug_color(), SurfaceId::new(43).debug_color());
+}
+
+#[test]
+fn test_surface_id_display() {
+    assert_eq!(format!("{}", SurfaceId::new(42)), "Surface(#42)");
+    assert_eq!(format!("{}", SurfaceId::INVALID), "Surface(INVALID)");
+    assert!(SurfaceId::INVALID.is_reserved());
+    assert!(!SurfaceId::new(1).is_reserved());
+}
+
+#[test]
+fn test_surface_preferred_buffer_scale() {
+    let output = |width_mm, height_mm| OutputInfo {
+        id: 0,
+        connector: ConnectorType::DisplayPort,
+        connected: true,
+        width_mm,
+        height_mm,
+    };
+    // Monitor 4K de 32" (~140 DPI, 1x) e painel 2560x1600 de 13" (~227 DPI,
+    // 2x): a escala vem da densidade física, não da resolução
+    let monitor = (
+        DisplayInfo::new(1, 3840, 2160, 60000, PixelFormat::XRGB8888, 15360),
+        output(697, 392),
+    );
+    let laptop = (
+        DisplayInfo::new(2, 2560, 1600, 60000, PixelFormat::XRGB8888, 10240),
+        output(286, 179),
+    );
+    let config = SurfaceConfig::new(800, 600);
+    // Superfície atravessando um display 1x e um 2x
+    assert_eq!(config.preferred_buffer_scale(&[monitor, laptop]), 2);
+    assert_eq!(config.preferred_buffer_scale(&[monitor]), 1);
+    assert_eq!(config.preferred_buffer_scale(&[]), 1);
+    // Dimensões físicas desconhecidas contam como 1x
+    let unknown = (laptop.0, output(0, 0));
+    assert_eq!(config.preferred_buffer_scale(&[unknown]), 1);
+}
+
+#[test]
+fn test_surface_commit_validate_scale() {
+    let logical = Size::new(800, 600);
+    let commit = SurfaceCommit::new(BufferHandle::new(1, 0)).with_scale(2);
+    let matching = BufferDescriptor::new(1600, 1200, PixelFormat::ARGB8888);
+    let mismatched = BufferDescriptor::new(800, 600, PixelFormat::ARGB8888);
+    assert!(commit.validate_scale(&matching, logical));
+    assert!(!commit.validate_scale(&mismatched, logical));
+    assert!(!commit
+        .with_scale(0)
+        .validate_scale(&BufferDescriptor::new(0, 0, PixelFormat::ARGB8888), logical));
+}
+
+// =============================================================================
+// SURFACE TREE TESTS
+// =============================================================================
+
+#[test]
+fn test_surface_tree_subtree_bounds() {
+    let top = SurfaceId::new(1);
+    let menu = SurfaceId::new(2);
+    let submenu = SurfaceId::new(3);
+    let other = SurfaceId::new(4);
+
+    let mut tree = SurfaceTree::new();
+    assert!(tree.insert(top, SurfaceId::INVALID, Rect::new(100, 100, 400, 300)));
+    assert!(tree.insert(submenu, menu, Rect::new(560, 200, 100, 150)));
+    assert!(tree.insert(menu, top, Rect::new(450, 150, 120, 200)));
+    assert!(tree.insert(other, SurfaceId::INVALID, Rect::new(0, 0, 10, 10)));
+    assert!(!tree.insert(top, SurfaceId::INVALID, Rect::ZERO));
+
+    assert_eq!(
+        tree.subtree_bounds(top),
+        Some(Rect::new(100, 100, 560, 300))
+    );
+    assert_eq!(
+        tree.subtree_bounds(menu),
+        Some(Rect::new(450, 150, 210, 200))
+    );
+    assert_eq!(tree.subtree_bounds(other), Some(Rect::new(0, 0, 10, 10)));
+    assert_eq!(tree.subtree_bounds(SurfaceId::new(99)), None);
+}
+
+// =============================================================================
+// FOCUS TESTS
+// =============================================================================
+
+/// Grade 3x3 de células 100x100 com 10px de espaço.
+fn focus_grid() -> [Rect; 9] {
+    core::array::from_fn(|i| {
+        let col = (i % 3) as i32;
+        let row = (i / 3) as i32;
+        Rect::new(col * 110, row * 110, 100, 100)
+    })
+}
+
+#[test]
+fn test_next_focusable_adjacent() {
+    let grid = focus_grid();
+    assert_eq!(next_focusable(&grid, 0, SwipeDirection::Right), Some(1));
+    assert_eq!(next_focusable(&grid, 0, SwipeDirection::Down), Some(3));
+    assert_eq!(next_focusable(&grid, 4, SwipeDirection::Left), Some(3));
+    assert_eq!(next_focusable(&grid, 4, SwipeDirection::Up), Some(1));
+}
+
+#[test]
+fn test_next_focusable_edge() {
+    let grid = focus_grid();
+    assert_eq!(next_focusable(&grid, 0, SwipeDirection::Left), None);
+    assert_eq!(next_focusable(&grid, 0, SwipeDirection::Up), None);
+    assert_eq!(next_focusable(&grid, 8, SwipeDirection::Right), None);
+    assert_eq!(next_focusable(&grid, 99, SwipeDirection::Right), None);
+}
+
+#[test]
+fn test_next_focusable_far_apart() {
+    // Distâncias além de i32 e pontuações além de i64 não estouram
+    let rects = [
+        Rect::new(-2_000_000_000, 0, 10, 10),
+        Rect::new(2_000_000_000, 0, 10, 10),
+        Rect::new(1_000_000_000, 0, 10, 10),
+    ];
+    assert_eq!(next_focusable(&rects, 0, SwipeDirection::Right), Some(2));
+    assert_eq!(next_focusable(&rects, 1, SwipeDirection::Left), Some(2));
+    assert_eq!(
+        next_focusable(&rects[..2], 0, SwipeDirection::Right),
+        Some(1)
+    );
+}
+
+// =============================================================================
+// HIT TEST TESTS
+// =============================================================================
+
+#[test]
+fn test_hit_test_layers() {
+    let windows = [
+        (
+            LayerType::Overlay,
+            Rect::new(50, 50, 100, 100),
+            WindowFlags::NONE,
+        ),
+        (
+            LayerType::Normal,
+            Rect::new(0, 0, 200, 200),
+            WindowFlags::NONE,
+        ),
+        (
+            LayerType::Normal,
+            Rect::new(100, 100, 200, 200),
+            WindowFlags::NONE,
+        ),
+    ];
+    // Overlay cobre a janela normal, mesmo vindo antes na lista
+    assert_eq!(hit_test(&windows, Point::new(60, 60)), Some(0));
+    // Mesma camada: a mais tarde na lista fica acima
+    assert_eq!(hit_test(&windows, Point::new(180, 180)), Some(2));
+    assert_eq!(hit_test(&windows, Point::new(10, 10)), Some(1));
+    // Fora de todas
+    assert_eq!(hit_test(&windows, Point::new(500, 500)), None);
+}
+
+#[test]
+fn test_hit_test_input_transparent() {
+    let windows = [
+        (
+            LayerType::Normal,
+            Rect::new(0, 0, 200, 200),
+            WindowFlags::NONE,
+        ),
+        (
+            LayerType::Overlay,
+            Rect::new(0, 0, 200, 200),
+            WindowFlags::INPUT_TRANSPARENT,
+        ),
+        (
+            LayerType::Top,
+            Rect::new(0, 0, 50, 50),
+            WindowFlags::NO_FOCUS,
+        ),
+    ];
+    assert_eq!(hit_test(&windows, Point::new(10, 10)), Some(0));
+}
+
+// =============================================================================
+// PAINT LIST TESTS
+// =============================================================================
+
+#[test]
+fn test_paint_list_order() {
+    let rect = Rect::new(0, 0, 100, 100);
+    let mut list = PaintList::new();
+    assert!(list.push(LayerType::Overlay, 0, SurfaceId::new(4), rect));
+    assert!(list.push(LayerType::Normal, 2, SurfaceId::new(3), rect));
+    assert!(list.push(LayerType::Background, 0, SurfaceId::new(1), rect));
+    assert!(list.push(LayerType::Normal, 1, SurfaceId::new(2), rect));
+
+    let paint: Vec<u32> = list.iter_back_to_front().map(|e| e.surface.0).collect();
+    assert_eq!(paint, [1, 2, 3, 4]);
+    let hit: Vec<u32> = list.iter_front_to_back().map(|e| e.surface.0).collect();
+    assert_eq!(hit, [4, 3, 2, 1]);
+}
+
+#[test]
+fn test_paint_list_stable_and_bounded() {
+    let rect = Rect::new(0, 0, 10, 10);
+    let mut list = PaintList::new();
+    // Mesma chave: ordem de inserção preservada
+    assert!(list.push(LayerType::Normal, 5, SurfaceId::new(1), rect));
+    assert!(list.push(LayerType::Normal, 5, SurfaceId::new(2), rect));
+    let ids: Vec<u32> = list.iter_back_to_front().map(|e| e.surface.0).collect();
+    assert_eq!(ids, [1, 2]);
+
+    for i in 2..MAX_PAINT_ENTRIES as u32 {
+        assert!(list.push(LayerType::Top, i, SurfaceId::new(i + 1), rect));
+    }
+    assert!(!list.push(LayerType::Cursor, 0, SurfaceId::new(999), rect));
+    assert_eq!(list.len(), MAX_PAINT_ENTRIES);
+    assert!(LayerType::Top.stacking_key(0) > LayerType::Normal.stacking_key(u32::MAX));
+}
+
+// =============================================================================
+// SPATIAL GRID TESTS
+// =============================================================================
+
+fn sample_grid() -> SpatialGrid {
+    let mut grid = SpatialGrid::new(Rect::new(0, 0, 800, 800));
+    assert!(grid.insert(SurfaceId::new(1), Rect::new(0, 0, 300, 300)));
+    assert!(grid.insert(SurfaceId::new(2), Rect::new(200, 200, 300, 300)));
+    assert!(grid.insert(SurfaceId::new(3), Rect::new(600, 600, 100, 100)));
+    // Parcialmente fora da grade
+    assert!(grid.insert(SurfaceId::new(4), Rect::new(750, -50, 200, 100)));
+    grid
+}
+
+#[test]
+fn test_spatial_grid_query_point() {
+    let grid = sample_grid();
+    let hits = |x, y| grid.query_point(Point::new(x, y)).collect::<Vec<_>>();
+    assert_eq!(hits(10, 10), [SurfaceId::new(1)]);
+    assert_eq!(hits(250, 250), [SurfaceId::new(1), SurfaceId::new(2)]);
+    // Mesma célula que a superfície 2, mas fora do retângulo
+    assert_eq!(hits(490, 199), []);
+    assert_eq!(hits(650, 650), [SurfaceId::new(3)]);
+    assert_eq!(hits(900, 0), [SurfaceId::new(4)]);
+    assert!(hits(100, 700).is_empty());
+}
+
+#[test]
+fn test_spatial_grid_query_rect_and_remove() {
+    let mut grid = sample_grid();
+    let hits = |g: &SpatialGrid, r| g.query_rect(r).collect::<Vec<_>>();
+    assert_eq!(
+        hits(&grid, Rect::new(280, 280, 400, 400)),
+        [SurfaceId::new(1), SurfaceId::new(2), SurfaceId::new(3)]
+    );
+    assert_eq!(hits(&grid, Rect::new(700, 0, 100, 10)), [SurfaceId::new(4)]);
+    assert!(hits(&grid, Rect::new(0, 500, 100, 100)).is_empty());
+
+    assert!(grid.remove(SurfaceId::new(1)));
+    assert!(!grid.remove(SurfaceId::new(1)));
+    assert_eq!(grid.len(), 3);
+    assert_eq!(
+        hits(&grid, Rect::new(280, 280, 400, 400)),
+        [SurfaceId::new(2), SurfaceId::new(3)]
+    );
+    assert!(!grid.insert(SurfaceId::new(5), Rect::new(0, 0, 0, 10)));
+}
+
+// =============================================================================
+// WINDOW GEOMETRY TESTS
+// =============================================================================
+
+const OUTPUT: Rect = Rect::new(0, 0, 1920, 1080);
+// Painel de 40px embaixo
+const WORK_AREA: Rect = Rect::new(0, 0, 1920, 1040);
+const NORMAL: Rect = Rect::new(100, 100, 800, 600);
+
+fn resolve(state: WindowState, flags: WindowFlags) -> Rect {
+    resolve_window_rect(state, flags, NORMAL, WORK_AREA, OUTPUT)
+}
+
+#[test]
+fn test_resolve_window_rect_tiles() {
+    let none = WindowFlags::NONE;
+    assert_eq!(
+        resolve(WindowState::TiledLeft, none),
+        Rect::new(0, 0, 960, 1040)
+    );
+    assert_eq!(
+        resolve(WindowState::TiledRight, none),
+        Rect::new(960, 0, 960, 1040)
+    );
+    assert_eq!(
+        resolve(WindowState::TiledTop, none),
+        Rect::new(0, 0, 1920, 520)
+    );
+    assert_eq!(
+        resolve(WindowState::TiledBottom, none),
+        Rect::new(0, 520, 1920, 520)
+    );
+}
+
+#[test]
+fn test_resolve_window_rect_maximize_vs_fullscreen() {
+    let none = WindowFlags::NONE;
+    assert_eq!(resolve(WindowState::Maximized, none), WORK_AREA);
+    // Fullscreen ignora o painel
+    assert_eq!(resolve(WindowState::Fullscreen, none), OUTPUT);
+    assert_eq!(
+        resolve(WindowState::Normal, WindowFlags::FULLSCREEN),
+        OUTPUT
+    );
+}
+
+#[test]
+fn test_resolve_window_rect_normal_and_flags() {
+    assert_eq!(resolve(WindowState::Normal, WindowFlags::NONE), NORMAL);
+    assert_eq!(resolve(WindowState::Minimized, WindowFlags::NONE), NORMAL);
+    assert_eq!(
+        resolve(WindowState::Hidden, WindowFlags::FULLSCREEN),
+        NORMAL
+    );
+    assert_eq!(
+        resolve(WindowState::Maximized, WindowFlags::NO_MAXIMIZE),
+        NORMAL
+    );
+    assert_eq!(
+        resolve(WindowState::TiledLeft, WindowFlags::NO_RESIZE),
+        NORMAL
+    );
+}
+
+// =============================================================================
+// SIZE CONSTRAINTS TESTS
+// =============================================================================
+
+#[test]
+fn test_size_constraints_terminal_increments() {
+    // Terminal com 4px de padding e células de 8x16
+    let constraints = SizeConstraints::new(Size::new(84, 52), Size::new(0, 0))
+        .with_increment(Size::new(4, 4), Size::new(8, 16));
+    assert_eq!(constraints.apply(Size::new(805, 600)), Size::new(804, 596));
+    // Abaixo do mínimo: sobe até o próximo passo
+    assert_eq!(constraints.apply(Size::new(10, 10)), Size::new(84, 52));
+    // Exatamente em um passo não muda
+    assert_eq!(constraints.apply(Size::new(164, 68)), Size::new(164, 68));
+}
+
+#[test]
+fn test_size_constraints_aspect_ratio() {
+    let constraints = SizeConstraints::NONE.with_aspect(16.0 / 9.0);
+    assert_eq!(
+        constraints.apply(Size::new(1920, 1200)),
+        Size::new(1920, 1080)
+    );
+    assert_eq!(
+        constraints.apply(Size::new(3000, 1080)),
+        Size::new(1920, 1080)
+    );
+}
+
+#[test]
+fn test_size_constraints_min_max() {
+    let constraints = SizeConstraints::new(Size::new(100, 100), Size::new(400, 300));
+    assert_eq!(constraints.apply(Size::new(50, 500)), Size::new(100, 300));
+    assert_eq!(constraints.apply(Size::new(200, 200)), Size::new(200, 200));
+
+    // Conflito: mínimo prevalece sobre máximo
+    let conflicting = SizeConstraints::new(Size::new(300, 300), Size::new(200, 200));
+    assert_eq!(conflicting.apply(Size::new(250, 250)), Size::new(300, 300));
+}